@@ -2,7 +2,6 @@ use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use walkdir::WalkDir;
 
 // Include generated code
 #[allow(dead_code, unused_imports)]
@@ -17,6 +16,7 @@ fn main() {
     println!("cargo:rerun-if-changed=schema/icon.fbs");
     println!("cargo:rerun-if-changed=inspirations/icon-sets/json");
     println!("cargo:rerun-if-changed=inspirations/svgl/static/library");
+    println!("cargo:rerun-if-changed=inspirations/svgl/src/data/svgs.ts");
 
     let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
     let schema_path = Path::new("schema/icon.fbs");
@@ -24,7 +24,7 @@ fn main() {
 
     // Compile FlatBuffers schema
     let status = Command::new("flatc")
-        .args(&[
+        .args([
             "--rust",
             "-o",
             src_dir.to_str().unwrap(),
@@ -53,51 +53,29 @@ fn main() {
         }
     }
 
-    // Create output directory for icon binaries
-    let icons_dir = out_dir.join("icons");
-    fs::create_dir_all(&icons_dir).expect("Failed to create icons directory");
-
-    // Process Icon Sets (JSON)
-    let json_dir = Path::new("inspirations/icon-sets/json");
-    if json_dir.exists() {
-        for entry in WalkDir::new(json_dir).into_iter().filter_map(|e| e.ok()) {
-            let path = entry.path();
-            if path.extension().map_or(false, |ext| ext == "json") {
-                match converters::iconsets::IconSetJson::from_file(path) {
-                    Ok(iconset) => {
-                        let data = iconset.to_flatbuffer();
-                        let filename = path.file_stem().unwrap().to_string_lossy();
-                        let out_path = icons_dir.join(format!("{}.bin", filename));
-                        fs::write(&out_path, data).expect("Failed to write iconset binary");
-                    }
-                    Err(e) => {
-                        println!("cargo:warning=Failed to parse {}: {}", path.display(), e);
-                    }
-                }
-            }
-        }
+    // Compile every icon source into FlatBuffers binaries
+    let sources = converters::bundle::BundleSources {
+        icon_sets: Path::new("inspirations/icon-sets/json"),
+        svgl: Path::new("inspirations/svgl/static/library"),
+        svgl_data: Path::new("inspirations/svgl/src/data/svgs.ts"),
+        optimize: Some(converters::optimize::OptimizeOptions::default()),
+    };
+    let bundle = converters::bundle::build_bundle(&sources, &out_dir.join("icons"))
+        .expect("Failed to write icon binaries");
+    for warning in &bundle.warnings {
+        println!("cargo:warning={}", warning);
     }
 
-    // Process SVGL Icons (SVG)
-    let svgl_dir = Path::new("inspirations/svgl/static/library");
-    if svgl_dir.exists() {
-        let mut icons = Vec::new();
-        for entry in WalkDir::new(svgl_dir).into_iter().filter_map(|e| e.ok()) {
-            let path = entry.path();
-            if path.extension().map_or(false, |ext| ext == "svg") {
-                match converters::svgl::SvgIcon::from_file(path) {
-                    Ok(icon) => icons.push(icon),
-                    Err(e) => {
-                        println!("cargo:warning=Failed to parse SVG {}: {}", path.display(), e);
-                    }
-                }
-            }
-        }
-        
-        if !icons.is_empty() {
-            let data = converters::svgl::SvgIcon::build_collection(&icons);
-            let out_path = icons_dir.join("svgl.bin");
-            fs::write(&out_path, data).expect("Failed to write svgl binary");
-        }
+    // Embed every compiled binary so readers need no filesystem access
+    let mut index = String::from("pub static ICON_SETS: &[(&str, &[u8])] = &[\n");
+    for (prefix, path) in &bundle.sets {
+        index.push_str(&format!("    ({:?}, include_bytes!({:?})),\n", prefix, path));
+    }
+    index.push_str("];\n");
+    match &bundle.svgl {
+        Some(path) => index.push_str(&format!("pub static SVGL: &[u8] = include_bytes!({:?});\n", path)),
+        None => index.push_str("pub static SVGL: &[u8] = &[];\n"),
     }
+    index.push_str(&format!("pub static SEARCH_INDEX: &[u8] = include_bytes!({:?});\n", bundle.search));
+    fs::write(out_dir.join("icon_sets.rs"), index).expect("Failed to write icon set index");
 }
@@ -0,0 +1,170 @@
+//! Compile-time icon lookup for Rust UI frameworks.
+//!
+//! `icon!("mdi:home")` expands to the icon's SVG as a `&'static str` and `icon_data!("mdi:home")`
+//! to a `dx_icon_types::StaticIcon`. Icons are resolved while compiling, so a binary only carries the
+//! icons it names, and unknown ids fail the build with suggestions. Neither expansion refers to
+//! `dx-icon` itself; `icon_data!` only needs the dependency-free `dx-icon-types`.
+
+use std::sync::OnceLock;
+use dx_icon::converters::utils::svg_with_xmlns;
+use dx_icon::{IconRegistry, ResolvedIcon, SearchOptions, Theme};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Token};
+
+/// SVG document of an icon as a `&'static str`: `icon!("mdi:home")`, `icon!("svgl:github", theme = "dark")`
+#[proc_macro]
+pub fn icon(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(input.into(), false).into()
+}
+
+/// Icon as a `dx_icon_types::StaticIcon` with its body, viewBox and size: `icon_data!("mdi:home")`
+#[proc_macro]
+pub fn icon_data(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(input.into(), true).into()
+}
+
+/// `"prefix:name"` with an optional `theme = "light" | "dark"` for svgl logos
+struct IconInput {
+    id: LitStr,
+    theme: Option<Theme>,
+}
+
+impl Parse for IconInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let id: LitStr = input.parse()?;
+        let mut theme = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "theme" {
+                return Err(syn::Error::new(key.span(), "expected `theme = \"light\"` or `theme = \"dark\"`"));
+            }
+            input.parse::<Token![=]>()?;
+            let value: LitStr = input.parse()?;
+            theme = Some(value.value().parse().map_err(|_| syn::Error::new(value.span(), "theme must be \"light\" or \"dark\""))?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(IconInput { id, theme })
+    }
+}
+
+/// Registry shared by every macro call the compiler expands in this process
+fn registry() -> &'static IconRegistry {
+    static REGISTRY: OnceLock<IconRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(IconRegistry::new)
+}
+
+fn expand(input: TokenStream, typed: bool) -> TokenStream {
+    let input = match syn::parse2::<IconInput>(input) {
+        Ok(input) => input,
+        Err(e) => return e.to_compile_error(),
+    };
+    let registry = registry();
+    let id = input.id.value();
+    let icon = match input.theme {
+        Some(theme) => registry.resolve_themed(&id, theme),
+        None => registry.resolve(&id),
+    };
+    match icon {
+        Some(icon) if typed => static_icon(&icon, input.id.span()),
+        Some(icon) => {
+            let svg = LitStr::new(&svg_with_xmlns(&icon.to_svg()), input.id.span());
+            quote!(#svg)
+        }
+        None => syn::Error::new(input.id.span(), unknown_icon(registry, &id)).to_compile_error(),
+    }
+}
+
+fn static_icon(icon: &ResolvedIcon, span: Span) -> TokenStream {
+    let lit = |value: &str| LitStr::new(value, span);
+    let (prefix, name, body) = (lit(icon.prefix), lit(icon.name), lit(&icon.body));
+    let view_box = lit(&icon.view_box.view_box());
+    let svg = lit(&svg_with_xmlns(&icon.to_svg()));
+    let (width, height) = (icon.width(), icon.height());
+    quote! {
+        ::dx_icon_types::StaticIcon {
+            prefix: #prefix,
+            name: #name,
+            body: #body,
+            view_box: #view_box,
+            width: #width,
+            height: #height,
+            svg: #svg,
+        }
+    }
+}
+
+/// Error message for an id that did not resolve, suggesting close matches
+fn unknown_icon(registry: &IconRegistry, id: &str) -> String {
+    let Some((prefix, name)) = id.split_once(':') else {
+        return format!("expected an icon id like `mdi:home`, got `{}`", id);
+    };
+
+    let prefixes = registry.prefixes();
+    let suggestions: Vec<String> = if prefixes.contains(&prefix) {
+        let options = SearchOptions { prefixes: vec![prefix.to_string()], limit: 5, fuzzy: true };
+        dx_icon::search().search(name, &options).iter().map(|hit| hit.id()).collect()
+    } else {
+        let mut close: Vec<(usize, &str)> = prefixes
+            .iter()
+            .map(|candidate| (edit_distance(prefix, candidate), *candidate))
+            .filter(|(distance, candidate)| *distance <= 2 || candidate.starts_with(prefix))
+            .collect();
+        close.sort();
+        close.iter().take(5).map(|(_, candidate)| format!("{}:{}", candidate, name)).collect()
+    };
+
+    let what = if prefixes.contains(&prefix) { "icon" } else { "icon set" };
+    let missing = if what == "icon" { id } else { prefix };
+    match suggestions.is_empty() {
+        true => format!("unknown {} `{}`", what, missing),
+        false => format!("unknown {} `{}`; did you mean {}?", what, missing, quoted(&suggestions)),
+    }
+}
+
+fn quoted(items: &[String]) -> String {
+    items.iter().map(|item| format!("`{}`", item)).collect::<Vec<_>>().join(", ")
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous + (ca != *cb) as usize;
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand() {
+        let svg = expand(quote!("mdi:home"), false).to_string();
+        assert!(svg.starts_with("\"<svg xmlns=\\\"http://www.w3.org/2000/svg\\\" viewBox=\\\"0 0 24 24\\\""));
+        assert!(svg.contains("d=\\\"M10 20v-6h4v6h5v-8h3L12 3L2 12h3v8z\\\""));
+
+        let data = expand(quote!("mdi:home"), true).to_string();
+        assert!(data.starts_with(":: dx_icon_types :: StaticIcon { prefix : \"mdi\" , name : \"home\""));
+        assert!(data.contains("view_box : \"0 0 24 24\" , width : 24f32"));
+
+        let dark = expand(quote!("svgl:github", theme = "dark"), false).to_string();
+        let light = expand(quote!("svgl:github", theme = "light"), false).to_string();
+        assert_ne!(dark, light);
+
+        let unknown = expand(quote!("mdi:hom"), false).to_string();
+        assert!(unknown.contains("compile_error"));
+        assert!(unknown.contains("unknown icon `mdi:hom`; did you mean `mdi:home`"));
+        let set = expand(quote!("mdii:home"), false).to_string();
+        assert!(set.contains("unknown icon set `mdii`; did you mean `mdi:home`"));
+        assert!(expand(quote!("svgl:github", theme = "dim"), false).to_string().contains("theme must be"));
+    }
+}
@@ -0,0 +1,25 @@
+//! Types that `dx-icon-macros` expand to.
+//!
+//! A crate that embeds icons with `icon_data!` depends on this crate instead of `dx-icon`, which
+//! is only needed while compiling, so none of the icon data, rendering or CLI ends up in its build.
+
+/// Icon baked into a binary at compile time, as expanded by `dx_icon_macros::icon_data!`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StaticIcon {
+    pub prefix: &'static str,
+    pub name: &'static str,
+    /// Markup inside the root `<svg>` element
+    pub body: &'static str,
+    pub view_box: &'static str,
+    pub width: f32,
+    pub height: f32,
+    /// Complete SVG document
+    pub svg: &'static str,
+}
+
+impl StaticIcon {
+    /// Full `prefix:name` identifier
+    pub fn id(&self) -> String {
+        format!("{}:{}", self.prefix, self.name)
+    }
+}
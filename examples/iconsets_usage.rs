@@ -97,7 +97,7 @@ fn main() {
     println!("\n--- Statistics ---");
     println!("Total icon sets: {}", total_sets);
     println!("Total icons across all sets: {}", total_icons);
-    if total_sets > 0 {
-        println!("Average icons per set: {}", total_icons / total_sets);
+    if let Some(average) = total_icons.checked_div(total_sets) {
+        println!("Average icons per set: {}", average);
     }
 }
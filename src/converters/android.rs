@@ -0,0 +1,1037 @@
+use std::collections::HashMap;
+use crate::converters::jsx::unescape;
+use crate::converters::render::IconBox;
+use crate::converters::stylesheet::Stylesheet;
+use crate::converters::utils::{capitalize, escape_attribute, number};
+use crate::converters::xml::{parse, Element, Node};
+
+/// Names an `ImageVector` file imports, which can't also name its property
+#[allow(dead_code)]
+pub const KOTLIN_NAMES: &[&str] = &["ImageVector", "Color", "SolidColor", "PathFillType", "StrokeCap", "StrokeJoin"];
+
+/// Elements that only take part in drawing when referenced, dropped without a warning
+const NOT_DRAWN: &[&str] = &[
+    "defs", "clipPath", "linearGradient", "radialGradient", "mask", "pattern", "marker", "filter", "symbol",
+    "style", "title", "desc", "metadata",
+];
+
+/// Elements converted to a path
+const SHAPES: &[&str] = &["path", "rect", "circle", "ellipse", "line", "polyline", "polygon"];
+
+/// Containers drawn as a group of their children
+const CONTAINERS: &[&str] = &["svg", "g", "a", "switch"];
+
+/// Properties read from attributes and CSS
+const PROPERTIES: &[&str] = &[
+    "fill", "fill-opacity", "fill-rule", "stroke", "stroke-width", "stroke-opacity", "stroke-linecap",
+    "stroke-linejoin", "stroke-miterlimit", "stroke-dasharray", "opacity", "color", "clip-path", "mask", "filter",
+    "display", "visibility",
+];
+
+/// Named colors accepted besides hex and `rgb()`
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("black", 0x000000),
+    ("white", 0xffffff),
+    ("red", 0xff0000),
+    ("green", 0x008000),
+    ("blue", 0x0000ff),
+    ("yellow", 0xffff00),
+    ("orange", 0xffa500),
+    ("purple", 0x800080),
+    ("gray", 0x808080),
+    ("grey", 0x808080),
+    ("silver", 0xc0c0c0),
+    ("maroon", 0x800000),
+    ("navy", 0x000080),
+    ("teal", 0x008080),
+    ("olive", 0x808000),
+    ("lime", 0x00ff00),
+    ("aqua", 0x00ffff),
+    ("cyan", 0x00ffff),
+    ("fuchsia", 0xff00ff),
+    ("magenta", 0xff00ff),
+];
+
+/// Fill or stroke color of a path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Paint {
+    /// `currentColor`; drawn white under a tint when every color is `currentColor`, black otherwise
+    Current,
+    Argb(u32),
+}
+
+/// Icon in the model shared by Android `VectorDrawable` and Compose `ImageVector`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AndroidVector {
+    /// Size in dp
+    pub width: f32,
+    pub height: f32,
+    pub viewport_width: f32,
+    pub viewport_height: f32,
+    /// Every color is `currentColor`, so the icon can follow a tint like system icons
+    pub tintable: bool,
+    pub nodes: Vec<VectorNode>,
+    /// Constructs that were dropped or could only be partly converted
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VectorNode {
+    Group(VectorGroup),
+    Path(VectorPath),
+}
+
+/// Android groups scale, then rotate around the pivot, then translate
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorGroup {
+    /// Degrees clockwise
+    pub rotation: f32,
+    pub pivot_x: f32,
+    pub pivot_y: f32,
+    pub scale_x: f32,
+    pub scale_y: f32,
+    pub translate_x: f32,
+    pub translate_y: f32,
+    /// Path data clipping the children
+    pub clip_path: Option<String>,
+    pub children: Vec<VectorNode>,
+}
+
+impl Default for VectorGroup {
+    fn default() -> Self {
+        VectorGroup {
+            rotation: 0.0,
+            pivot_x: 0.0,
+            pivot_y: 0.0,
+            scale_x: 1.0,
+            scale_y: 1.0,
+            translate_x: 0.0,
+            translate_y: 0.0,
+            clip_path: None,
+            children: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorPath {
+    pub path_data: String,
+    pub fill: Option<Paint>,
+    pub fill_alpha: f32,
+    pub even_odd: bool,
+    pub stroke: Option<Paint>,
+    pub stroke_alpha: f32,
+    pub stroke_width: f32,
+    /// `butt`, `round` or `square`
+    pub line_cap: String,
+    /// `miter`, `round` or `bevel`
+    pub line_join: String,
+    pub miter_limit: f32,
+}
+
+/// Parse an SVG document into paths and groups Android can draw, reporting what it cannot
+#[allow(dead_code)]
+pub fn svg_to_android(content: &str) -> AndroidVector {
+    let nodes = parse(content).unwrap_or_default();
+    let root = nodes.iter().find_map(|node| match node {
+        Node::Element(element) if element.name == "svg" => Some(element),
+        _ => None,
+    });
+    let Some(root) = root else {
+        return AndroidVector {
+            warnings: vec!["markup is not a well-formed SVG document".to_string()],
+            ..AndroidVector::default()
+        };
+    };
+
+    let view_box = root.attr("viewBox").and_then(IconBox::parse_view_box);
+    let width = root.attr("width").and_then(length);
+    let height = root.attr("height").and_then(length);
+    let view_box = view_box.unwrap_or(IconBox::new(0.0, 0.0, width.unwrap_or(24.0), height.unwrap_or(24.0)));
+
+    let mut ids = HashMap::new();
+    collect_ids(root, &mut ids);
+    let stylesheet = Stylesheet::collect(&nodes);
+    let mut converter = Converter { warnings: stylesheet.warnings.clone(), stylesheet, ids, ..Converter::default() };
+
+    let mut nodes = converter.element(root, &Style::default(), 0);
+    if view_box.left != 0.0 || view_box.top != 0.0 {
+        let group = VectorGroup { translate_x: -view_box.left, translate_y: -view_box.top, children: nodes, ..VectorGroup::default() };
+        nodes = vec![VectorNode::Group(group)];
+    }
+    AndroidVector {
+        width: width.unwrap_or(view_box.width),
+        height: height.unwrap_or(view_box.height),
+        viewport_width: view_box.width,
+        viewport_height: view_box.height,
+        tintable: converter.current && !converter.fixed,
+        nodes,
+        warnings: converter.warnings,
+    }
+}
+
+/// Android `<vector>` drawable resource, with unsupported constructs listed in a comment
+#[allow(dead_code)]
+pub fn svg_to_vector_drawable(content: &str) -> String {
+    svg_to_android(content).to_vector_drawable()
+}
+
+/// Kotlin file declaring a Compose `ImageVector` named `name`
+#[allow(dead_code)]
+pub fn svg_to_compose(content: &str, name: &str) -> String {
+    svg_to_android(content).to_image_vector(name)
+}
+
+impl AndroidVector {
+    /// `res/drawable` XML; tintable icons are drawn white under `?attr/colorControlNormal`
+    #[allow(dead_code)]
+    pub fn to_vector_drawable(&self) -> String {
+        let mut xml = String::new();
+        for warning in &self.warnings {
+            xml.push_str(&format!("<!-- VectorDrawable: {} -->\n", warning.replace("--", "- -")));
+        }
+        xml.push_str("<vector xmlns:android=\"http://schemas.android.com/apk/res/android\"");
+        let mut attrs = vec![
+            ("width", format!("{}dp", number(self.width, 4))),
+            ("height", format!("{}dp", number(self.height, 4))),
+            ("viewportWidth", number(self.viewport_width, 4)),
+            ("viewportHeight", number(self.viewport_height, 4)),
+        ];
+        if self.tintable {
+            attrs.push(("tint", "?attr/colorControlNormal".to_string()));
+        }
+        write_xml_attrs(&attrs, 4, &mut xml);
+        xml.push_str(">\n");
+        self.write_xml_nodes(&self.nodes, 4, &mut xml);
+        xml.push_str("</vector>\n");
+        xml
+    }
+
+    /// Kotlin source for a lazily built `ImageVector` property
+    #[allow(dead_code)]
+    pub fn to_image_vector(&self, name: &str) -> String {
+        let mut body = String::new();
+        let mut imports = vec![
+            "androidx.compose.ui.graphics.vector.ImageVector",
+            "androidx.compose.ui.unit.dp",
+        ];
+        self.write_kotlin_nodes(&self.nodes, 8, &mut body, &mut imports);
+        imports.sort();
+        imports.dedup();
+
+        let mut kotlin = String::new();
+        for import in imports {
+            kotlin.push_str(&format!("import {}\n", import));
+        }
+        kotlin.push('\n');
+        for warning in &self.warnings {
+            kotlin.push_str(&format!("// ImageVector: {}\n", warning));
+        }
+        kotlin.push_str(&format!(
+            "val {name}: ImageVector by lazy {{\n    \
+                ImageVector.Builder(\n        \
+                    name = {label},\n        \
+                    defaultWidth = {width}.dp,\n        \
+                    defaultHeight = {height}.dp,\n        \
+                    viewportWidth = {viewport_width}f,\n        \
+                    viewportHeight = {viewport_height}f,\n    \
+                ).apply {{\n\
+            {body}    \
+                }}.build()\n\
+            }}\n",
+            name = name,
+            label = kotlin_string(name),
+            width = number(self.width, 4),
+            height = number(self.height, 4),
+            viewport_width = number(self.viewport_width, 4),
+            viewport_height = number(self.viewport_height, 4),
+            body = body,
+        ));
+        kotlin
+    }
+
+    fn xml_color(&self, paint: Paint) -> String {
+        match paint {
+            Paint::Current if self.tintable => "@android:color/white".to_string(),
+            Paint::Current => "#FF000000".to_string(),
+            Paint::Argb(argb) => format!("#{:08X}", argb),
+        }
+    }
+
+    fn write_xml_nodes(&self, nodes: &[VectorNode], indent: usize, xml: &mut String) {
+        let pad = " ".repeat(indent);
+        for node in nodes {
+            match node {
+                VectorNode::Path(path) => {
+                    xml.push_str(&format!("{}<path", pad));
+                    let mut attrs = Vec::new();
+                    if let Some(fill) = path.fill {
+                        attrs.push(("fillColor", self.xml_color(fill)));
+                        if path.fill_alpha != 1.0 {
+                            attrs.push(("fillAlpha", number(path.fill_alpha, 4)));
+                        }
+                    }
+                    if path.even_odd {
+                        attrs.push(("fillType", "evenOdd".to_string()));
+                    }
+                    if let Some(stroke) = path.stroke {
+                        attrs.push(("strokeColor", self.xml_color(stroke)));
+                        attrs.push(("strokeWidth", number(path.stroke_width, 4)));
+                        if path.stroke_alpha != 1.0 {
+                            attrs.push(("strokeAlpha", number(path.stroke_alpha, 4)));
+                        }
+                        if path.line_cap != "butt" {
+                            attrs.push(("strokeLineCap", path.line_cap.clone()));
+                        }
+                        if path.line_join != "miter" {
+                            attrs.push(("strokeLineJoin", path.line_join.clone()));
+                        }
+                        if path.miter_limit != 4.0 {
+                            attrs.push(("strokeMiterLimit", number(path.miter_limit, 4)));
+                        }
+                    }
+                    attrs.push(("pathData", path.path_data.clone()));
+                    write_xml_attrs(&attrs, indent + 4, xml);
+                    xml.push_str("/>\n");
+                }
+                VectorNode::Group(group) => {
+                    xml.push_str(&format!("{}<group", pad));
+                    write_xml_attrs(&group_attrs(group).iter().map(|(key, value)| (*key, number(*value, 4))).collect::<Vec<_>>(), indent + 4, xml);
+                    xml.push_str(">\n");
+                    if let Some(clip) = &group.clip_path {
+                        xml.push_str(&format!("{}    <clip-path android:pathData=\"{}\"/>\n", pad, escape_attribute(clip)));
+                    }
+                    self.write_xml_nodes(&group.children, indent + 4, xml);
+                    xml.push_str(&format!("{}</group>\n", pad));
+                }
+            }
+        }
+    }
+
+    fn write_kotlin_nodes(&self, nodes: &[VectorNode], indent: usize, kotlin: &mut String, imports: &mut Vec<&'static str>) {
+        let pad = " ".repeat(indent);
+        for node in nodes {
+            let mut args = Vec::new();
+            match node {
+                VectorNode::Path(path) => {
+                    imports.push("androidx.compose.ui.graphics.vector.addPathNodes");
+                    args.push(format!("pathData = addPathNodes({})", kotlin_string(&path.path_data)));
+                    if let Some(fill) = path.fill {
+                        args.push(format!("fill = {}", kotlin_brush(fill, imports)));
+                        if path.fill_alpha != 1.0 {
+                            args.push(format!("fillAlpha = {}f", number(path.fill_alpha, 4)));
+                        }
+                    }
+                    if path.even_odd {
+                        imports.push("androidx.compose.ui.graphics.PathFillType");
+                        args.push("pathFillType = PathFillType.EvenOdd".to_string());
+                    }
+                    if let Some(stroke) = path.stroke {
+                        args.push(format!("stroke = {}", kotlin_brush(stroke, imports)));
+                        args.push(format!("strokeLineWidth = {}f", number(path.stroke_width, 4)));
+                        if path.stroke_alpha != 1.0 {
+                            args.push(format!("strokeAlpha = {}f", number(path.stroke_alpha, 4)));
+                        }
+                        if path.line_cap != "butt" {
+                            imports.push("androidx.compose.ui.graphics.StrokeCap");
+                            args.push(format!("strokeLineCap = StrokeCap.{}", capitalize(&path.line_cap)));
+                        }
+                        if path.line_join != "miter" {
+                            imports.push("androidx.compose.ui.graphics.StrokeJoin");
+                            args.push(format!("strokeLineJoin = StrokeJoin.{}", capitalize(&path.line_join)));
+                        }
+                        if path.miter_limit != 4.0 {
+                            args.push(format!("strokeLineMiter = {}f", number(path.miter_limit, 4)));
+                        }
+                    }
+                    write_kotlin_call("addPath", &args, indent, kotlin);
+                }
+                VectorNode::Group(group) => {
+                    let names = [
+                        ("rotation", "rotate"),
+                        ("pivotX", "pivotX"),
+                        ("pivotY", "pivotY"),
+                        ("scaleX", "scaleX"),
+                        ("scaleY", "scaleY"),
+                        ("translateX", "translationX"),
+                        ("translateY", "translationY"),
+                    ];
+                    for (key, value) in group_attrs(group) {
+                        let name = names.iter().find(|(xml, _)| *xml == key).map_or(key, |(_, kotlin)| kotlin);
+                        args.push(format!("{} = {}f", name, number(value, 4)));
+                    }
+                    if let Some(clip) = &group.clip_path {
+                        imports.push("androidx.compose.ui.graphics.vector.addPathNodes");
+                        args.push(format!("clipPathData = addPathNodes({})", kotlin_string(clip)));
+                    }
+                    write_kotlin_call("addGroup", &args, indent, kotlin);
+                    self.write_kotlin_nodes(&group.children, indent + 4, kotlin, imports);
+                    kotlin.push_str(&format!("{}clearGroup()\n", pad));
+                }
+            }
+        }
+    }
+}
+
+/// Computed style inherited down the tree
+#[derive(Debug, Clone)]
+struct Style {
+    fill: Option<Paint>,
+    fill_opacity: f32,
+    even_odd: bool,
+    stroke: Option<Paint>,
+    stroke_opacity: f32,
+    stroke_width: f32,
+    line_cap: String,
+    line_join: String,
+    miter_limit: f32,
+    /// Product of the `opacity` of every ancestor
+    opacity: f32,
+    /// What `currentColor` means here
+    color: Paint,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Style {
+            fill: Some(Paint::Argb(0xff000000)),
+            fill_opacity: 1.0,
+            even_odd: false,
+            stroke: None,
+            stroke_opacity: 1.0,
+            stroke_width: 1.0,
+            line_cap: "butt".to_string(),
+            line_join: "miter".to_string(),
+            miter_limit: 4.0,
+            opacity: 1.0,
+            color: Paint::Current,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Converter<'a> {
+    stylesheet: Stylesheet,
+    ids: HashMap<String, &'a Element>,
+    warnings: Vec<String>,
+    /// Whether a drawn path uses `currentColor`, and whether one uses a fixed color
+    current: bool,
+    fixed: bool,
+}
+
+impl<'a> Converter<'a> {
+    fn warn(&mut self, warning: String) {
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    /// Convert an element and its subtree, wrapped in groups for its transform and clip path
+    fn element(&mut self, element: &'a Element, parent: &Style, depth: usize) -> Vec<VectorNode> {
+        let name = element.name.as_str();
+        if NOT_DRAWN.contains(&name) {
+            return Vec::new();
+        }
+        if !CONTAINERS.contains(&name) && !SHAPES.contains(&name) && name != "use" {
+            match name {
+                "text" | "tspan" | "textPath" => self.warn("text is not supported; convert it to paths".to_string()),
+                // Namespaced elements are editor data such as `sodipodi:namedview`
+                _ if name.contains(':') => {}
+                _ => self.warn(format!("<{}> is not supported and was removed", name)),
+            }
+            return Vec::new();
+        }
+
+        let properties = self.properties(element);
+        let property = |key: &str| properties.iter().rev().find(|(k, _)| k == key).map(|(_, v)| v.as_str());
+        if property("display") == Some("none") || matches!(property("visibility"), Some("hidden") | Some("collapse")) {
+            return Vec::new();
+        }
+        let style = self.style(parent, &properties);
+        for (key, feature) in [("mask", "masks"), ("filter", "filters")] {
+            if property(key).is_some_and(|value| value != "none") {
+                self.warn(format!("{} are not supported and were ignored", feature));
+            }
+        }
+        if property("stroke-dasharray").is_some_and(|value| value != "none") {
+            self.warn("dashed strokes are not supported and are drawn solid".to_string());
+        }
+
+        let mut nodes = match name {
+            "use" => self.use_element(element, &style, depth),
+            _ if SHAPES.contains(&name) => self.shape(element, &style).map(VectorNode::Path).into_iter().collect(),
+            _ => {
+                if depth > 0 && name == "svg" && element.attr("viewBox").is_some() {
+                    self.warn("the viewBox of a nested <svg> was ignored".to_string());
+                }
+                self.children(element, &style, depth)
+            }
+        };
+        if nodes.is_empty() {
+            return nodes;
+        }
+
+        if let Some(clip) = property("clip-path").filter(|value| *value != "none") {
+            if let Some(path_data) = self.clip_path(clip) {
+                let group = VectorGroup { clip_path: Some(path_data), children: nodes, ..VectorGroup::default() };
+                nodes = vec![VectorNode::Group(group)];
+            }
+        }
+        // The root's transform is not part of the icon's coordinate system
+        if let Some(transform) = element.attr("transform").filter(|_| depth > 0) {
+            for transform in self.transforms(&unescape(transform)).into_iter().rev() {
+                nodes = vec![VectorNode::Group(VectorGroup { children: nodes, ..transform })];
+            }
+        }
+        nodes
+    }
+
+    fn children(&mut self, element: &'a Element, style: &Style, depth: usize) -> Vec<VectorNode> {
+        let mut nodes = Vec::new();
+        for child in &element.children {
+            match child {
+                Node::Element(child) => nodes.extend(self.element(child, style, depth + 1)),
+                Node::Text(text) if !text.trim().is_empty() && element.name != "style" => {
+                    self.warn(format!("text outside <text> was removed: `{}`", text.trim()));
+                }
+                _ => {}
+            }
+        }
+        nodes
+    }
+
+    /// `<use>` draws its target, shifted by `x` and `y`
+    fn use_element(&mut self, element: &'a Element, style: &Style, depth: usize) -> Vec<VectorNode> {
+        let href = element.attr("href").or_else(|| element.attr("xlink:href")).unwrap_or_default();
+        let Some(&target) = href.strip_prefix('#').and_then(|id| self.ids.get(id)) else {
+            self.warn(format!("<use> target `{}` was not found", href));
+            return Vec::new();
+        };
+        if depth > 16 {
+            self.warn("<use> references nest too deeply".to_string());
+            return Vec::new();
+        }
+        let nodes = match target.name.as_str() {
+            "symbol" => self.children(target, style, depth),
+            _ => self.element(target, style, depth + 1),
+        };
+        let x = element.attr("x").and_then(length).unwrap_or(0.0);
+        let y = element.attr("y").and_then(length).unwrap_or(0.0);
+        if nodes.is_empty() || (x == 0.0 && y == 0.0) {
+            return nodes;
+        }
+        vec![VectorNode::Group(VectorGroup { translate_x: x, translate_y: y, children: nodes, ..VectorGroup::default() })]
+    }
+
+    /// Presentation attributes, then CSS rules and the `style` attribute, which override them
+    fn properties(&self, element: &Element) -> Vec<(String, String)> {
+        let mut properties: Vec<(String, String)> = element
+            .attrs
+            .iter()
+            .filter(|(key, _)| PROPERTIES.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), unescape(value).trim().to_string()))
+            .collect();
+        properties.extend(
+            self.stylesheet
+                .declarations(element)
+                .into_iter()
+                .filter(|(key, _)| PROPERTIES.contains(&key.as_str())),
+        );
+        properties
+    }
+
+    fn style(&mut self, parent: &Style, properties: &[(String, String)]) -> Style {
+        let mut style = parent.clone();
+        // `currentColor` refers to this element's `color`, wherever it is declared
+        if let Some((_, color)) = properties.iter().rev().find(|(key, _)| key == "color") {
+            if let Some(Some(paint)) = self.paint(color, &style) {
+                style.color = paint;
+            }
+        }
+        for (key, value) in properties {
+            let number = value.trim_end_matches("px").parse::<f32>().ok();
+            match key.as_str() {
+                "fill" => {
+                    if let Some(paint) = self.paint(value, &style) {
+                        style.fill = paint;
+                    }
+                }
+                "stroke" => {
+                    if let Some(paint) = self.paint(value, &style) {
+                        style.stroke = paint;
+                    }
+                }
+                "fill-opacity" => style.fill_opacity = opacity(value).unwrap_or(style.fill_opacity),
+                "stroke-opacity" => style.stroke_opacity = opacity(value).unwrap_or(style.stroke_opacity),
+                "opacity" => style.opacity = parent.opacity * opacity(value).unwrap_or(1.0),
+                "fill-rule" => style.even_odd = value == "evenodd",
+                "stroke-width" => style.stroke_width = number.unwrap_or(style.stroke_width),
+                "stroke-miterlimit" => style.miter_limit = number.unwrap_or(style.miter_limit),
+                "stroke-linecap" if ["butt", "round", "square"].contains(&value.as_str()) => style.line_cap = value.clone(),
+                "stroke-linejoin" if ["miter", "round", "bevel"].contains(&value.as_str()) => style.line_join = value.clone(),
+                "stroke-linejoin" => self.warn(format!("stroke-linejoin `{}` is not supported and was ignored", value)),
+                _ => {}
+            }
+        }
+        style
+    }
+
+    /// Resolve a paint: `Some(None)` for `none`, `None` when the value is invalid and should be ignored
+    fn paint(&mut self, value: &str, style: &Style) -> Option<Option<Paint>> {
+        let value = value.trim();
+        if value == "none" || value == "transparent" {
+            return Some(None);
+        }
+        if value == "inherit" {
+            return None;
+        }
+        if value.eq_ignore_ascii_case("currentColor") {
+            return Some(Some(style.color));
+        }
+        if let Some(reference) = value.strip_prefix("url(") {
+            let id = reference.split(')').next().unwrap_or_default().trim().trim_matches(|c| c == '\'' || c == '"');
+            let target = id.strip_prefix('#').and_then(|id| self.ids.get(id)).copied();
+            return match target {
+                Some(gradient) if gradient.name.ends_with("Gradient") => {
+                    self.warn("gradients are not supported and are drawn with their first stop color".to_string());
+                    Some(self.first_stop(gradient, style))
+                }
+                Some(target) => {
+                    self.warn(format!("<{}> paint is not supported; the shape is drawn without it", target.name));
+                    Some(None)
+                }
+                None => {
+                    // `url(#missing) red` falls back to the color after the reference
+                    let fallback = value.split_once(')').map(|(_, rest)| rest.trim()).unwrap_or_default();
+                    match fallback {
+                        "" => Some(None),
+                        fallback => self.paint(fallback, style),
+                    }
+                }
+            };
+        }
+        match parse_color(value) {
+            Some(argb) => Some(Some(Paint::Argb(argb))),
+            None => {
+                self.warn(format!("color `{}` is not supported and was ignored", value));
+                None
+            }
+        }
+    }
+
+    fn first_stop(&mut self, gradient: &'a Element, style: &Style) -> Option<Paint> {
+        let stop = gradient.children.iter().find_map(|child| match child {
+            Node::Element(stop) if stop.name == "stop" => Some(stop),
+            _ => None,
+        });
+        let Some(stop) = stop else {
+            // Gradients may inherit their stops from another gradient
+            let href = gradient.attr("href").or_else(|| gradient.attr("xlink:href"))?;
+            let inherited = *self.ids.get(href.strip_prefix('#')?)?;
+            return if std::ptr::eq(inherited, gradient) { None } else { self.first_stop(inherited, style) };
+        };
+        let declarations = self.stylesheet.declarations(stop);
+        let color = declarations
+            .iter()
+            .rev()
+            .find(|(key, _)| key == "stop-color")
+            .map(|(_, value)| value.clone())
+            .or_else(|| stop.attr("stop-color").map(unescape))
+            .unwrap_or_else(|| "black".to_string());
+        let paint = self.paint(&color, style).flatten()?;
+        let alpha = declarations
+            .iter()
+            .rev()
+            .find(|(key, _)| key == "stop-opacity")
+            .map(|(_, value)| value.clone())
+            .or_else(|| stop.attr("stop-opacity").map(unescape))
+            .and_then(|value| opacity(&value))
+            .unwrap_or(1.0);
+        Some(match paint {
+            Paint::Argb(argb) => Paint::Argb(with_alpha(argb, alpha)),
+            current => current,
+        })
+    }
+
+    fn shape(&mut self, element: &Element, style: &Style) -> Option<VectorPath> {
+        let path_data = shape_path(element)?;
+        let fill = style.fill.filter(|_| !matches!(element.name.as_str(), "line"));
+        let stroke = style.stroke.filter(|_| style.stroke_width > 0.0);
+        if fill.is_none() && stroke.is_none() {
+            return None;
+        }
+        for paint in fill.iter().chain(stroke.iter()) {
+            match paint {
+                Paint::Current => self.current = true,
+                Paint::Argb(_) => self.fixed = true,
+            }
+        }
+        // Android paints have no alpha of their own, so a translucent color moves its alpha to the path
+        let split = |paint: Option<Paint>, opacity: f32| match paint {
+            Some(Paint::Argb(argb)) => (Some(Paint::Argb(argb | 0xff000000)), opacity * (argb >> 24) as f32 / 255.0),
+            paint => (paint, opacity),
+        };
+        let (fill, fill_alpha) = split(fill, style.fill_opacity * style.opacity);
+        let (stroke, stroke_alpha) = split(stroke, style.stroke_opacity * style.opacity);
+        Some(VectorPath {
+            path_data,
+            fill,
+            fill_alpha,
+            even_odd: style.even_odd,
+            stroke,
+            stroke_alpha,
+            stroke_width: style.stroke_width,
+            line_cap: style.line_cap.clone(),
+            line_join: style.line_join.clone(),
+            miter_limit: style.miter_limit,
+        })
+    }
+
+    /// Path data of a `<clipPath>`'s shapes, for `clip-path: url(#id)`
+    fn clip_path(&mut self, value: &str) -> Option<String> {
+        let id = value.strip_prefix("url(")?.split(')').next()?.trim().trim_matches(|c| c == '\'' || c == '"');
+        let Some(&clip) = id.strip_prefix('#').and_then(|id| self.ids.get(id)) else {
+            self.warn(format!("clip path `{}` was not found", value));
+            return None;
+        };
+        if clip.attr("clipPathUnits") == Some("objectBoundingBox") {
+            self.warn("clip paths in objectBoundingBox units are not supported and were ignored".to_string());
+            return None;
+        }
+        let mut data = Vec::new();
+        for child in &clip.children {
+            let Node::Element(shape) = child else { continue };
+            if shape.attr("transform").is_some() {
+                self.warn("transforms inside clip paths are not supported and were ignored".to_string());
+            }
+            let shape = match shape.name.as_str() {
+                "use" => shape
+                    .attr("href")
+                    .or_else(|| shape.attr("xlink:href"))
+                    .and_then(|href| self.ids.get(href.strip_prefix('#')?).copied())
+                    .unwrap_or(shape),
+                _ => shape,
+            };
+            data.extend(shape_path(shape));
+        }
+        Some(data.join(" "))
+    }
+
+    /// One group per transform function, outermost first
+    fn transforms(&mut self, value: &str) -> Vec<VectorGroup> {
+        let mut groups = Vec::new();
+        for function in value.split_inclusive(')') {
+            let Some((name, args)) = function.split_once('(') else { continue };
+            let name = name.trim().trim_start_matches(',').trim();
+            let args: Vec<f32> = args
+                .trim_end_matches(')')
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter_map(|arg| arg.parse().ok())
+                .collect();
+            let group = match (name, args.as_slice()) {
+                ("translate", [x]) => VectorGroup { translate_x: *x, ..VectorGroup::default() },
+                ("translate", [x, y]) => VectorGroup { translate_x: *x, translate_y: *y, ..VectorGroup::default() },
+                ("scale", [s]) => VectorGroup { scale_x: *s, scale_y: *s, ..VectorGroup::default() },
+                ("scale", [x, y]) => VectorGroup { scale_x: *x, scale_y: *y, ..VectorGroup::default() },
+                ("rotate", [a]) => VectorGroup { rotation: *a, ..VectorGroup::default() },
+                ("rotate", [a, x, y]) => VectorGroup { rotation: *a, pivot_x: *x, pivot_y: *y, ..VectorGroup::default() },
+                ("matrix", [a, b, c, d, e, f]) => {
+                    // A group is translate * rotate * scale, which covers every matrix without skew
+                    let scale_x = a.hypot(*b);
+                    if scale_x == 0.0 || (a * c + b * d).abs() > 1e-4 * scale_x * c.hypot(*d).max(1.0) {
+                        self.warn(format!("skewed transform `{}` is not supported and was ignored", function.trim()));
+                        continue;
+                    }
+                    VectorGroup {
+                        rotation: b.atan2(*a).to_degrees(),
+                        scale_x,
+                        scale_y: (a * d - b * c) / scale_x,
+                        translate_x: *e,
+                        translate_y: *f,
+                        ..VectorGroup::default()
+                    }
+                }
+                _ => {
+                    self.warn(format!("transform `{}` is not supported and was ignored", function.trim()));
+                    continue;
+                }
+            };
+            groups.push(group);
+        }
+        groups
+    }
+}
+
+fn collect_ids<'a>(element: &'a Element, ids: &mut HashMap<String, &'a Element>) {
+    if let Some(id) = element.attr("id") {
+        ids.entry(unescape(id)).or_insert(element);
+    }
+    for child in &element.children {
+        if let Node::Element(child) = child {
+            collect_ids(child, ids);
+        }
+    }
+}
+
+/// Path data for a basic shape
+fn shape_path(element: &Element) -> Option<String> {
+    let attr = |name: &str| element.attr(name).and_then(length).unwrap_or(0.0);
+    let n = |value: f32| number(value, 4);
+    let data = match element.name.as_str() {
+        "path" => unescape(element.attr("d")?).trim().to_string(),
+        "rect" => {
+            let (x, y, width, height) = (attr("x"), attr("y"), attr("width"), attr("height"));
+            if width <= 0.0 || height <= 0.0 {
+                return None;
+            }
+            let rx = element.attr("rx").and_then(length);
+            let ry = element.attr("ry").and_then(length);
+            let rx = rx.or(ry).unwrap_or(0.0).clamp(0.0, width / 2.0);
+            let ry = ry.or(Some(rx)).unwrap_or(0.0).clamp(0.0, height / 2.0);
+            if rx == 0.0 || ry == 0.0 {
+                format!("M{},{}h{}v{}h{}z", n(x), n(y), n(width), n(height), n(-width))
+            } else {
+                let (h, v) = (width - 2.0 * rx, height - 2.0 * ry);
+                let arc = |dx: f32, dy: f32| format!("a{},{} 0 0 1 {},{}", n(rx), n(ry), n(dx), n(dy));
+                format!(
+                    "M{},{}h{}{}v{}{}h{}{}v{}{}z",
+                    n(x + rx), n(y), n(h), arc(rx, ry), n(v), arc(-rx, ry), n(-h), arc(-rx, -ry), n(-v), arc(rx, -ry)
+                )
+            }
+        }
+        "circle" | "ellipse" => {
+            let (cx, cy) = (attr("cx"), attr("cy"));
+            let (rx, ry) = match element.name.as_str() {
+                "circle" => (attr("r"), attr("r")),
+                _ => (attr("rx"), attr("ry")),
+            };
+            if rx <= 0.0 || ry <= 0.0 {
+                return None;
+            }
+            format!(
+                "M{},{}a{},{} 0 1 0 {},0a{},{} 0 1 0 {},0z",
+                n(cx - rx), n(cy), n(rx), n(ry), n(2.0 * rx), n(rx), n(ry), n(-2.0 * rx)
+            )
+        }
+        "line" => format!("M{},{}L{},{}", n(attr("x1")), n(attr("y1")), n(attr("x2")), n(attr("y2"))),
+        "polyline" | "polygon" => {
+            let points: Vec<f32> = unescape(element.attr("points")?)
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter_map(|point| point.parse().ok())
+                .collect();
+            let pairs: Vec<String> = points.chunks_exact(2).map(|pair| format!("{},{}", n(pair[0]), n(pair[1]))).collect();
+            if pairs.is_empty() {
+                return None;
+            }
+            let close = if element.name == "polygon" { "z" } else { "" };
+            format!("M{}{}", pairs.join("L"), close)
+        }
+        _ => return None,
+    };
+    (!data.is_empty()).then_some(data)
+}
+
+/// Non-default transform attributes of a group, by VectorDrawable name
+fn group_attrs(group: &VectorGroup) -> Vec<(&'static str, f32)> {
+    let mut attrs = Vec::new();
+    if group.rotation != 0.0 {
+        attrs.push(("rotation", group.rotation));
+        if group.pivot_x != 0.0 {
+            attrs.push(("pivotX", group.pivot_x));
+        }
+        if group.pivot_y != 0.0 {
+            attrs.push(("pivotY", group.pivot_y));
+        }
+    }
+    if group.scale_x != 1.0 {
+        attrs.push(("scaleX", group.scale_x));
+    }
+    if group.scale_y != 1.0 {
+        attrs.push(("scaleY", group.scale_y));
+    }
+    if group.translate_x != 0.0 {
+        attrs.push(("translateX", group.translate_x));
+    }
+    if group.translate_y != 0.0 {
+        attrs.push(("translateY", group.translate_y));
+    }
+    attrs
+}
+
+/// Attributes one per line, as Android Studio formats drawables
+fn write_xml_attrs(attrs: &[(&str, String)], indent: usize, xml: &mut String) {
+    for (key, value) in attrs {
+        xml.push_str(&format!("\n{}android:{}=\"{}\"", " ".repeat(indent), key, escape_attribute(value)));
+    }
+}
+
+fn write_kotlin_call(function: &str, args: &[String], indent: usize, kotlin: &mut String) {
+    let pad = " ".repeat(indent);
+    if args.is_empty() {
+        kotlin.push_str(&format!("{}{}()\n", pad, function));
+        return;
+    }
+    kotlin.push_str(&format!("{}{}(\n", pad, function));
+    for arg in args {
+        kotlin.push_str(&format!("{}    {},\n", pad, arg));
+    }
+    kotlin.push_str(&format!("{})\n", pad));
+}
+
+fn kotlin_brush(paint: Paint, imports: &mut Vec<&'static str>) -> String {
+    imports.push("androidx.compose.ui.graphics.Color");
+    imports.push("androidx.compose.ui.graphics.SolidColor");
+    match paint {
+        Paint::Current => "SolidColor(Color.Black)".to_string(),
+        Paint::Argb(argb) => format!("SolidColor(Color(0x{:08X}))", argb),
+    }
+}
+
+fn kotlin_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('$', "\\$"))
+}
+
+/// Parse `#rgb`, `#rrggbb`, their alpha forms, `rgb()`/`rgba()` and common names into ARGB
+fn parse_color(value: &str) -> Option<u32> {
+    let value = value.trim().to_ascii_lowercase();
+    if let Some(hex) = value.strip_prefix('#') {
+        let digits: Vec<u32> = hex.chars().map(|c| c.to_digit(16)).collect::<Option<_>>()?;
+        let channels: Vec<u32> = match digits.len() {
+            3 | 4 => digits.iter().map(|d| d * 17).collect(),
+            6 | 8 => digits.chunks(2).map(|pair| pair[0] * 16 + pair[1]).collect(),
+            _ => return None,
+        };
+        let alpha = channels.get(3).copied().unwrap_or(255);
+        return Some(alpha << 24 | channels[0] << 16 | channels[1] << 8 | channels[2]);
+    }
+    if let Some(args) = value.strip_prefix("rgba(").or_else(|| value.strip_prefix("rgb(")) {
+        let parts: Vec<&str> = args
+            .trim_end_matches(')')
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|part| !part.is_empty())
+            .collect();
+        let channel = |part: &str| match part.strip_suffix('%') {
+            Some(percent) => percent.parse::<f32>().ok().map(|p| p * 2.55),
+            None => part.parse::<f32>().ok(),
+        };
+        let rgb: Vec<u32> = parts.iter().take(3).map(|part| channel(part).map(|c| c.round().clamp(0.0, 255.0) as u32)).collect::<Option<_>>()?;
+        if rgb.len() != 3 {
+            return None;
+        }
+        let alpha = parts.get(3).and_then(|part| opacity(part)).unwrap_or(1.0);
+        return Some(with_alpha(0xff000000 | rgb[0] << 16 | rgb[1] << 8 | rgb[2], alpha));
+    }
+    NAMED_COLORS.iter().find(|(name, _)| *name == value).map(|(_, rgb)| 0xff000000 | rgb)
+}
+
+fn with_alpha(argb: u32, alpha: f32) -> u32 {
+    let current = (argb >> 24) as f32 / 255.0;
+    let alpha = (current * alpha * 255.0).round().clamp(0.0, 255.0) as u32;
+    alpha << 24 | (argb & 0xffffff)
+}
+
+/// Opacity as a number or percentage, clamped to 0-1
+fn opacity(value: &str) -> Option<f32> {
+    let value = value.trim();
+    let opacity = match value.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok()? / 100.0,
+        None => value.parse::<f32>().ok()?,
+    };
+    Some(opacity.clamp(0.0, 1.0))
+}
+
+/// A length in user units; `px` is the only unit accepted
+fn length(value: &str) -> Option<f32> {
+    value.trim().trim_end_matches("px").trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg_to_android() {
+        let home = svg_to_android(r#"<svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor"><path d="M10 20v-6h4v6h5v-8h3L12 3L2 12h3v8z"/></svg>"#);
+        assert!(home.tintable);
+        assert!(home.warnings.is_empty());
+        assert_eq!(
+            home.to_vector_drawable(),
+            "<vector xmlns:android=\"http://schemas.android.com/apk/res/android\"\n    \
+            android:width=\"24dp\"\n    android:height=\"24dp\"\n    android:viewportWidth=\"24\"\n    \
+            android:viewportHeight=\"24\"\n    android:tint=\"?attr/colorControlNormal\">\n    \
+            <path\n        android:fillColor=\"@android:color/white\"\n        \
+            android:pathData=\"M10 20v-6h4v6h5v-8h3L12 3L2 12h3v8z\"/>\n</vector>\n"
+        );
+        let kotlin = home.to_image_vector("Home");
+        assert!(kotlin.starts_with("import androidx.compose.ui.graphics.Color\nimport androidx.compose.ui.graphics.SolidColor\n"));
+        assert!(kotlin.contains("val Home: ImageVector by lazy {\n    ImageVector.Builder(\n        name = \"Home\",\n        defaultWidth = 24.dp,"));
+        assert!(kotlin.contains("        addPath(\n            pathData = addPathNodes(\"M10 20v-6h4v6h5v-8h3L12 3L2 12h3v8z\"),\n            fill = SolidColor(Color.Black),\n        )\n    }.build()\n}\n"));
+
+        let svg = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 32 16">
+  <style>.a { fill: #f00; fill-opacity: .5 } .b > .c { fill: red }</style>
+  <defs>
+    <clipPath id="clip"><rect width="16" height="16"/></clipPath>
+    <linearGradient id="grad"><stop offset="0" stop-color="#00f"/><stop offset="1" stop-color="#fff"/></linearGradient>
+    <circle id="dot" r="2"/>
+  </defs>
+  <g transform="translate(1 2) rotate(45 8 8)" clip-path="url(#clip)" filter="url(#blur)">
+    <rect class="a" x="1" y="1" width="10" height="6" rx="2"/>
+    <path fill="none" stroke="rgba(0,0,0,0.5)" stroke-width="2" stroke-linecap="round" stroke-dasharray="2 2" d="M0 0h4"/>
+  </g>
+  <use href="#dot" x="20" y="8" fill="url(#grad)"/>
+  <g transform="matrix(0 1 -1 0 16 0)" mask="url(#m)"><polygon points="0,0 4,0 4,4" fill-rule="evenodd"/></g>
+  <text>Hi</text>
+</svg>"##;
+        let vector = svg_to_android(svg);
+        assert!(!vector.tintable);
+        assert_eq!((vector.width, vector.viewport_width, vector.viewport_height), (32.0, 32.0, 16.0));
+        assert_eq!(
+            vector.warnings,
+            [
+                "CSS selector `.b > .c` is too complex to inline",
+                "filters are not supported and were ignored",
+                "dashed strokes are not supported and are drawn solid",
+                "gradients are not supported and are drawn with their first stop color",
+                "masks are not supported and were ignored",
+                "text is not supported; convert it to paths",
+            ]
+        );
+
+        let VectorNode::Group(translate) = &vector.nodes[0] else { panic!("expected a group") };
+        assert_eq!((translate.translate_x, translate.translate_y), (1.0, 2.0));
+        let VectorNode::Group(rotate) = &translate.children[0] else { panic!("expected a group") };
+        assert_eq!((rotate.rotation, rotate.pivot_x, rotate.pivot_y), (45.0, 8.0, 8.0));
+        let VectorNode::Group(clip) = &rotate.children[0] else { panic!("expected a group") };
+        assert_eq!(clip.clip_path.as_deref(), Some("M0,0h16v16h-16z"));
+        let VectorNode::Path(rect) = &clip.children[0] else { panic!("expected a path") };
+        assert_eq!(rect.path_data, "M3,1h6a2,2 0 0 1 2,2v2a2,2 0 0 1 -2,2h-6a2,2 0 0 1 -2,-2v-2a2,2 0 0 1 2,-2z");
+        assert_eq!((rect.fill, rect.fill_alpha), (Some(Paint::Argb(0xffff0000)), 0.5));
+        let VectorNode::Path(line) = &clip.children[1] else { panic!("expected a path") };
+        assert_eq!((line.fill, line.stroke, line.stroke_alpha), (None, Some(Paint::Argb(0xff000000)), 128.0 / 255.0));
+
+        let VectorNode::Group(used) = &vector.nodes[1] else { panic!("expected a group") };
+        let VectorNode::Path(dot) = &used.children[0] else { panic!("expected a path") };
+        assert_eq!((used.translate_x, used.translate_y), (20.0, 8.0));
+        assert_eq!((dot.path_data.as_str(), dot.fill), ("M-2,0a2,2 0 1 0 4,0a2,2 0 1 0 -4,0z", Some(Paint::Argb(0xff0000ff))));
+
+        let VectorNode::Group(rotated) = &vector.nodes[2] else { panic!("expected a group") };
+        let transform = [rotated.rotation, rotated.scale_x, rotated.scale_y, rotated.translate_x].map(|value| number(value, 4));
+        assert_eq!(transform, ["90", "1", "1", "16"]);
+
+        let xml = vector.to_vector_drawable();
+        assert!(xml.starts_with("<!-- VectorDrawable: CSS selector `.b > .c` is too complex to inline -->\n"));
+        assert!(xml.contains("    <group\n        android:translateX=\"1\"\n        android:translateY=\"2\">\n        <group\n            android:rotation=\"45\""));
+        assert!(xml.contains("            <group>\n                <clip-path android:pathData=\"M0,0h16v16h-16z\"/>\n"));
+        assert!(xml.contains("android:fillColor=\"#FFFF0000\"\n                    android:fillAlpha=\"0.5\""));
+        assert!(xml.contains("android:strokeWidth=\"2\"\n                    android:strokeAlpha=\"0.502\"\n                    android:strokeLineCap=\"round\""));
+        assert!(xml.contains("android:fillType=\"evenOdd\"\n            android:pathData=\"M0,0L4,0L4,4z\""));
+
+        let kotlin = vector.to_image_vector("Logo");
+        assert!(kotlin.contains("// ImageVector: masks are not supported and were ignored\n"));
+        assert!(kotlin.contains("        addGroup(\n            translationX = 1f,\n            translationY = 2f,\n        )\n"));
+        assert!(kotlin.contains("                addGroup(\n                    clipPathData = addPathNodes(\"M0,0h16v16h-16z\"),\n                )\n"));
+        assert!(kotlin.contains("strokeLineCap = StrokeCap.Round,"));
+        assert!(kotlin.contains("pathFillType = PathFillType.EvenOdd,"));
+        assert!(kotlin.contains("                clearGroup()\n            clearGroup()\n        clearGroup()\n"));
+    }
+}
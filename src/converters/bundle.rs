@@ -0,0 +1,134 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+use crate::converters::iconsets::IconSetJson;
+use crate::converters::optimize::{OptimizeOptions, OptimizeStats};
+use crate::converters::search::SearchIndexWriter;
+use crate::converters::svgl::{SvgIcon, SvglEntry};
+
+/// Where to find icon sources for a bundle build
+pub struct BundleSources<'a> {
+    /// Directory of Iconify icon-set JSON files
+    pub icon_sets: &'a Path,
+    /// Directory of svgl SVG files
+    pub svgl: &'a Path,
+    /// svgl's `svgs.ts` metadata module
+    pub svgl_data: &'a Path,
+    /// Optimizer passes applied to svgl logos; `None` stores them verbatim
+    pub optimize: Option<OptimizeOptions>,
+}
+
+/// Binaries written by `build_bundle`
+#[derive(Debug, Default)]
+pub struct Bundle {
+    /// `(prefix, path)` for each icon set, sorted by prefix
+    pub sets: Vec<(String, PathBuf)>,
+    pub svgl: Option<PathBuf>,
+    /// Sizes of the svgl logos before and after optimization
+    pub svgl_stats: OptimizeStats,
+    pub search: PathBuf,
+    /// Sources that were skipped because they failed to parse
+    pub warnings: Vec<String>,
+}
+
+/// Compile icon sets, svgl logos and the search index into FlatBuffers binaries in `out_dir`
+pub fn build_bundle(sources: &BundleSources, out_dir: &Path) -> io::Result<Bundle> {
+    fs::create_dir_all(out_dir)?;
+    let mut bundle = Bundle::default();
+    let mut search_index = SearchIndexWriter::new();
+
+    // Process Icon Sets (JSON)
+    if sources.icon_sets.exists() {
+        for entry in WalkDir::new(sources.icon_sets).sort_by_file_name().into_iter().filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                match IconSetJson::from_file(path) {
+                    Ok(iconset) => {
+                        let filename = path.file_stem().unwrap().to_string_lossy();
+                        let out_path = out_dir.join(format!("{}.bin", filename));
+                        fs::write(&out_path, iconset.to_flatbuffer())?;
+                        search_index.add_icon_set(&iconset);
+                        bundle.sets.push((iconset.prefix.clone(), out_path));
+                    }
+                    Err(e) => bundle.warnings.push(format!("Failed to parse {}: {}", path.display(), e)),
+                }
+            }
+        }
+    }
+    bundle.sets.sort();
+
+    // Process SVGL Icons (SVG)
+    if sources.svgl.exists() {
+        let mut icons = Vec::new();
+        for entry in WalkDir::new(sources.svgl).into_iter().filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "svg") {
+                match SvgIcon::from_file(path) {
+                    Ok(icon) => icons.push(icon),
+                    Err(e) => bundle.warnings.push(format!("Failed to parse SVG {}: {}", path.display(), e)),
+                }
+            }
+        }
+
+        // Logo titles, categories and variants live in svgl's data module
+        // A data module that fails to parse would silently drop every title, so it stops the build
+        let entries = if sources.svgl_data.exists() {
+            SvglEntry::from_ts_file(sources.svgl_data).map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("Failed to parse {}: {}", sources.svgl_data.display(), e))
+            })?
+        } else {
+            Vec::new()
+        };
+
+        if !icons.is_empty() {
+            let out_path = out_dir.join("svgl.bin");
+            let (collection, stats) = SvgIcon::build_collection(&icons, &entries, sources.optimize.as_ref());
+            fs::write(&out_path, collection)?;
+            bundle.svgl_stats = stats;
+            search_index.add_svgl("svgl", "SVGL", &icons);
+            bundle.svgl = Some(out_path);
+        }
+    }
+
+    // Build the search index over everything compiled above
+    bundle.search = out_dir.join("search.bin");
+    fs::write(&bundle.search, search_index.to_flatbuffer())?;
+
+    Ok(bundle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_bundle() {
+        let root = std::env::temp_dir().join(format!("dx-icon-bundle-test-{}", std::process::id()));
+        let json_dir = root.join("json");
+        fs::create_dir_all(&json_dir).unwrap();
+        fs::write(
+            json_dir.join("test.json"),
+            r#"{ "prefix": "test", "info": { "name": "Test", "total": 1 }, "icons": { "cat": { "body": "<path/>" } } }"#,
+        )
+        .unwrap();
+        fs::write(json_dir.join("broken.json"), "{").unwrap();
+
+        let sources = BundleSources {
+            icon_sets: &json_dir,
+            svgl: &root.join("missing"),
+            svgl_data: &root.join("missing.ts"),
+            optimize: None,
+        };
+        let bundle = build_bundle(&sources, &root.join("out")).unwrap();
+
+        assert_eq!(bundle.sets.len(), 1);
+        assert_eq!(bundle.sets[0].0, "test");
+        assert!(bundle.sets[0].1.exists());
+        assert!(bundle.svgl.is_none());
+        assert!(bundle.search.exists());
+        assert_eq!(bundle.warnings.len(), 1);
+
+        fs::remove_dir_all(root).ok();
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+use crate::converters::iconsets::IconSetJson;
+use crate::converters::render::IconBox;
+use crate::converters::utils::{number, to_css_url};
+
+/// Naming and sizing of the classes written by `CssIconBuilder`
+#[derive(Debug, Clone)]
+pub struct CssOptions {
+    /// Selector for each icon; `{prefix}` and `{name}` are filled in, as in `.i-{prefix}-{name}`
+    pub selector: String,
+    /// Icon height; the width follows the icon's aspect ratio
+    pub size: String,
+    /// Custom property that overrides `size` when set on the element or an ancestor, e.g. `--icon-size`
+    pub size_var: Option<String>,
+}
+
+impl Default for CssOptions {
+    fn default() -> Self {
+        CssOptions {
+            selector: ".i-{prefix}-{name}".to_string(),
+            size: "1em".to_string(),
+            size_var: None,
+        }
+    }
+}
+
+/// Builds a stylesheet with one class per icon, embedding the SVG as a data URI.
+/// Monotone icons are drawn through `mask-image` so they follow `color`; palette icons use `background-image`.
+#[derive(Debug, Default)]
+pub struct CssIconBuilder {
+    options: CssOptions,
+    rules: Vec<String>,
+    selectors: HashSet<String>,
+}
+
+impl CssIconBuilder {
+    #[allow(dead_code)]
+    pub fn new(options: CssOptions) -> Self {
+        CssIconBuilder { options, ..Self::default() }
+    }
+
+    /// Add a complete SVG document; returns its selector, or `None` if the selector was already added
+    #[allow(dead_code)]
+    pub fn add(&mut self, prefix: &str, name: &str, svg: &str, view_box: IconBox, palette: bool) -> Option<String> {
+        let selector = self
+            .options
+            .selector
+            .replace("{prefix}", &class_part(prefix))
+            .replace("{name}", &class_part(name));
+        if !self.selectors.insert(selector.clone()) {
+            return None;
+        }
+
+        let size = match &self.options.size_var {
+            Some(var) => format!("var({}, {})", var, self.options.size),
+            None => self.options.size.clone(),
+        };
+        let ratio = view_box.width / view_box.height;
+        let width = if (ratio - 1.0).abs() < 1e-4 {
+            size.clone()
+        } else if self.options.size_var.is_some() {
+            format!("calc({} * {})", size, number(ratio, 4))
+        } else {
+            scale_length(&size, ratio)
+        };
+
+        let mut rule = format!(
+            "{} {{\n  display: inline-block;\n  width: {};\n  height: {};\n",
+            selector, width, size
+        );
+        if palette {
+            rule.push_str(&format!(
+                "  background: {} no-repeat center / 100% 100%;\n",
+                to_css_url(svg)
+            ));
+        } else {
+            // Masks only use the alpha channel, so the icon's own color is irrelevant
+            rule.push_str(&format!(
+                "  --svg: {};\n  \
+                background-color: currentColor;\n  \
+                -webkit-mask: var(--svg) no-repeat center / 100% 100%;\n  \
+                mask: var(--svg) no-repeat center / 100% 100%;\n",
+                to_css_url(&svg.replace("currentColor", "#000"))
+            ));
+        }
+        rule.push('}');
+        self.rules.push(rule);
+        Some(selector)
+    }
+
+    /// Add an icon or alias from an Iconify icon set, honouring the set's `palette` flag
+    #[allow(dead_code)]
+    pub fn add_from_set(&mut self, iconset: &IconSetJson, name: &str) -> Option<String> {
+        let icon = iconset.render(name)?;
+        let palette = iconset.info.palette.unwrap_or(false);
+        self.add(&iconset.prefix, name, &icon.to_svg(), icon.view_box, palette)
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    #[allow(dead_code)]
+    pub fn to_css(&self) -> String {
+        let mut css = self.rules.join("\n\n");
+        css.push('\n');
+        css
+    }
+}
+
+/// Turn an icon name like `github_dark` or `1.5x` into a valid class name fragment
+fn class_part(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '-' })
+        .collect()
+}
+
+/// Multiply a length like `1em` or `24px`, falling back to `calc()` for anything else
+fn scale_length(length: &str, factor: f32) -> String {
+    let split = length.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(length.len());
+    match length[..split].parse::<f32>() {
+        Ok(value) => format!("{}{}", number(value * factor, 4), &length[split..]),
+        Err(_) => format!("calc({} * {})", length, number(factor, 4)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_css_icon_classes() {
+        let json = r##"{
+            "prefix": "test",
+            "info": { "name": "Test", "total": 2 },
+            "icons": {
+                "home": { "body": "<path fill=\"currentColor\" d=\"M0 0h16v16z\"/>" },
+                "wide_logo": { "body": "<path fill=\"#f00\" d=\"M0 0h32v16z\"/>", "width": 32 }
+            }
+        }"##;
+        let iconset: IconSetJson = serde_json::from_str(json).unwrap();
+        let mut css = CssIconBuilder::new(CssOptions::default());
+        assert_eq!(css.add_from_set(&iconset, "home").as_deref(), Some(".i-test-home"));
+        assert_eq!(css.add_from_set(&iconset, "home"), None);
+        let home = css.to_css();
+        assert!(home.contains("width: 1em;\n  height: 1em;"));
+        assert!(home.contains("--svg: url(\"data:image/svg+xml,%3Csvg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 16 16'"));
+        assert!(home.contains("fill='%23000'"));
+        assert!(home.contains("mask: var(--svg) no-repeat center / 100% 100%;"));
+
+        let options = CssOptions {
+            selector: ".icon-{name}".to_string(),
+            size: "24px".to_string(),
+            size_var: Some("--icon-size".to_string()),
+        };
+        let mut logos = CssIconBuilder::new(options);
+        let icon = iconset.render("wide_logo").unwrap();
+        assert_eq!(logos.add("test", "wide_logo", &icon.to_svg(), icon.view_box, true).as_deref(), Some(".icon-wide-logo"));
+        let logo = logos.to_css();
+        assert!(logo.contains("width: calc(var(--icon-size, 24px) * 2);\n  height: var(--icon-size, 24px);"));
+        assert!(logo.contains("background: url(\"data:image/svg+xml,"));
+        assert!(logo.contains("fill='%23f00'"));
+        assert_eq!(scale_length("1em", 1.5), "1.5em");
+    }
+}
@@ -0,0 +1,353 @@
+use usvg::tiny_skia_path::{LineCap, LineJoin, PathSegment, Point, Transform};
+use crate::converters::outline::{outline, Outline, OutlineColor};
+use crate::converters::utils::{number, svg_with_xmlns, type_name};
+
+/// Words Dart reserves, which can't name a `DxIcons` field
+const DART_RESERVED: &[&str] = &[
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else", "enum", "extends",
+    "false", "final", "finally", "for", "if", "in", "is", "new", "null", "rethrow", "return", "super", "switch",
+    "this", "throw", "true", "try", "var", "void", "while", "with",
+];
+
+/// Flutter and `dart:ui` classes the generated widgets and painters use, which an icon class would shadow
+const DART_CLASSES: &[&str] = &[
+    "BuildContext", "Canvas", "Color", "CustomPaint", "CustomPainter", "DxIcons", "IconTheme", "Paint",
+    "PaintingStyle", "Path", "PathFillType", "Size", "StatelessWidget", "StrokeCap", "StrokeJoin", "String",
+    "SvgPicture", "SvgTheme", "Widget",
+];
+
+/// A `CustomPainter` converted from an SVG document
+#[derive(Debug, Default)]
+#[allow(dead_code)]
+pub struct DartPainter {
+    /// The painter class, without imports
+    pub code: String,
+    /// Constructs that were dropped or could only be partly converted
+    pub warnings: Vec<String>,
+}
+
+/// Dart class name for an icon, e.g. `github_dark` -> `GithubDark`, `2fa` -> `Icon2fa`, `color` -> `ColorIcon`
+#[allow(dead_code)]
+pub fn dart_class_name(name: &str) -> String {
+    type_name(name, is_dart_class)
+}
+
+/// Whether an icon class called `name`, or its `{name}Painter`, would shadow a class the generated code uses
+#[allow(dead_code)]
+pub fn is_dart_class(name: &str) -> bool {
+    DART_CLASSES.contains(&name) || DART_CLASSES.contains(&format!("{}Painter", name).as_str())
+}
+
+/// A `flutter_svg` widget drawing the icon with `SvgPicture.string`, sized and colored by
+/// the ambient `IconTheme` unless `size` or `color` is given
+#[allow(dead_code)]
+pub fn svg_to_flutter(content: &str, name: &str, snippet: bool) -> String {
+    let outline = parse(content);
+    let name = dart_class_name(name);
+    let code = format!(
+        "class {name} extends StatelessWidget {{\n  \
+            const {name}({{super.key, this.size, this.color}});\n\n\
+            {fields}\n\n  \
+            @override\n  \
+            Widget build(BuildContext context) {{\n    \
+                final theme = IconTheme.of(context);\n    \
+                final height = size ?? theme.size ?? 24;\n    \
+                return SvgPicture.string(\n      \
+                    svg,\n      \
+                    width: {width},\n      \
+                    height: height,\n      \
+                    theme: SvgTheme(currentColor: color ?? theme.color ?? const Color(0xFF000000)),\n    \
+                );\n  \
+            }}\n\
+        }}\n",
+        name = name,
+        fields = widget_fields(content),
+        width = scaled_width(&outline),
+    );
+    if snippet {
+        return code;
+    }
+    format!(
+        "import 'package:flutter/widgets.dart';\nimport 'package:flutter_svg/flutter_svg.dart';\n\n{}",
+        code
+    )
+}
+
+/// A widget drawing the icon with a generated `CustomPainter`, which needs no packages
+#[allow(dead_code)]
+pub fn svg_to_flutter_painter(content: &str, name: &str, snippet: bool) -> String {
+    let outline = parse(content);
+    let name = dart_class_name(name);
+    let painter = custom_painter(&outline, &format!("{}Painter", name));
+    let mut code = String::new();
+    for warning in &painter.warnings {
+        code.push_str(&format!("// Flutter: {}\n", warning));
+    }
+    code.push_str(&format!(
+        "class {name} extends StatelessWidget {{\n  \
+            const {name}({{super.key, this.size, this.color}});\n\n\
+            {fields}\n\n  \
+            @override\n  \
+            Widget build(BuildContext context) {{\n    \
+                final theme = IconTheme.of(context);\n    \
+                final height = size ?? theme.size ?? 24;\n    \
+                return CustomPaint(\n      \
+                    size: Size({width}, height),\n      \
+                    painter: {name}Painter(color: color ?? theme.color ?? const Color(0xFF000000)),\n    \
+                );\n  \
+            }}\n\
+        }}\n\n{painter}",
+        name = name,
+        fields = widget_fields(content),
+        width = scaled_width(&outline),
+        painter = painter.code,
+    ));
+    if snippet {
+        return code;
+    }
+    format!("import 'package:flutter/widgets.dart';\n\n{}", code)
+}
+
+/// A `CustomPainter` named `name` that scales the icon's viewBox to the canvas.
+/// `currentColor` is drawn with the painter's `color`.
+#[allow(dead_code)]
+pub fn svg_to_custom_painter(content: &str, name: &str) -> DartPainter {
+    custom_painter(&parse(content), name)
+}
+
+/// Library re-exporting generated icon files, with a `DxIcons` class holding every icon's SVG.
+/// `exports` are class names with the file that declares them.
+#[allow(dead_code)]
+pub fn dx_icons_library(exports: &[(String, String)]) -> String {
+    let mut library = String::new();
+    for (_, file_name) in exports {
+        library.push_str(&format!("import '{}';\n", file_name));
+    }
+    library.push('\n');
+    for (_, file_name) in exports {
+        library.push_str(&format!("export '{}';\n", file_name));
+    }
+    library.push_str("\n/// SVG markup of every icon, for `SvgPicture.string` or anywhere else\nclass DxIcons {\n  DxIcons._();\n\n");
+    for (class_name, _) in exports {
+        library.push_str(&format!("  static const String {} = {}.svg;\n", dart_field_name(class_name), class_name));
+    }
+    library.push_str("}\n");
+    library
+}
+
+/// `lowerCamelCase` field name for a class name, avoiding reserved words
+fn dart_field_name(class_name: &str) -> String {
+    let mut chars = class_name.chars();
+    let field: String = match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    };
+    if DART_RESERVED.contains(&field.as_str()) {
+        format!("icon{}", class_name)
+    } else {
+        field
+    }
+}
+
+/// Fields shared by both widget styles
+fn widget_fields(content: &str) -> String {
+    format!(
+        "  /// SVG markup of the icon\n  \
+        static const String svg = {};\n\n  \
+        /// Height, and width for square icons; the ambient `IconTheme` size when null\n  \
+        final double? size;\n\n  \
+        /// Color for `currentColor`; the ambient `IconTheme` color when null\n  \
+        final Color? color;",
+        dart_string(&svg_with_xmlns(content))
+    )
+}
+
+/// Paths of the icon in its own units, or only a warning if usvg can't read it
+fn parse(content: &str) -> Outline {
+    outline(&svg_with_xmlns(content), Transform::identity()).unwrap_or_else(|error| Outline {
+        width: 24.0,
+        height: 24.0,
+        warnings: vec![format!("markup could not be parsed: {}", error)],
+        ..Default::default()
+    })
+}
+
+/// Width expression keeping the icon's aspect ratio at `height`
+fn scaled_width(outline: &Outline) -> String {
+    let ratio = outline.width as f64 / outline.height as f64;
+    if !ratio.is_finite() || (ratio - 1.0).abs() < 1e-4 {
+        "height".to_string()
+    } else {
+        format!("height * {}", number(ratio, 3))
+    }
+}
+
+fn custom_painter(outline: &Outline, name: &str) -> DartPainter {
+    let point = |p: Point| format!("{}, {}", number(p.x as f64, 3), number(p.y as f64, 3));
+    let mut body = String::new();
+    for (i, path) in outline.paths.iter().enumerate() {
+        let mut cascade = Vec::new();
+        if path.fill.as_ref().is_some_and(|fill| fill.rule == usvg::FillRule::EvenOdd) {
+            cascade.push("fillType = PathFillType.evenOdd".to_string());
+        }
+        for segment in path.data.segments() {
+            cascade.push(match segment {
+                PathSegment::MoveTo(to) => format!("moveTo({})", point(to)),
+                PathSegment::LineTo(to) => format!("lineTo({})", point(to)),
+                PathSegment::CubicTo(control1, control2, to) => {
+                    format!("cubicTo({}, {}, {})", point(control1), point(control2), point(to))
+                }
+                PathSegment::QuadTo(control, to) => format!("quadraticBezierTo({}, {})", point(control), point(to)),
+                PathSegment::Close => "close()".to_string(),
+            });
+        }
+        body.push_str(&format!("    final path{} = Path()", i));
+        for call in cascade {
+            body.push_str(&format!("\n      ..{}", call));
+        }
+        body.push_str(";\n");
+
+        if let Some(fill) = &path.fill {
+            body.push_str(&format!("    canvas.drawPath(path{}, Paint()..color = {});\n", i, dart_color(fill.color, fill.opacity)));
+        }
+        if let Some(stroke) = &path.stroke {
+            let style = &stroke.style;
+            let mut cascade = vec![
+                "style = PaintingStyle.stroke".to_string(),
+                format!("strokeWidth = {}", number((style.width * path.scale()) as f64, 3)),
+            ];
+            match style.line_cap {
+                LineCap::Butt => {}
+                LineCap::Round => cascade.push("strokeCap = StrokeCap.round".to_string()),
+                LineCap::Square => cascade.push("strokeCap = StrokeCap.square".to_string()),
+            }
+            match style.line_join {
+                LineJoin::Miter | LineJoin::MiterClip => {}
+                LineJoin::Round => cascade.push("strokeJoin = StrokeJoin.round".to_string()),
+                LineJoin::Bevel => cascade.push("strokeJoin = StrokeJoin.bevel".to_string()),
+            }
+            if style.miter_limit != 4.0 {
+                cascade.push(format!("strokeMiterLimit = {}", number(style.miter_limit as f64, 3)));
+            }
+            cascade.push(format!("color = {}", dart_color(stroke.color, stroke.opacity)));
+            body.push_str(&format!("    canvas.drawPath(\n      path{},\n      Paint()", i));
+            for call in cascade {
+                body.push_str(&format!("\n        ..{}", call));
+            }
+            body.push_str(",\n    );\n");
+        }
+    }
+
+    let code = format!(
+        "class {name} extends CustomPainter {{\n  \
+            const {name}({{this.color = const Color(0xFF000000)}});\n\n  \
+            /// Color for `currentColor`\n  \
+            final Color color;\n\n  \
+            @override\n  \
+            void paint(Canvas canvas, Size size) {{\n    \
+                canvas.scale(size.width / {width}, size.height / {height});\n\
+            {body}  \
+            }}\n\n  \
+            @override\n  \
+            bool shouldRepaint({name} oldDelegate) => oldDelegate.color != color;\n\
+        }}\n",
+        name = name,
+        width = number(outline.width as f64, 3),
+        height = number(outline.height as f64, 3),
+        body = body,
+    );
+    DartPainter { code, warnings: outline.warnings.clone() }
+}
+
+/// Paint color as a Dart expression, with the path's alpha folded in
+fn dart_color(color: OutlineColor, alpha: f32) -> String {
+    match color {
+        OutlineColor::Current if alpha == 1.0 => "color".to_string(),
+        OutlineColor::Current => format!("color.withValues(alpha: color.a * {})", number(alpha as f64, 3)),
+        OutlineColor::Rgb(r, g, b) => {
+            let alpha = (alpha * 255.0).round().clamp(0.0, 255.0) as u8;
+            format!("const Color(0x{:02X}{:02X}{:02X}{:02X})", alpha, r, g, b)
+        }
+    }
+}
+
+/// Raw Dart string literal, falling back to an escaped one if both raw quote styles occur
+fn dart_string(text: &str) -> String {
+    if !text.contains("'''") {
+        format!("r'''{}'''", text)
+    } else if !text.contains("\"\"\"") {
+        format!("r\"\"\"{}\"\"\"", text)
+    } else {
+        format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'").replace('$', "\\$").replace('\n', "\\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::{export_set, ExportOptions, Framework, IconSelection};
+
+    #[test]
+    fn test_flutter_class_names() {
+        assert_eq!(dart_class_name("github_dark"), "GithubDark");
+        assert_eq!(dart_class_name("color"), "ColorIcon");
+        assert_eq!(dart_class_name("custom"), "CustomIcon");
+        let painter = svg_to_flutter_painter(r#"<svg viewBox="0 0 24 24"><path d="M0 0h24v24H0z"/></svg>"#, "paint", true);
+        assert!(painter.contains("class PaintIcon extends StatelessWidget {"));
+        assert!(painter.contains("class PaintIconPainter extends CustomPainter {"));
+        assert!(painter.contains("canvas.drawPath(path0, Paint()..color = "));
+    }
+
+    #[test]
+    fn test_flutter_widgets() {
+        let home = r#"<svg viewBox="0 0 24 24" fill="currentColor"><path d="M10 20v-6h4v6h5v-8h3L12 3L2 12h3v8z"/></svg>"#;
+        let widget = svg_to_flutter(home, "home", false);
+        assert!(widget.starts_with("import 'package:flutter/widgets.dart';\nimport 'package:flutter_svg/flutter_svg.dart';\n\nclass Home extends StatelessWidget {\n  const Home({super.key, this.size, this.color});\n"));
+        assert!(widget.contains("  static const String svg = r'''<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 24 24\""));
+        assert!(widget.contains("    return SvgPicture.string(\n      svg,\n      width: height,\n      height: height,\n      theme: SvgTheme(currentColor: color ?? theme.color ?? const Color(0xFF000000)),\n    );\n"));
+
+        let logo = r##"<svg viewBox="0 0 48 24"><g transform="scale(2)"><path fill="#f00" fill-opacity=".5" fill-rule="evenodd" d="M0 0h4v4z"/><path fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-opacity=".25" d="M0 0a2 2 0 0 1 4 0"/></g><text>x</text></svg>"##;
+        let painter = svg_to_flutter_painter(logo, "2fa", false);
+        assert!(painter.starts_with("import 'package:flutter/widgets.dart';\n\n// Flutter: text is not supported; convert it to paths\nclass Icon2fa extends StatelessWidget {"));
+        assert!(painter.contains("      size: Size(height * 2, height),\n      painter: Icon2faPainter(color: color ?? theme.color ?? const Color(0xFF000000)),\n"));
+        assert!(painter.contains("class Icon2faPainter extends CustomPainter {\n  const Icon2faPainter({this.color = const Color(0xFF000000)});\n"));
+        assert!(painter.contains("    canvas.scale(size.width / 48, size.height / 24);\n    final path0 = Path()\n      ..fillType = PathFillType.evenOdd\n      ..moveTo(0, 0)\n      ..lineTo(8, 0)\n      ..lineTo(8, 8)\n      ..close();\n    canvas.drawPath(path0, Paint()..color = const Color(0x80FF0000));\n"));
+        assert!(painter.contains("    final path1 = Path()\n      ..moveTo(0, 0)\n      ..cubicTo(0, -2.209, 1.791, -4, 4, -4)\n"));
+        assert!(painter.contains("      Paint()\n        ..style = PaintingStyle.stroke\n        ..strokeWidth = 4\n        ..strokeCap = StrokeCap.round\n        ..color = color.withValues(alpha: color.a * 0.25),\n    );\n"));
+        assert!(painter.contains("  bool shouldRepaint(Icon2faPainter oldDelegate) => oldDelegate.color != color;\n"));
+
+        let library = dx_icons_library(&[("Home".to_string(), "home.dart".to_string()), ("Switch".to_string(), "switch.dart".to_string())]);
+        assert_eq!(
+            library,
+            "import 'home.dart';\nimport 'switch.dart';\n\nexport 'home.dart';\nexport 'switch.dart';\n\n\
+            /// SVG markup of every icon, for `SvgPicture.string` or anywhere else\nclass DxIcons {\n  DxIcons._();\n\n  \
+            static const String home = Home.svg;\n  static const String iconSwitch = Switch.svg;\n}\n"
+        );
+        assert_eq!(dart_string("a'''b"), "r\"\"\"a'''b\"\"\"");
+    }
+
+    #[test]
+    fn test_export_flutter() {
+        let registry = crate::IconRegistry::new();
+        let out = std::env::temp_dir().join(format!("dx-icon-flutter-export-test-{}", std::process::id()));
+        std::fs::remove_dir_all(&out).ok();
+
+        let options = ExportOptions { framework: Framework::FlutterPainter, ..Default::default() };
+        let names = IconSelection::Names(["home-outline", "numeric-1", "color", "canvas"].map(String::from).to_vec());
+        let report = export_set(&registry, "mdi", &names, &options, &out).unwrap();
+        let home = std::fs::read_to_string(out.join("home_outline.dart")).unwrap();
+        assert!(home.starts_with("/*\nmdi:home-outline\n"));
+        assert!(home.contains("class HomeOutlinePainter extends CustomPainter {"));
+        let library = std::fs::read_to_string(report.barrel.unwrap()).unwrap();
+        assert!(library.contains("export 'home_outline.dart';\n"));
+        assert!(library.contains("  static const String numeric1 = Numeric1.svg;\n"));
+
+        // `Color` and `Canvas` would shadow the dart:ui classes the painters draw with
+        let color = std::fs::read_to_string(out.join("color_icon.dart")).unwrap();
+        assert!(color.contains("class ColorIcon extends StatelessWidget {") && color.contains("const Color(0xFF000000)"));
+        assert!(std::fs::read_to_string(out.join("canvas_icon.dart")).unwrap().contains("void paint(Canvas canvas, Size size) {"));
+        assert!(library.contains("  static const String colorIcon = ColorIcon.svg;\n"));
+
+        std::fs::remove_dir_all(out).ok();
+    }
+}
@@ -2,7 +2,13 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
-use crate::converters::utils::{svg_to_jsx, to_pascal_case, svg_to_react_native, svg_to_qwik, svg_to_solid, svg_to_astro};
+use crate::converters::render::{IconBox, RenderedIcon};
+use crate::converters::jsx::svg_to_jsx;
+use crate::converters::react_native::svg_to_react_native;
+use crate::converters::android::{svg_to_compose, svg_to_vector_drawable};
+use crate::converters::flutter::{svg_to_flutter, svg_to_flutter_painter};
+use crate::converters::swift::{svg_to_swiftui, swift_type_name};
+use crate::converters::utils::{to_pascal_case, svg_to_qwik, svg_to_solid, svg_to_astro, svg_to_leptos, svg_to_dioxus, svg_to_yew};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Author {
@@ -27,6 +33,10 @@ pub struct IconSetInfo {
     pub height: Option<u32>,
     pub category: Option<String>,
     pub palette: Option<bool>,
+    #[serde(default)]
+    pub samples: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -36,17 +46,51 @@ pub struct IconData {
     pub width: Option<f32>,
     #[serde(default)]
     pub height: Option<f32>,
+    #[serde(default)]
+    pub left: Option<f32>,
+    #[serde(default)]
+    pub top: Option<f32>,
+    #[serde(default)]
+    pub rotate: Option<u8>,
+    #[serde(default, rename = "hFlip")]
+    pub h_flip: Option<bool>,
+    #[serde(default, rename = "vFlip")]
+    pub v_flip: Option<bool>,
+    #[serde(default)]
+    pub hidden: Option<bool>,
 }
 
 impl IconData {
+    pub fn transform(&self) -> IconTransform {
+        IconTransform {
+            rotate: self.rotate.unwrap_or(0) % 4,
+            h_flip: self.h_flip.unwrap_or(false),
+            v_flip: self.v_flip.unwrap_or(false),
+            width: self.width,
+            height: self.height,
+            left: self.left,
+            top: self.top,
+            hidden: self.hidden,
+        }
+    }
+
+    /// Icon dimensions, falling back to the set-level defaults
+    #[allow(dead_code)]
+    pub fn icon_box(&self, defaults: IconBox) -> IconBox {
+        self.transform().icon_box(defaults)
+    }
+
+    /// Apply the icon's own transforms plus any inherited from an alias
+    #[allow(dead_code)]
+    pub fn render(&self, defaults: IconBox, alias: IconTransform) -> RenderedIcon {
+        let transform = self.transform().merge(alias);
+        RenderedIcon::new(&self.body, transform.icon_box(defaults), transform)
+    }
+
     #[allow(dead_code)]
     pub fn to_svg(&self, default_width: u32, default_height: u32) -> String {
-        let w = self.width.unwrap_or(default_width as f32);
-        let h = self.height.unwrap_or(default_height as f32);
-        format!(
-            "<svg viewBox=\"0 0 {} {}\" width=\"{}\" height=\"{}\" fill=\"currentColor\">{}</svg>",
-            w, h, w, h, self.body
-        )
+        let defaults = IconBox::new(0.0, 0.0, default_width as f32, default_height as f32);
+        self.render(defaults, IconTransform::default()).to_svg()
     }
 
     #[allow(dead_code)]
@@ -103,17 +147,17 @@ impl IconData {
     }
 
     #[allow(dead_code)]
-    pub fn to_react_native_component(&self, name: &str, default_width: u32, default_height: u32, _typescript: bool) -> String {
+    pub fn to_react_native_component(&self, name: &str, default_width: u32, default_height: u32, typescript: bool) -> String {
         let name = to_pascal_case(name);
         let svg = self.to_svg(default_width, default_height);
-        svg_to_react_native(&svg, &name, false)
+        svg_to_react_native(&svg, &name, false, typescript)
     }
 
     #[allow(dead_code)]
-    pub fn to_qwik_component(&self, name: &str, default_width: u32, default_height: u32, _typescript: bool) -> String {
+    pub fn to_qwik_component(&self, name: &str, default_width: u32, default_height: u32, typescript: bool) -> String {
         let name = to_pascal_case(name);
         let svg = self.to_svg(default_width, default_height);
-        svg_to_qwik(&svg, &name, false)
+        svg_to_qwik(&svg, &name, false, typescript)
     }
 
     #[allow(dead_code)]
@@ -128,6 +172,135 @@ impl IconData {
         let svg = self.to_svg(default_width, default_height);
         svg_to_astro(&svg)
     }
+
+    #[allow(dead_code)]
+    pub fn to_leptos_component(&self, name: &str, default_width: u32, default_height: u32) -> String {
+        svg_to_leptos(&self.to_svg(default_width, default_height), name)
+    }
+
+    #[allow(dead_code)]
+    pub fn to_dioxus_component(&self, name: &str, default_width: u32, default_height: u32) -> String {
+        svg_to_dioxus(&self.to_svg(default_width, default_height), name)
+    }
+
+    #[allow(dead_code)]
+    pub fn to_yew_component(&self, name: &str, default_width: u32, default_height: u32) -> String {
+        svg_to_yew(&self.to_svg(default_width, default_height), name)
+    }
+
+    #[allow(dead_code)]
+    pub fn to_vector_drawable(&self, default_width: u32, default_height: u32) -> String {
+        svg_to_vector_drawable(&self.to_svg(default_width, default_height))
+    }
+
+    #[allow(dead_code)]
+    pub fn to_compose_image_vector(&self, name: &str, default_width: u32, default_height: u32) -> String {
+        svg_to_compose(&self.to_svg(default_width, default_height), &to_pascal_case(name))
+    }
+
+    #[allow(dead_code)]
+    pub fn to_swiftui_shape(&self, name: &str, default_width: u32, default_height: u32) -> String {
+        svg_to_swiftui(&self.to_svg(default_width, default_height), &swift_type_name(name))
+    }
+
+    #[allow(dead_code)]
+    pub fn to_flutter_widget(&self, name: &str, default_width: u32, default_height: u32) -> String {
+        svg_to_flutter(&self.to_svg(default_width, default_height), name, false)
+    }
+
+    #[allow(dead_code)]
+    pub fn to_flutter_painter(&self, name: &str, default_width: u32, default_height: u32) -> String {
+        svg_to_flutter_painter(&self.to_svg(default_width, default_height), name, false)
+    }
+}
+
+/// Rotation, flips and dimension overrides carried by an icon or alias
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct IconTransform {
+    /// Quarter turns, 0-3
+    pub rotate: u8,
+    pub h_flip: bool,
+    pub v_flip: bool,
+    /// Dimensions; `None` keeps the parent's or the set default
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub left: Option<f32>,
+    pub top: Option<f32>,
+    pub hidden: Option<bool>,
+}
+
+impl IconTransform {
+    /// Apply `other`, an alias of whatever `self` describes, the way Iconify merges alias data:
+    /// rotations and flips combine, while the alias's dimensions and `hidden` replace the parent's
+    #[allow(dead_code)]
+    pub fn merge(self, other: IconTransform) -> Self {
+        IconTransform {
+            rotate: (self.rotate + other.rotate) % 4,
+            h_flip: self.h_flip != other.h_flip,
+            v_flip: self.v_flip != other.v_flip,
+            width: other.width.or(self.width),
+            height: other.height.or(self.height),
+            left: other.left.or(self.left),
+            top: other.top.or(self.top),
+            hidden: other.hidden.or(self.hidden),
+        }
+    }
+
+    /// No rotation or flips; dimension overrides don't count
+    #[allow(dead_code)]
+    pub fn is_identity(&self) -> bool {
+        self.rotate == 0 && !self.h_flip && !self.v_flip
+    }
+
+    /// Dimensions with unset values taken from the set-level defaults
+    #[allow(dead_code)]
+    pub fn icon_box(&self, defaults: IconBox) -> IconBox {
+        IconBox::new(
+            self.left.unwrap_or(defaults.left),
+            self.top.unwrap_or(defaults.top),
+            self.width.unwrap_or(defaults.width),
+            self.height.unwrap_or(defaults.height),
+        )
+    }
+}
+
+/// Maximum alias chain length before a lookup is treated as a cycle
+pub const MAX_ALIAS_DEPTH: usize = 24;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AliasData {
+    pub parent: String,
+    #[serde(default)]
+    pub rotate: Option<u8>,
+    #[serde(default, rename = "hFlip")]
+    pub h_flip: Option<bool>,
+    #[serde(default, rename = "vFlip")]
+    pub v_flip: Option<bool>,
+    #[serde(default)]
+    pub width: Option<f32>,
+    #[serde(default)]
+    pub height: Option<f32>,
+    #[serde(default)]
+    pub left: Option<f32>,
+    #[serde(default)]
+    pub top: Option<f32>,
+    #[serde(default)]
+    pub hidden: Option<bool>,
+}
+
+impl AliasData {
+    pub fn transform(&self) -> IconTransform {
+        IconTransform {
+            rotate: self.rotate.unwrap_or(0) % 4,
+            h_flip: self.h_flip.unwrap_or(false),
+            v_flip: self.v_flip.unwrap_or(false),
+            width: self.width,
+            height: self.height,
+            left: self.left,
+            top: self.top,
+            hidden: self.hidden,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -137,6 +310,20 @@ pub struct IconSetJson {
     #[serde(rename = "lastModified")]
     pub last_modified: Option<u64>,
     pub icons: HashMap<String, IconData>,
+    #[serde(default)]
+    pub aliases: HashMap<String, AliasData>,
+    #[serde(default)]
+    pub width: Option<f32>,
+    #[serde(default)]
+    pub height: Option<f32>,
+    #[serde(default)]
+    pub left: Option<f32>,
+    #[serde(default)]
+    pub top: Option<f32>,
+    #[serde(default)]
+    pub categories: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub chars: HashMap<String, String>,
 }
 
 impl IconSetJson {
@@ -146,14 +333,56 @@ impl IconSetJson {
         Ok(iconset)
     }
 
+    /// Set-level icon dimensions
+    pub fn defaults(&self) -> IconBox {
+        let fallback = IconBox::iconify_default();
+        IconBox::new(
+            self.left.unwrap_or(fallback.left),
+            self.top.unwrap_or(fallback.top),
+            self.width.unwrap_or(fallback.width),
+            self.height.unwrap_or(fallback.height),
+        )
+    }
+
+    /// Look up an icon by name, following alias chains to the source icon
+    #[allow(dead_code)]
+    pub fn resolve(&self, name: &str) -> Option<(&IconData, IconTransform)> {
+        let mut transform = IconTransform::default();
+        let mut current = name;
+        for _ in 0..=MAX_ALIAS_DEPTH {
+            if let Some(icon) = self.icons.get(current) {
+                return Some((icon, transform));
+            }
+            // Aliases nearer the requested name override those further up the chain
+            let alias = self.aliases.get(current)?;
+            transform = alias.transform().merge(transform);
+            current = &alias.parent;
+        }
+        None
+    }
+
+    /// Resolve an icon or alias and apply all transforms and set defaults
+    #[allow(dead_code)]
+    pub fn render(&self, name: &str) -> Option<RenderedIcon> {
+        let (icon, transform) = self.resolve(name)?;
+        Some(icon.render(self.defaults(), transform))
+    }
+
+    #[allow(dead_code)]
+    pub fn icon_svg(&self, name: &str) -> Option<String> {
+        self.render(name).map(|icon| icon.to_svg())
+    }
+
     pub fn to_flatbuffer(&self) -> Vec<u8> {
         use crate::icon_generated::dx_icon::{
             Icon, IconArgs, IconInfo, IconInfoArgs, IconSet, IconSetArgs, 
-            Author as FbAuthor, AuthorArgs, License as FbLicense, LicenseArgs
+            Author as FbAuthor, AuthorArgs, License as FbLicense, LicenseArgs,
+            Alias, AliasArgs, Category, CategoryArgs, CharMapping, CharMappingArgs
         };
         use flatbuffers::FlatBufferBuilder;
 
         let mut builder = FlatBufferBuilder::new();
+        let defaults = self.defaults();
 
         // Create Author
         let author = if let Some(a) = &self.info.author {
@@ -185,6 +414,10 @@ impl IconSetJson {
         let name = builder.create_string(&self.info.name);
         let version = self.info.version.as_ref().map(|v| builder.create_string(v));
         let category = self.info.category.as_ref().map(|c| builder.create_string(c));
+        let samples: Vec<_> = self.info.samples.iter().map(|s| builder.create_string(s)).collect();
+        let samples = builder.create_vector(&samples);
+        let tags: Vec<_> = self.info.tags.iter().map(|t| builder.create_string(t)).collect();
+        let tags = builder.create_vector(&tags);
         
         let info = IconInfo::create(&mut builder, &IconInfoArgs {
             name: Some(name),
@@ -192,9 +425,11 @@ impl IconSetJson {
             version,
             author,
             license,
-            height: self.info.height.unwrap_or(16),
+            height: self.info.height.unwrap_or(defaults.height as u32),
             category,
             palette: self.info.palette.unwrap_or(false),
+            samples: Some(samples),
+            tags: Some(tags),
         });
 
         // Create Icons
@@ -207,22 +442,95 @@ impl IconSetJson {
             let value = &self.icons[key];
             let id = builder.create_string(key);
             let body = builder.create_string(&value.body);
+            let transform = value.transform();
             let icon = Icon::create(&mut builder, &IconArgs {
                 id: Some(id),
                 body: Some(body),
-                width: value.width.unwrap_or(0.0) as u32,
-                height: value.height.unwrap_or(0.0) as u32,
+                width: value.width,
+                height: value.height,
+                left: value.left,
+                top: value.top,
+                rotate: transform.rotate,
+                h_flip: transform.h_flip,
+                v_flip: transform.v_flip,
+                hidden: value.hidden.unwrap_or(false),
             });
             icons_vec.push(icon);
         }
         let icons = builder.create_vector(&icons_vec);
 
+        // Create Aliases
+        let mut aliases_vec = Vec::new();
+        let mut alias_keys: Vec<&String> = self.aliases.keys().collect();
+        alias_keys.sort();
+
+        for key in alias_keys {
+            let value = &self.aliases[key];
+            let transform = value.transform();
+            let id = builder.create_string(key);
+            let parent = builder.create_string(&value.parent);
+            let alias = Alias::create(&mut builder, &AliasArgs {
+                id: Some(id),
+                parent: Some(parent),
+                rotate: transform.rotate,
+                h_flip: transform.h_flip,
+                v_flip: transform.v_flip,
+                width: transform.width,
+                height: transform.height,
+                left: transform.left,
+                top: transform.top,
+                hidden: transform.hidden,
+            });
+            aliases_vec.push(alias);
+        }
+        let aliases = builder.create_vector(&aliases_vec);
+
+        // Create Categories
+        let mut categories_vec = Vec::new();
+        let mut category_names: Vec<&String> = self.categories.keys().collect();
+        category_names.sort();
+
+        for name in category_names {
+            let icons: Vec<_> = self.categories[name].iter().map(|i| builder.create_string(i)).collect();
+            let icons = builder.create_vector(&icons);
+            let name = builder.create_string(name);
+            let category = Category::create(&mut builder, &CategoryArgs {
+                name: Some(name),
+                icons: Some(icons),
+            });
+            categories_vec.push(category);
+        }
+        let categories = builder.create_vector(&categories_vec);
+
+        // Create Chars
+        let mut chars_vec = Vec::new();
+        let mut char_keys: Vec<&String> = self.chars.keys().collect();
+        char_keys.sort();
+
+        for key in char_keys {
+            let chars = builder.create_string(key);
+            let icon = builder.create_string(&self.chars[key]);
+            let mapping = CharMapping::create(&mut builder, &CharMappingArgs {
+                chars: Some(chars),
+                icon: Some(icon),
+            });
+            chars_vec.push(mapping);
+        }
+        let chars = builder.create_vector(&chars_vec);
+
         // Create IconSet
         let prefix = builder.create_string(&self.prefix);
         let icon_set = IconSet::create(&mut builder, &IconSetArgs {
             prefix: Some(prefix),
             info: Some(info),
             icons: Some(icons),
+            aliases: Some(aliases),
+            width: defaults.width,
+            height: defaults.height,
+            left: defaults.left,
+            top: defaults.top,
+            categories: Some(categories),
+            chars: Some(chars),
         });
 
         builder.finish(icon_set, None);
@@ -256,4 +564,77 @@ mod tests {
         assert_eq!(iconset.info.name, "Test Icons");
         assert_eq!(iconset.icons.len(), 1);
     }
+
+    #[test]
+    fn test_resolve_alias_chain() {
+        let json = r#"{
+            "prefix": "test",
+            "info": { "name": "Test Icons", "total": 1 },
+            "icons": { "arrow": { "body": "<path d='M0 0'/>" } },
+            "aliases": {
+                "arrow-right": { "parent": "arrow", "rotate": 1 },
+                "arrow-left": { "parent": "arrow-right", "rotate": 2, "hFlip": true },
+                "loop": { "parent": "loop" }
+            }
+        }"#;
+
+        let iconset: IconSetJson = serde_json::from_str(json).unwrap();
+        let (icon, transform) = iconset.resolve("arrow-left").unwrap();
+        assert_eq!(icon.body, "<path d='M0 0'/>");
+        assert_eq!(transform, IconTransform { rotate: 3, h_flip: true, ..Default::default() });
+        assert!(iconset.resolve("arrow").unwrap().1.is_identity());
+        assert!(iconset.resolve("loop").is_none());
+    }
+
+    #[test]
+    fn test_resized_alias() {
+        let json = r#"{
+            "prefix": "test",
+            "info": { "name": "Test Icons", "total": 1 },
+            "icons": { "grid": { "body": "<path/>", "width": 20, "hidden": true } },
+            "aliases": {
+                "grid-wide": { "parent": "grid", "width": 32, "left": -6, "hidden": false },
+                "grid-wide-tall": { "parent": "grid-wide", "height": 28, "rotate": 2 }
+            },
+            "width": 24,
+            "height": 24
+        }"#;
+
+        let iconset: IconSetJson = serde_json::from_str(json).unwrap();
+        let (_, transform) = iconset.resolve("grid-wide-tall").unwrap();
+        assert_eq!((transform.width, transform.height, transform.left), (Some(32.0), Some(28.0), Some(-6.0)));
+        assert_eq!(transform.hidden, Some(false));
+        assert!(iconset.icon_svg("grid").unwrap().starts_with("<svg viewBox=\"0 0 20 24\""));
+        assert!(iconset.icon_svg("grid-wide").unwrap().starts_with("<svg viewBox=\"-6 0 32 24\""));
+        assert!(iconset.icon_svg("grid-wide-tall").unwrap().starts_with("<svg viewBox=\"-6 0 32 28\""));
+    }
+
+    #[test]
+    fn test_set_default_dimensions() {
+        let json = r#"{
+            "prefix": "test",
+            "info": { "name": "Test Icons", "total": 2 },
+            "icons": {
+                "square": { "body": "<path/>" },
+                "wide": { "body": "<path/>", "width": 32 }
+            },
+            "width": 24,
+            "height": 24
+        }"#;
+
+        let iconset: IconSetJson = serde_json::from_str(json).unwrap();
+        assert!(iconset.icon_svg("square").unwrap().starts_with("<svg viewBox=\"0 0 24 24\""));
+        assert!(iconset.icon_svg("wide").unwrap().starts_with("<svg viewBox=\"0 0 32 24\""));
+    }
+
+    #[test]
+    fn test_icon_props_to_svg() {
+        let json = r#"{ "body": "<path/>", "left": -2, "top": 1, "width": 20, "vFlip": true, "rotate": 1 }"#;
+        let icon: IconData = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            icon.to_svg(24, 24),
+            "<svg viewBox=\"0 0 24 20\" width=\"24\" height=\"20\" fill=\"currentColor\">\
+            <g transform=\"rotate(90 12 12) translate(2 25) scale(1 -1)\"><path/></g></svg>"
+        );
+    }
 }
@@ -0,0 +1,248 @@
+use crate::converters::utils::capitalize;
+use crate::converters::xml::{parse, Element, Node};
+
+/// Attributes React spells differently from SVG, besides the usual kebab-case to camelCase
+const RENAMED: &[(&str, &str)] = &[
+    ("class", "className"),
+    ("for", "htmlFor"),
+    ("tabindex", "tabIndex"),
+    ("crossorigin", "crossOrigin"),
+];
+
+/// Convert SVG markup to JSX: React attribute names, `style` objects, escaped text and JSX comments.
+/// Markup that isn't well-formed XML is returned unchanged.
+#[allow(dead_code)]
+pub fn svg_to_jsx(content: &str) -> String {
+    let Some(nodes) = parse(content) else {
+        return content.to_string();
+    };
+    // Only the root element is an expression; prologs and comments around it would not parse
+    let mut jsx = String::with_capacity(content.len());
+    for node in &nodes {
+        if let Node::Element(element) = node {
+            write_element(element, &mut jsx);
+        }
+    }
+    jsx
+}
+
+/// React prop name for an SVG attribute, e.g. `stroke-width` -> `strokeWidth`, `xlink:href` -> `xlinkHref`
+#[allow(dead_code)]
+pub fn jsx_attribute_name(name: &str) -> String {
+    if let Some((_, renamed)) = RENAMED.iter().find(|(from, _)| *from == name) {
+        return renamed.to_string();
+    }
+    if name.starts_with("data-") || name.starts_with("aria-") {
+        return name.to_string();
+    }
+    match name.split_once(':') {
+        Some((prefix, local)) => format!("{}{}", prefix, capitalize(&camel_case(local))),
+        None => camel_case(name),
+    }
+}
+
+/// Inline CSS as the body of a JSX style object, e.g. `fill:red;stop-color:#fff` -> `fill: 'red', stopColor: '#fff'`
+#[allow(dead_code)]
+pub fn style_object(style: &str) -> String {
+    let mut entries = Vec::new();
+    for declaration in split_declarations(&unescape(style)) {
+        let Some((property, value)) = declaration.split_once(':') else {
+            continue;
+        };
+        let (property, value) = (property.trim(), value.trim());
+        if property.is_empty() || value.is_empty() {
+            continue;
+        }
+        let key = if property.starts_with("--") {
+            js_string(property)
+        } else if let Some(vendor) = property.strip_prefix("-ms-") {
+            format!("ms{}", capitalize(&camel_case(vendor)))
+        } else if let Some(vendor) = property.strip_prefix('-') {
+            capitalize(&camel_case(vendor))
+        } else {
+            camel_case(&property.to_ascii_lowercase())
+        };
+        entries.push(format!("{}: {}", key, js_string(value)));
+    }
+    entries.join(", ")
+}
+
+fn write_jsx(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Element(element) => write_element(element, out),
+            Node::Text(text) => push_text(text, out),
+            Node::Comment(comment) => {
+                out.push_str("{/*");
+                out.push_str(&comment.replace("*/", "* /"));
+                out.push_str("*/}");
+            }
+            Node::CData(data) => push_template(data, out),
+            // `<?xml ?>` and doctypes aren't valid inside JSX
+            Node::Prolog(_) => {}
+        }
+    }
+}
+
+fn write_element(element: &Element, out: &mut String) {
+    out.push('<');
+    out.push_str(&element.name);
+    for (key, value) in &element.attrs {
+        out.push(' ');
+        if key == "style" {
+            out.push_str("style={{ ");
+            out.push_str(&style_object(value));
+            out.push_str(" }}");
+            continue;
+        }
+        out.push_str(&jsx_attribute_name(key));
+        out.push_str("=\"");
+        // Values stay escaped as in the source; JSX strings understand the same entities
+        out.push_str(&value.replace('"', "&quot;"));
+        out.push('"');
+    }
+    if element.children.is_empty() {
+        out.push_str("/>");
+        return;
+    }
+    out.push('>');
+    if element.name == "style" || element.name == "script" {
+        // CSS braces and selectors pass through a template literal untouched
+        let mut text = String::new();
+        for child in &element.children {
+            match child {
+                Node::Text(content) => text.push_str(&unescape(content)),
+                Node::CData(content) => text.push_str(content),
+                _ => {}
+            }
+        }
+        push_template(&text, out);
+    } else {
+        write_jsx(&element.children, out);
+    }
+    out.push_str("</");
+    out.push_str(&element.name);
+    out.push('>');
+}
+
+/// Escaped XML text as JSX text, where braces and `>` need escaping too
+pub(crate) fn push_text(text: &str, out: &mut String) {
+    for c in text.chars() {
+        match c {
+            '{' => out.push_str("{'{'}"),
+            '}' => out.push_str("{'}'}"),
+            '>' => out.push_str("&gt;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// `{`...`}` expression holding `text` verbatim
+fn push_template(text: &str, out: &mut String) {
+    out.push_str("{`");
+    out.push_str(&text.replace('\\', "\\\\").replace('`', "\\`").replace("${", "\\${"));
+    out.push_str("`}");
+}
+
+/// Split declarations on `;`, ignoring semicolons inside quotes or parentheses such as `url(data:...;base64,...)`
+pub(crate) fn split_declarations(style: &str) -> Vec<&str> {
+    let mut declarations = Vec::new();
+    let (mut depth, mut quote, mut start) = (0usize, None, 0);
+    for (i, c) in style.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                declarations.push(&style[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    declarations.push(&style[start..]);
+    declarations
+}
+
+/// Decode the predefined XML entities and character references
+pub(crate) fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                entity => {
+                    let code = match entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn camel_case(name: &str) -> String {
+    let mut parts = name.split('-');
+    let mut camel = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        camel.push_str(&capitalize(part));
+    }
+    camel
+}
+
+/// Single-quoted JavaScript string literal
+fn js_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'").replace('\n', "\\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg_to_jsx() {
+        let svg = r##"<?xml version="1.0"?>
+<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" class="logo" data-name="Layer 1">
+  <!-- drawn by hand */ -->
+  <style>.a{fill:#f00}.b > .c{fill:url(#g)}</style>
+  <defs><linearGradient id="g"><stop offset="0" style="stop-color:#fff; stop-opacity: .5;"/></linearGradient></defs>
+  <path fill-rule="evenodd" aria-label="a stroke-width=2 label" style="-webkit-mask: url('data:image/png;base64,AA'); --tone: 'x'" d="M0 0h24"/>
+  <use xlink:href="#g" xml:space="preserve"/>
+  <text font-size="12">{brace} &amp; a > b</text>
+</svg>"##;
+        let jsx = svg_to_jsx(svg);
+        assert!(jsx.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" xmlnsXlink=\"http://www.w3.org/1999/xlink\" className=\"logo\" data-name=\"Layer 1\">"));
+        assert!(jsx.contains("{/* drawn by hand * / */}"));
+        assert!(jsx.contains("<style>{`.a{fill:#f00}.b > .c{fill:url(#g)}`}</style>"));
+        assert!(jsx.contains("<stop offset=\"0\" style={{ stopColor: '#fff', stopOpacity: '.5' }}/>"));
+        assert!(jsx.contains("<path fillRule=\"evenodd\" aria-label=\"a stroke-width=2 label\" style={{ WebkitMask: 'url(\\'data:image/png;base64,AA\\')', '--tone': '\\'x\\'' }} d=\"M0 0h24\"/>"));
+        assert!(jsx.contains("<use xlinkHref=\"#g\" xmlSpace=\"preserve\"/>"));
+        assert!(jsx.contains("<text fontSize=\"12\">{'{'}brace{'}'} &amp; a &gt; b</text>"));
+
+        assert_eq!(svg_to_jsx("<svg><path"), "<svg><path");
+    }
+}
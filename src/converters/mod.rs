@@ -1,3 +1,17 @@
+pub mod android;
+pub mod bundle;
+pub mod css;
+pub mod flutter;
 pub mod iconsets;
+pub mod jsx;
+pub mod optimize;
+pub mod react_native;
+pub mod render;
+pub mod search;
+pub mod sprite;
 pub mod svgl;
+pub mod swift;
 pub mod utils;
+pub(crate) mod outline;
+mod stylesheet;
+mod xml;
@@ -0,0 +1,847 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::AddAssign;
+use std::str::FromStr;
+use crate::converters::xml::{parse, write_nodes, Element, Node};
+
+/// Namespace prefixes written by design tools that renderers ignore
+const EDITOR_PREFIXES: &[&str] = &[
+    "inkscape", "sodipodi", "sketch", "serif", "i", "x", "graph", "svgjs", "osb", "rdf", "dc", "cc", "figma",
+];
+
+/// Attributes that children inherit, so a group's value can move onto its only child
+const INHERITED: &[&str] = &[
+    "fill", "fill-rule", "fill-opacity", "stroke", "stroke-width", "stroke-linecap", "stroke-linejoin",
+    "stroke-miterlimit", "stroke-dasharray", "stroke-dashoffset", "stroke-opacity", "clip-rule", "color",
+    "font-family", "font-size", "font-style", "font-weight", "visibility",
+];
+
+/// Attributes whose rendering changes when two paths become one
+const UNMERGEABLE: &[&str] = &[
+    "id", "style", "opacity", "fill-opacity", "stroke-opacity", "clip-path", "mask", "filter",
+    "marker-start", "marker-mid", "marker-end",
+];
+
+const NUMERIC: &[&str] = &[
+    "x", "y", "width", "height", "cx", "cy", "r", "rx", "ry", "x1", "y1", "x2", "y2", "stroke-width",
+];
+
+/// Elements whose text content is rendered or parsed, so whitespace inside them is kept
+const TEXT_CONTENT: &[&str] = &["text", "tspan", "textPath", "style", "script", "title", "desc"];
+
+/// A single optimization pass, as named on the command line
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pass {
+    Comments,
+    Metadata,
+    Groups,
+    MergePaths,
+    Minify,
+    Precision,
+}
+
+impl Pass {
+    #[allow(dead_code)]
+    pub const ALL: [Pass; 6] = [Pass::Comments, Pass::Metadata, Pass::Groups, Pass::MergePaths, Pass::Minify, Pass::Precision];
+
+    #[allow(dead_code)]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Pass::Comments => "comments",
+            Pass::Metadata => "metadata",
+            Pass::Groups => "groups",
+            Pass::MergePaths => "merge-paths",
+            Pass::Minify => "minify",
+            Pass::Precision => "precision",
+        }
+    }
+}
+
+impl FromStr for Pass {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Pass::ALL
+            .into_iter()
+            .find(|pass| pass.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<&str> = Pass::ALL.iter().map(|pass| pass.as_str()).collect();
+                format!("unknown optimization pass '{}', expected one of: {}", s, names.join(", "))
+            })
+    }
+}
+
+/// Which optimization passes to run; every pass is on by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptimizeOptions {
+    /// Drop comments, keeping `<!--! ... -->` legal notices
+    pub remove_comments: bool,
+    /// Drop XML declarations, doctypes, `<metadata>`, generator `<desc>`s and design tool elements and attributes
+    pub remove_metadata: bool,
+    /// Unwrap attribute-less groups, fold group attributes into an only child and drop empty containers
+    pub collapse_groups: bool,
+    /// Join adjacent paths with identical attributes whose bounds don't overlap
+    pub merge_paths: bool,
+    /// Collapse whitespace, drop default and unreferenced attributes and write path data compactly
+    pub minify_attributes: bool,
+    /// Decimal places kept in path data and numeric attributes; `None` keeps full precision
+    pub precision: Option<u8>,
+}
+
+impl Default for OptimizeOptions {
+    fn default() -> Self {
+        OptimizeOptions {
+            remove_comments: true,
+            remove_metadata: true,
+            collapse_groups: true,
+            merge_paths: true,
+            minify_attributes: true,
+            precision: Some(3),
+        }
+    }
+}
+
+impl OptimizeOptions {
+    /// Turn off one pass
+    #[allow(dead_code)]
+    pub fn skip(&mut self, pass: Pass) {
+        match pass {
+            Pass::Comments => self.remove_comments = false,
+            Pass::Metadata => self.remove_metadata = false,
+            Pass::Groups => self.collapse_groups = false,
+            Pass::MergePaths => self.merge_paths = false,
+            Pass::Minify => self.minify_attributes = false,
+            Pass::Precision => self.precision = None,
+        }
+    }
+}
+
+/// Size totals for one or more optimized documents
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OptimizeStats {
+    pub files: usize,
+    pub original_bytes: usize,
+    pub optimized_bytes: usize,
+}
+
+impl OptimizeStats {
+    pub fn saved_bytes(&self) -> usize {
+        self.original_bytes.saturating_sub(self.optimized_bytes)
+    }
+
+    pub fn saved_percent(&self) -> f64 {
+        if self.original_bytes == 0 {
+            return 0.0;
+        }
+        self.saved_bytes() as f64 * 100.0 / self.original_bytes as f64
+    }
+}
+
+impl AddAssign for OptimizeStats {
+    fn add_assign(&mut self, other: Self) {
+        self.files += other.files;
+        self.original_bytes += other.original_bytes;
+        self.optimized_bytes += other.optimized_bytes;
+    }
+}
+
+impl fmt::Display for OptimizeStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} -> {} bytes (-{:.1}%)",
+            self.original_bytes, self.optimized_bytes, self.saved_percent()
+        )
+    }
+}
+
+/// Optimize an SVG document or icon body; markup that fails to parse is returned unchanged
+pub fn optimize_svg(svg: &str, options: &OptimizeOptions) -> (String, OptimizeStats) {
+    let optimized = match parse(svg) {
+        Some(mut nodes) => {
+            run_passes(&mut nodes, options);
+            let mut out = String::with_capacity(svg.len());
+            write_nodes(&nodes, &mut out);
+            out
+        }
+        None => svg.to_string(),
+    };
+    let stats = OptimizeStats { files: 1, original_bytes: svg.len(), optimized_bytes: optimized.len() };
+    (optimized, stats)
+}
+
+fn run_passes(nodes: &mut Vec<Node>, options: &OptimizeOptions) {
+    strip_whitespace(nodes);
+    if options.remove_comments {
+        remove_comments(nodes);
+    }
+    if options.remove_metadata {
+        remove_metadata(nodes);
+    }
+
+    // Stylesheets and scripts can select on structure and ids, so leave those alone
+    let scripted = any_element(nodes, &|element| element.name == "style" || element.name == "script");
+    if options.collapse_groups && !scripted {
+        let referenced = referenced_ids(nodes);
+        collapse_groups(nodes, &referenced);
+    }
+    if options.merge_paths && !scripted {
+        merge_paths(nodes);
+    }
+    if options.minify_attributes {
+        minify_attributes(nodes, scripted);
+    }
+    if options.minify_attributes || options.precision.is_some() {
+        format_numbers(nodes, options.precision, true);
+    }
+}
+
+fn any_element(nodes: &[Node], predicate: &dyn Fn(&Element) -> bool) -> bool {
+    nodes.iter().any(|node| match node {
+        Node::Element(element) => predicate(element) || any_element(&element.children, predicate),
+        _ => false,
+    })
+}
+
+fn for_each_element(nodes: &mut [Node], f: &mut dyn FnMut(&mut Element)) {
+    for node in nodes {
+        if let Node::Element(element) = node {
+            f(element);
+            for_each_element(&mut element.children, f);
+        }
+    }
+}
+
+/// Drop indentation between tags, outside of text content elements
+fn strip_whitespace(nodes: &mut Vec<Node>) {
+    nodes.retain(|node| !matches!(node, Node::Text(text) if text.trim().is_empty()));
+    for node in nodes {
+        if let Node::Element(element) = node {
+            if !TEXT_CONTENT.contains(&element.name.as_str()) {
+                strip_whitespace(&mut element.children);
+            }
+        }
+    }
+}
+
+fn remove_comments(nodes: &mut Vec<Node>) {
+    nodes.retain(|node| !matches!(node, Node::Comment(comment) if !comment.starts_with('!')));
+    for node in nodes {
+        if let Node::Element(element) = node {
+            remove_comments(&mut element.children);
+        }
+    }
+}
+
+fn is_editor_name(name: &str) -> bool {
+    let name = name.strip_prefix("xmlns:").unwrap_or(name);
+    match name.split_once(':') {
+        Some((prefix, _)) => EDITOR_PREFIXES.contains(&prefix),
+        None => EDITOR_PREFIXES.contains(&name),
+    }
+}
+
+fn remove_metadata(nodes: &mut Vec<Node>) {
+    nodes.retain(|node| match node {
+        Node::Prolog(_) => false,
+        Node::Element(element) => {
+            let generated = element.name == "desc"
+                && element.children.iter().all(|child| match child {
+                    Node::Text(text) => {
+                        let text = text.trim();
+                        text.is_empty() || text.starts_with("Created with") || text.starts_with("Generated by")
+                    }
+                    _ => false,
+                });
+            element.name != "metadata" && !generated && !element.name.contains(':')
+        }
+        _ => true,
+    });
+    for node in nodes {
+        if let Node::Element(element) = node {
+            element.attrs.retain(|(key, _)| {
+                let editor = if key.starts_with("xmlns:") { is_editor_name(key) } else { key.contains(':') && is_editor_name(key) };
+                !editor && key != "data-name"
+            });
+            remove_metadata(&mut element.children);
+        }
+    }
+}
+
+fn collapse_groups(nodes: &mut Vec<Node>, referenced: &HashSet<String>) {
+    for node in nodes.iter_mut() {
+        if let Node::Element(element) = node {
+            collapse_groups(&mut element.children, referenced);
+        }
+    }
+
+    let mut collapsed = Vec::with_capacity(nodes.len());
+    for node in nodes.drain(..) {
+        let Node::Element(mut element) = node else {
+            collapsed.push(node);
+            continue;
+        };
+        let container = element.name == "g" || element.name == "defs";
+        if container && !element.has_element_children() && element.attr("id").is_none() {
+            continue;
+        }
+        if element.name == "g" && element.attrs.is_empty() {
+            collapsed.extend(element.children);
+            continue;
+        }
+        if element.name == "g" && element.children.len() == 1 && element.has_element_children() {
+            let Some(Node::Element(mut child)) = element.children.pop() else { unreachable!() };
+            if hoist_attributes(&element, &mut child, referenced) {
+                collapsed.push(Node::Element(child));
+                continue;
+            }
+            element.children.push(Node::Element(child));
+        }
+        collapsed.push(Node::Element(element));
+    }
+    *nodes = collapsed;
+}
+
+/// Move a group's attributes onto its only child; false if any of them can't move.
+/// Referenced children are left alone, since `<use>` copies of them must not pick up the group's attributes.
+fn hoist_attributes(group: &Element, child: &mut Element, referenced: &HashSet<String>) -> bool {
+    let movable = !child.attr("id").is_some_and(|id| referenced.contains(id)) && group.attrs.iter().all(|(key, _)| {
+        INHERITED.contains(&key.as_str())
+            || key == "transform"
+            || (key == "opacity" && child.attr("opacity").is_none())
+    });
+    if !movable {
+        return false;
+    }
+
+    for (key, value) in &group.attrs {
+        if key == "transform" {
+            let transform = match child.attr("transform") {
+                Some(inner) => format!("{} {}", value, inner),
+                None => value.clone(),
+            };
+            child.set_attr("transform", transform);
+        } else if child.attr(key).is_none() {
+            child.attrs.push((key.clone(), value.clone()));
+        }
+    }
+    true
+}
+
+fn merge_paths(nodes: &mut Vec<Node>) {
+    let mut merged: Vec<Node> = Vec::with_capacity(nodes.len());
+    // Parsed path data and bounds of the last node in `merged`, when it can take more paths
+    let mut open: Option<(Vec<Segment>, Bounds)> = None;
+
+    for node in nodes.drain(..) {
+        let Node::Element(mut element) = node else {
+            open = None;
+            merged.push(node);
+            continue;
+        };
+        merge_paths(&mut element.children);
+
+        let mergeable = element.name == "path"
+            && element.children.is_empty()
+            && element.attrs.iter().all(|(key, _)| !UNMERGEABLE.contains(&key.as_str()));
+        let parsed = element
+            .attr("d")
+            .filter(|_| mergeable)
+            .and_then(parse_path)
+            .and_then(|segments| Some((path_bounds(&segments)?, segments)));
+        let Some((bounds, mut segments)) = parsed else {
+            open = None;
+            merged.push(Node::Element(element));
+            continue;
+        };
+
+        if let (Some((previous, union)), Some(Node::Element(last))) = (open.as_mut(), merged.last_mut()) {
+            if same_attributes_except_d(last, &element) && !union.overlaps(&bounds) {
+                // A leading relative moveto is absolute, so it stays correct mid-path once uppercased
+                segments[0].command = 'M';
+                previous.extend(segments);
+                *union = union.union(&bounds);
+                last.set_attr("d", write_path(previous, None));
+                continue;
+            }
+        }
+        open = Some((segments, bounds));
+        merged.push(Node::Element(element));
+    }
+    *nodes = merged;
+}
+
+fn same_attributes_except_d(a: &Element, b: &Element) -> bool {
+    let mut a: Vec<_> = a.attrs.iter().filter(|(key, _)| key != "d").collect();
+    let mut b: Vec<_> = b.attrs.iter().filter(|(key, _)| key != "d").collect();
+    a.sort();
+    b.sort();
+    a == b
+}
+
+fn minify_attributes(nodes: &mut [Node], scripted: bool) {
+    let referenced = referenced_ids(nodes);
+    let mut used_prefixes = HashSet::new();
+    let mut animated = false;
+    let mut has_text = false;
+    for_each_element(nodes, &mut |element| {
+        animated |= element.name.starts_with("animate") || element.name == "set";
+        has_text |= TEXT_CONTENT[..3].contains(&element.name.as_str());
+        for name in std::iter::once(&element.name).chain(element.attrs.iter().map(|(key, _)| key)) {
+            if let Some((prefix, _)) = name.split_once(':') {
+                if prefix != "xmlns" {
+                    used_prefixes.insert(prefix.to_string());
+                }
+            }
+        }
+    });
+
+    let keep_ids = scripted || animated;
+    for_each_element(nodes, &mut |element| {
+        element.attrs.retain_mut(|(key, value)| {
+            if !matches!(key.as_str(), "d" | "points") {
+                *value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+            }
+            match key.as_str() {
+                "version" | "baseProfile" | "enable-background" => false,
+                "xml:space" => has_text,
+                "id" => keep_ids || referenced.contains(value.as_str()),
+                "class" | "style" | "transform" => !value.is_empty(),
+                _ => match key.strip_prefix("xmlns:") {
+                    Some(prefix) => used_prefixes.contains(prefix),
+                    None => true,
+                },
+            }
+        });
+    });
+}
+
+/// Ids referenced anywhere as `url(#id)` or `href="#id"`
+fn referenced_ids(nodes: &[Node]) -> HashSet<String> {
+    fn collect(nodes: &[Node], referenced: &mut HashSet<String>) {
+        for node in nodes {
+            let Node::Element(element) = node else { continue };
+            for (key, value) in &element.attrs {
+                if key == "href" || key == "xlink:href" {
+                    if let Some(id) = value.strip_prefix('#') {
+                        referenced.insert(id.to_string());
+                    }
+                }
+                let mut rest = value.as_str();
+                while let Some(start) = rest.find("url(") {
+                    rest = &rest[start + 4..];
+                    let end = rest.find(')').unwrap_or(rest.len());
+                    let id = rest[..end].trim().trim_matches(|c| c == '"' || c == '\'');
+                    if let Some(id) = id.strip_prefix('#') {
+                        referenced.insert(id.to_string());
+                    }
+                    rest = &rest[end..];
+                }
+            }
+            collect(&element.children, referenced);
+        }
+    }
+
+    let mut referenced = HashSet::new();
+    collect(nodes, &mut referenced);
+    referenced
+}
+
+/// Rewrite path data and numeric attributes, rounding to `precision` when set
+fn format_numbers(nodes: &mut [Node], precision: Option<u8>, root: bool) {
+    for node in nodes {
+        let Node::Element(element) = node else { continue };
+        let is_root = root && element.name == "svg";
+        for (key, value) in element.attrs.iter_mut() {
+            match key.as_str() {
+                "d" => {
+                    if let Some(segments) = parse_path(value) {
+                        *value = write_path(&segments, precision);
+                    }
+                }
+                "points" => {
+                    if let Some(numbers) = parse_numbers(value) {
+                        let numbers: Vec<String> = numbers.into_iter().map(|n| format_number(n, precision)).collect();
+                        *value = join_numbers(&numbers);
+                    }
+                }
+                // The root size and viewBox are kept exact; they define the icon's box
+                _ if is_root => {}
+                key if NUMERIC.contains(&key) => {
+                    if let Ok(number) = value.trim().parse::<f64>() {
+                        *value = format_number(number, precision);
+                    }
+                }
+                _ => {}
+            }
+        }
+        format_numbers(&mut element.children, precision, false);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Segment {
+    command: char,
+    args: Vec<f64>,
+}
+
+fn arg_count(command: char) -> Option<usize> {
+    match command.to_ascii_uppercase() {
+        'Z' => Some(0),
+        'H' | 'V' => Some(1),
+        'M' | 'L' | 'T' => Some(2),
+        'S' | 'Q' => Some(4),
+        'C' => Some(6),
+        'A' => Some(7),
+        _ => None,
+    }
+}
+
+/// Parse path data into one segment per command, expanding implicit repeats
+fn parse_path(d: &str) -> Option<Vec<Segment>> {
+    let bytes = d.as_bytes();
+    let mut i = 0;
+    let mut segments = Vec::new();
+    let mut command: Option<char> = None;
+
+    let skip_separators = |i: &mut usize| {
+        while *i < bytes.len() && (bytes[*i].is_ascii_whitespace() || bytes[*i] == b',') {
+            *i += 1;
+        }
+    };
+
+    loop {
+        skip_separators(&mut i);
+        if i >= bytes.len() {
+            break;
+        }
+        let c = bytes[i] as char;
+        if c.is_ascii_alphabetic() {
+            arg_count(c)?;
+            command = Some(c);
+            i += 1;
+        } else if command.is_none() {
+            return None;
+        }
+
+        let current = command?;
+        let count = arg_count(current)?;
+        let mut args = Vec::with_capacity(count);
+        for n in 0..count {
+            skip_separators(&mut i);
+            // Arc flags are single digits that may be written without separators
+            if current.eq_ignore_ascii_case(&'a') && (n == 3 || n == 4) {
+                match bytes.get(i) {
+                    Some(b'0') => args.push(0.0),
+                    Some(b'1') => args.push(1.0),
+                    _ => return None,
+                }
+                i += 1;
+            } else {
+                let (number, len) = scan_number(&d[i..])?;
+                args.push(number);
+                i += len;
+            }
+        }
+        segments.push(Segment { command: current, args });
+
+        match current {
+            'Z' | 'z' => command = None,
+            'M' => command = Some('L'),
+            'm' => command = Some('l'),
+            _ => {}
+        }
+    }
+    if segments.first().is_some_and(|segment| segment.command.eq_ignore_ascii_case(&'m')) {
+        Some(segments)
+    } else {
+        None
+    }
+}
+
+/// Read one number from the start of `s`, returning it with its length
+fn scan_number(s: &str) -> Option<(f64, usize)> {
+    let bytes = s.as_bytes();
+    let mut end = 0;
+    if matches!(bytes.first(), Some(b'+') | Some(b'-')) {
+        end += 1;
+    }
+    let mut dot = false;
+    while end < bytes.len() && (bytes[end].is_ascii_digit() || (bytes[end] == b'.' && !dot)) {
+        dot |= bytes[end] == b'.';
+        end += 1;
+    }
+    if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+        let mut exp = end + 1;
+        if matches!(bytes.get(exp), Some(b'+') | Some(b'-')) {
+            exp += 1;
+        }
+        if bytes.get(exp).is_some_and(u8::is_ascii_digit) {
+            end = exp;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+        }
+    }
+    s[..end].parse().ok().map(|number| (number, end))
+}
+
+fn parse_numbers(value: &str) -> Option<Vec<f64>> {
+    let mut numbers = Vec::new();
+    let mut rest = value.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+    while !rest.is_empty() {
+        let (number, len) = scan_number(rest)?;
+        numbers.push(number);
+        rest = rest[len..].trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+    }
+    Some(numbers)
+}
+
+/// Write path data compactly. Relative coordinates are rounded against the rounded
+/// position so errors don't accumulate along the path.
+fn write_path(segments: &[Segment], precision: Option<u8>) -> String {
+    let round = |value: f64| match precision {
+        Some(digits) => {
+            let factor = 10f64.powi(digits as i32);
+            (value * factor).round() / factor
+        }
+        None => value,
+    };
+
+    let mut out = String::new();
+    let (mut exact, mut rounded) = ((0.0, 0.0), (0.0, 0.0));
+    let (mut exact_start, mut rounded_start) = ((0.0, 0.0), (0.0, 0.0));
+    let mut previous: Option<char> = None;
+    let mut last_number = String::new();
+
+    for segment in segments {
+        let command = segment.command;
+        let relative = command.is_ascii_lowercase();
+        let (dx, dy) = if relative { (exact.0 - rounded.0, exact.1 - rounded.1) } else { (0.0, 0.0) };
+
+        let mut args = segment.args.clone();
+        match command.to_ascii_uppercase() {
+            'Z' => {}
+            'H' => args[0] = round(args[0] + dx),
+            'V' => args[0] = round(args[0] + dy),
+            'A' => {
+                for arg in &mut args[..3] {
+                    *arg = round(*arg);
+                }
+                args[5] = round(args[5] + dx);
+                args[6] = round(args[6] + dy);
+            }
+            _ => {
+                for pair in args.chunks_mut(2) {
+                    pair[0] = round(pair[0] + dx);
+                    pair[1] = round(pair[1] + dy);
+                }
+            }
+        }
+
+        // Track where both the exact and the rounded paths end up
+        let advance = |current: (f64, f64), args: &[f64], start: (f64, f64)| -> (f64, f64) {
+            let base = if relative { current } else { (0.0, 0.0) };
+            match command.to_ascii_uppercase() {
+                'Z' => start,
+                'H' => (base.0 + args[0], current.1),
+                'V' => (current.0, base.1 + args[0]),
+                _ => (base.0 + args[args.len() - 2], base.1 + args[args.len() - 1]),
+            }
+        };
+        exact = advance(exact, &segment.args, exact_start);
+        rounded = advance(rounded, &args, rounded_start);
+        if command.eq_ignore_ascii_case(&'m') {
+            exact_start = exact;
+            rounded_start = rounded;
+        }
+
+        // Repeated commands can be left implicit, as can a lineto right after a moveto
+        let implicit = match previous {
+            Some(p) => (p == command && !p.eq_ignore_ascii_case(&'m')) || (p == 'M' && command == 'L') || (p == 'm' && command == 'l'),
+            None => false,
+        };
+        if !implicit {
+            out.push(command);
+            last_number.clear();
+        }
+        let arc = command.eq_ignore_ascii_case(&'a');
+        for (n, arg) in args.iter().enumerate() {
+            let number = if arc && (n == 3 || n == 4) {
+                format!("{}", *arg as u8)
+            } else {
+                format_number(*arg, None)
+            };
+            // A flag is always one digit, so whatever follows it needs no separator
+            let after_flag = arc && (n == 4 || n == 5);
+            if !after_flag && needs_separator(&last_number, &number) {
+                out.push(' ');
+            }
+            out.push_str(&number);
+            last_number = number;
+        }
+        previous = Some(command);
+    }
+    out
+}
+
+/// Shortest decimal form: no trailing zeros, no leading zero before the point
+fn format_number(value: f64, precision: Option<u8>) -> String {
+    let mut text = match precision {
+        Some(digits) => {
+            let text = format!("{:.*}", digits as usize, value);
+            if text.contains('.') {
+                text.trim_end_matches('0').trim_end_matches('.').to_string()
+            } else {
+                text
+            }
+        }
+        None => value.to_string(),
+    };
+    if text == "-0" {
+        text = "0".to_string();
+    }
+    if let Some(rest) = text.strip_prefix("0.") {
+        text = format!(".{}", rest);
+    } else if let Some(rest) = text.strip_prefix("-0.") {
+        text = format!("-.{}", rest);
+    }
+    text
+}
+
+fn needs_separator(previous: &str, next: &str) -> bool {
+    let joins = next.starts_with('-') || (next.starts_with('.') && previous.contains('.'));
+    !previous.is_empty() && !joins
+}
+
+fn join_numbers(numbers: &[String]) -> String {
+    let mut out = String::new();
+    let mut previous = "";
+    for number in numbers {
+        if needs_separator(previous, number) {
+            out.push(' ');
+        }
+        out.push_str(number);
+        previous = number;
+    }
+    out
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bounds {
+    min: (f64, f64),
+    max: (f64, f64),
+}
+
+impl Bounds {
+    fn include(&mut self, x: f64, y: f64) {
+        self.min = (self.min.0.min(x), self.min.1.min(y));
+        self.max = (self.max.0.max(x), self.max.1.max(y));
+    }
+
+    fn union(&self, other: &Bounds) -> Bounds {
+        let mut bounds = *self;
+        bounds.include(other.min.0, other.min.1);
+        bounds.include(other.max.0, other.max.1);
+        bounds
+    }
+
+    /// Touching counts as overlapping, since shared edges can change antialiasing
+    fn overlaps(&self, other: &Bounds) -> bool {
+        self.min.0 <= other.max.0 && other.min.0 <= self.max.0 && self.min.1 <= other.max.1 && other.min.1 <= self.max.1
+    }
+}
+
+/// Box around every point and control point; arcs are padded by their radii
+fn path_bounds(segments: &[Segment]) -> Option<Bounds> {
+    let mut bounds: Option<Bounds> = None;
+    let mut include = |x: f64, y: f64| match bounds.as_mut() {
+        Some(bounds) => bounds.include(x, y),
+        None => bounds = Some(Bounds { min: (x, y), max: (x, y) }),
+    };
+
+    let (mut current, mut start) = ((0.0, 0.0), (0.0, 0.0));
+    for segment in segments {
+        let base = if segment.command.is_ascii_lowercase() { current } else { (0.0, 0.0) };
+        let args = &segment.args;
+        let end = match segment.command.to_ascii_uppercase() {
+            'Z' => start,
+            'H' => (base.0 + args[0], current.1),
+            'V' => (current.0, base.1 + args[0]),
+            'A' => {
+                let end = (base.0 + args[5], base.1 + args[6]);
+                let (rx, ry) = (args[0].abs(), args[1].abs());
+                let radius = rx.max(ry);
+                for (x, y) in [current, end] {
+                    include(x - radius, y - radius);
+                    include(x + radius, y + radius);
+                }
+                end
+            }
+            _ => {
+                for pair in args.chunks(2) {
+                    include(base.0 + pair[0], base.1 + pair[1]);
+                }
+                (base.0 + args[args.len() - 2], base.1 + args[args.len() - 1])
+            }
+        };
+        include(end.0, end.1);
+        current = end;
+        if segment.command.eq_ignore_ascii_case(&'m') {
+            start = end;
+        }
+    }
+    bounds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optimize_svg() {
+        let svg = r##"<?xml version="1.0" encoding="UTF-8"?>
+<!-- Generator: Adobe Illustrator -->
+<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" xmlns:sketch="http://www.bohemiancoding.com/sketch/ns" version="1.1" viewBox="0 0 24 24" width="24" height="24" xml:space="preserve">
+  <desc>Created with Sketch.</desc>
+  <metadata><rdf:RDF/></metadata>
+  <g>
+    <g fill="#000" transform="translate(1 1)">
+      <path id="unused" data-name="Layer 1" sketch:type="MSShapeGroup" d="M 2.00001 2 L 10.123456 2.5 L 10 10 Z"/>
+    </g>
+    <path fill="#f00" d="M 14,14 l 0.3333,0.3333 l 0.3333,0.3333 l 0.3333,0.3333 z"/>
+    <path fill="#f00" d="m 20 20 h 2 v 2 z"/>
+    <linearGradient id="g"/>
+    <rect width="4.00049" height="2" fill="url(#g)"/>
+  </g>
+  <!--! Keep this notice -->
+</svg>"##;
+        let (optimized, stats) = optimize_svg(svg, &OptimizeOptions::default());
+        assert_eq!(
+            optimized,
+            concat!(
+                r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" width="24" height="24">"##,
+                r##"<path d="M2 2 10.123 2.5 10 10Z" fill="#000" transform="translate(1 1)"/>"##,
+                r##"<path fill="#f00" d="M14 14l.333.333.334.334.333.333zM20 20h2v2z"/>"##,
+                r##"<linearGradient id="g"/><rect width="4" height="2" fill="url(#g)"/>"##,
+                r##"<!--! Keep this notice --></svg>"##,
+            )
+        );
+        assert_eq!(stats.files, 1);
+        assert_eq!(stats.original_bytes, svg.len());
+        assert_eq!(stats.optimized_bytes, optimized.len());
+
+        // Overlapping paths and stylesheet-dependent structure are left unmerged
+        let body = r#"<style>g path{fill:red}</style><g><path d="M0 0h4v4z"/></g><path d="M2 2h4v4z"/>"#;
+        let mut options = OptimizeOptions::default();
+        options.skip("precision".parse().unwrap());
+        assert_eq!(optimize_svg(body, &options).0, body);
+        let body = r#"<path d="M0 0h4v4z"/><path d="M2 2h4v4z"/>"#;
+        assert_eq!(optimize_svg(body, &options).0, body);
+        // `<use>` copies of a referenced child must not inherit the group's attributes
+        let body = r##"<g fill="red"><path id="p" d="M0 0h4v4z"/></g><use href="#p" x="8"/>"##;
+        assert_eq!(optimize_svg(body, &options).0, body);
+        assert_eq!(optimize_svg("<path d=", &options).0, "<path d=");
+    }
+}
@@ -0,0 +1,168 @@
+use std::error::Error;
+use usvg::tiny_skia_path::{Path, PathStroker, Stroke, Transform};
+use usvg::{FillRule, Group, Node, Paint, Tree};
+
+/// Paint of a filled or stroked path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+pub(crate) enum OutlineColor {
+    /// `currentColor`, left to the caller
+    Current,
+    Rgb(u8, u8, u8),
+}
+
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub(crate) struct OutlineFill {
+    pub(crate) color: OutlineColor,
+    pub(crate) opacity: f32,
+    pub(crate) rule: FillRule,
+}
+
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub(crate) struct OutlineStroke {
+    pub(crate) color: OutlineColor,
+    pub(crate) opacity: f32,
+    /// Width, caps and joins in the path's own units
+    pub(crate) style: Stroke,
+}
+
+/// A visible path with every transform above it applied
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub(crate) struct OutlinePath {
+    /// Path data in the target space
+    pub(crate) data: Path,
+    pub(crate) fill: Option<OutlineFill>,
+    pub(crate) stroke: Option<OutlineStroke>,
+    local: Path,
+    transform: Transform,
+}
+
+#[allow(dead_code)]
+impl OutlinePath {
+    /// How much the transforms scale lengths such as stroke widths
+    pub(crate) fn scale(&self) -> f32 {
+        let t = self.transform;
+        (t.sx * t.sy - t.kx * t.ky).abs().sqrt()
+    }
+
+    /// The stroke as a filled outline in the target space, for targets that can only fill
+    pub(crate) fn stroke_outline(&self) -> Option<Path> {
+        let stroke = self.stroke.as_ref()?;
+        let resolution = PathStroker::compute_resolution_scale(&self.transform);
+        self.local.stroke(&stroke.style, resolution)?.transform(self.transform)
+    }
+}
+
+/// Paths of an SVG document as usvg resolves them: shapes, `<use>`, CSS and
+/// transforms are applied, leaving plain moves, lines, curves and paints
+#[derive(Debug, Default)]
+#[allow(dead_code)]
+pub(crate) struct Outline {
+    /// Size of the document the paths are drawn in, before the target transform
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+    pub(crate) paths: Vec<OutlinePath>,
+    /// Constructs that were dropped or could only be partly converted
+    pub(crate) warnings: Vec<String>,
+}
+
+/// Parse an SVG document and flatten its visible paths, mapping them through `target`
+#[allow(dead_code)]
+pub(crate) fn outline(svg: &str, target: Transform) -> Result<Outline, Box<dyn Error>> {
+    let options = usvg::Options::default();
+    let tree = Tree::from_str(svg, &options)?;
+    let mut outline = Outline { width: tree.size().width(), height: tree.size().height(), ..Default::default() };
+    let mut paths = Vec::new();
+    collect(tree.root(), 1.0, &mut paths, &mut outline.warnings);
+
+    // usvg draws `currentColor` black; parsing again with it white shows which paints follow it
+    let recolored = match svg.contains("currentColor") {
+        true => Some(Tree::from_str(&svg.replace("currentColor", "#fff"), &options)?),
+        false => None,
+    };
+    let mut recolored_paths = Vec::new();
+    if let Some(tree) = &recolored {
+        collect(tree.root(), 1.0, &mut recolored_paths, &mut Vec::new());
+    }
+
+    for (i, &(path, opacity)) in paths.iter().enumerate() {
+        let transform = path.abs_transform().post_concat(target);
+        let Some(data) = path.data().clone().transform(transform) else {
+            continue;
+        };
+        let other = recolored_paths.get(i).map(|&(path, _)| path);
+        let fill = path.fill().map(|fill| OutlineFill {
+            color: color(fill.paint(), other.and_then(|other| other.fill()).map(|fill| fill.paint()), &mut outline.warnings),
+            opacity: fill.opacity().get() * opacity,
+            rule: fill.rule(),
+        });
+        let stroke = path.stroke().map(|stroke| {
+            if stroke.dasharray().is_some() {
+                warn(&mut outline.warnings, "dashed strokes are not supported and are drawn solid");
+            }
+            OutlineStroke {
+                color: color(stroke.paint(), other.and_then(|other| other.stroke()).map(|stroke| stroke.paint()), &mut outline.warnings),
+                opacity: stroke.opacity().get() * opacity,
+                style: stroke.to_tiny_skia(),
+            }
+        });
+        outline.paths.push(OutlinePath { data, fill, stroke, local: path.data().clone(), transform });
+    }
+    if svg.contains("<text") {
+        warn(&mut outline.warnings, "text is not supported; convert it to paths");
+    }
+    Ok(outline)
+}
+
+/// Visible paths in drawing order, with the opacity of the groups above them
+fn collect<'a>(group: &'a Group, opacity: f32, paths: &mut Vec<(&'a usvg::Path, f32)>, warnings: &mut Vec<String>) {
+    if group.clip_path().is_some() {
+        warn(warnings, "clip paths are not supported and were ignored");
+    }
+    if group.mask().is_some() {
+        warn(warnings, "masks are not supported and were ignored");
+    }
+    if !group.filters().is_empty() {
+        warn(warnings, "filters are not supported and were ignored");
+    }
+    let opacity = opacity * group.opacity().get();
+    for node in group.children() {
+        match node {
+            Node::Group(group) => collect(group, opacity, paths, warnings),
+            Node::Path(path) if path.is_visible() => paths.push((path, opacity)),
+            Node::Image(_) => warn(warnings, "images are not supported and were removed"),
+            _ => {}
+        }
+    }
+}
+
+/// Solid color of a paint; `recolored` is the same paint with `currentColor` swapped
+fn color(paint: &Paint, recolored: Option<&Paint>, warnings: &mut Vec<String>) -> OutlineColor {
+    let rgb = |paint: &Paint| match paint {
+        Paint::Color(color) => Some((color.red, color.green, color.blue)),
+        Paint::LinearGradient(gradient) => gradient.stops().first().map(|stop| stop.color()).map(|c| (c.red, c.green, c.blue)),
+        Paint::RadialGradient(gradient) => gradient.stops().first().map(|stop| stop.color()).map(|c| (c.red, c.green, c.blue)),
+        Paint::Pattern(_) => None,
+    };
+    match paint {
+        Paint::LinearGradient(_) | Paint::RadialGradient(_) => {
+            warn(warnings, "gradients are not supported and are drawn with their first stop color")
+        }
+        Paint::Pattern(_) => warn(warnings, "patterns are not supported and are drawn black"),
+        Paint::Color(_) => {}
+    }
+    let (r, g, b) = rgb(paint).unwrap_or((0, 0, 0));
+    if recolored.and_then(rgb).is_some_and(|other| other != (r, g, b)) {
+        return OutlineColor::Current;
+    }
+    OutlineColor::Rgb(r, g, b)
+}
+
+fn warn(warnings: &mut Vec<String>, warning: &str) {
+    if !warnings.iter().any(|existing| existing == warning) {
+        warnings.push(warning.to_string());
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::BTreeSet;
+use crate::converters::jsx::{jsx_attribute_name, push_text};
+use crate::converters::stylesheet::Stylesheet;
+use crate::converters::utils::escape_attribute;
+use crate::converters::xml::{parse, Element, Node};
+
+/// SVG elements and the react-native-svg components that draw them
+const COMPONENTS: &[(&str, &str)] = &[
+    ("svg", "Svg"),
+    ("g", "G"),
+    ("path", "Path"),
+    ("circle", "Circle"),
+    ("ellipse", "Ellipse"),
+    ("line", "Line"),
+    ("polygon", "Polygon"),
+    ("polyline", "Polyline"),
+    ("rect", "Rect"),
+    ("text", "Text"),
+    ("tspan", "TSpan"),
+    ("textPath", "TextPath"),
+    ("use", "Use"),
+    ("image", "Image"),
+    ("symbol", "Symbol"),
+    ("defs", "Defs"),
+    ("linearGradient", "LinearGradient"),
+    ("radialGradient", "RadialGradient"),
+    ("stop", "Stop"),
+    ("clipPath", "ClipPath"),
+    ("pattern", "Pattern"),
+    ("mask", "Mask"),
+    ("marker", "Marker"),
+    ("filter", "Filter"),
+    ("feBlend", "FeBlend"),
+    ("feColorMatrix", "FeColorMatrix"),
+    ("feComposite", "FeComposite"),
+    ("feFlood", "FeFlood"),
+    ("feGaussianBlur", "FeGaussianBlur"),
+    ("feMerge", "FeMerge"),
+    ("feMergeNode", "FeMergeNode"),
+    ("feOffset", "FeOffset"),
+];
+
+/// Whether a converted module imports `name`, which then can't also name its component
+#[allow(dead_code)]
+pub fn imports_name(name: &str) -> bool {
+    matches!(name, "React" | "SvgProps") || COMPONENTS.iter().any(|(_, component)| *component == name)
+}
+
+/// Elements without a visual effect, dropped without a warning
+const IGNORED_ELEMENTS: &[&str] = &["title", "desc", "metadata", "style"];
+
+/// CSS properties that react-native-svg accepts as props
+const PRESENTATION: &[&str] = &[
+    "fill", "fill-opacity", "fill-rule", "stroke", "stroke-width", "stroke-opacity", "stroke-linecap",
+    "stroke-linejoin", "stroke-miterlimit", "stroke-dasharray", "stroke-dashoffset", "opacity", "clip-path",
+    "clip-rule", "mask", "filter", "font-size", "font-family", "font-weight", "font-style", "font-variant",
+    "text-anchor", "text-decoration", "letter-spacing", "word-spacing", "stop-color", "stop-opacity", "display",
+    "visibility", "transform", "color", "marker-start", "marker-mid", "marker-end", "vector-effect",
+    "alignment-baseline", "baseline-shift", "font-stretch", "kerning",
+];
+
+/// SVG rendering properties react-native-svg has no prop for; other CSS, such as the layout
+/// properties editors leave behind, does not affect SVG and is dropped silently
+const UNSUPPORTED_PROPERTIES: &[&str] = &["mix-blend-mode", "mask-type", "paint-order", "dominant-baseline", "writing-mode"];
+
+/// Elements whose text content is drawn
+const TEXT_ELEMENTS: &[&str] = &["text", "tspan", "textPath"];
+
+/// react-native-svg markup converted from an SVG document
+#[derive(Debug, Default)]
+pub struct NativeSvg {
+    /// JSX for the root `<Svg>`, spreading `props` onto it
+    pub jsx: String,
+    /// Components to import from `react-native-svg`, sorted
+    pub components: Vec<&'static str>,
+    /// Constructs that were dropped or could only be partly converted
+    pub warnings: Vec<String>,
+}
+
+/// Convert an SVG document for react-native-svg: map elements to components,
+/// turn `<style>` rules and `style` attributes into props, and drop what it cannot draw.
+#[allow(dead_code)]
+pub fn svg_to_native(content: &str) -> NativeSvg {
+    let Some(nodes) = parse(content) else {
+        return NativeSvg {
+            jsx: "<Svg {...props} />".to_string(),
+            components: vec!["Svg"],
+            warnings: vec!["markup is not well-formed XML".to_string()],
+        };
+    };
+    let stylesheet = Stylesheet::collect(&nodes);
+    let mut converter = Converter { warnings: stylesheet.warnings.clone(), stylesheet, ..Converter::default() };
+    let mut jsx = String::new();
+    for node in &nodes {
+        if let Node::Element(element) = node {
+            converter.write_element(element, Some(4), true, &mut jsx);
+        }
+    }
+    NativeSvg {
+        jsx: jsx.trim_start().to_string(),
+        components: converter.components.into_iter().collect(),
+        warnings: converter.warnings,
+    }
+}
+
+/// A react-native-svg component module, with unsupported constructs listed in a comment
+#[allow(dead_code)]
+pub fn svg_to_react_native(content: &str, name: &str, snippet: bool, typescript: bool) -> String {
+    let native = svg_to_native(content);
+    let signature = if typescript { "props: SvgProps" } else { "props" };
+    let mut code = String::new();
+    for warning in &native.warnings {
+        code.push_str(&format!("// react-native-svg: {}\n", warning));
+    }
+    code.push_str(&format!(
+        "export function {}({}) {{\n  return (\n    {}\n  );\n}}",
+        name, signature, native.jsx
+    ));
+    if snippet {
+        return code;
+    }
+
+    let mut named: Vec<&str> = native.components.iter().copied().filter(|c| *c != "Svg").collect();
+    if typescript {
+        named.insert(0, "type SvgProps");
+    }
+    let imports = if named.is_empty() {
+        "import Svg from 'react-native-svg';".to_string()
+    } else {
+        format!("import Svg, {{ {} }} from 'react-native-svg';", named.join(", "))
+    };
+    format!("import * as React from 'react';\n{}\n\n{}\n\nexport default {};\n", imports, code, name)
+}
+
+#[derive(Default)]
+struct Converter {
+    stylesheet: Stylesheet,
+    components: BTreeSet<&'static str>,
+    warnings: Vec<String>,
+}
+
+impl Converter {
+    fn warn(&mut self, warning: String) {
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    /// Props for an element: its attributes, then matching CSS rules by specificity, then its `style`
+    fn attributes(&mut self, element: &Element) -> Vec<(String, String)> {
+        let mut attrs: Vec<(String, String)> = Vec::new();
+        for (key, value) in &element.attrs {
+            let key = match key.as_str() {
+                "class" | "style" | "version" | "xmlns" | "enable-background" => continue,
+                // `xlinkHref` is deprecated in react-native-svg
+                "href" | "xlink:href" => "href",
+                key if key.starts_with("data-") || key.contains(':') => continue,
+                key => key,
+            };
+            attrs.push((key.to_string(), value.clone()));
+        }
+
+        let mut unsupported = Vec::new();
+        for (property, value) in self.stylesheet.declarations(element).into_iter().flat_map(expand_shorthand) {
+            if PRESENTATION.contains(&property.as_str()) {
+                let value = escape_attribute(&value);
+                match attrs.iter_mut().find(|(key, _)| *key == property) {
+                    Some(attr) => attr.1 = value,
+                    None => attrs.push((property, value)),
+                }
+            } else if UNSUPPORTED_PROPERTIES.contains(&property.as_str()) {
+                unsupported.push(property);
+            }
+        }
+        for property in unsupported {
+            self.warn(format!("CSS property `{}` is not supported and was dropped", property));
+        }
+
+        attrs
+            .into_iter()
+            .map(|(key, value)| (if key == "href" { key } else { jsx_attribute_name(&key) }, value))
+            .collect()
+    }
+
+    /// Write an element at `indent` spaces, or inline inside text content when `indent` is `None`
+    fn write_element(&mut self, element: &Element, indent: Option<usize>, root: bool, out: &mut String) {
+        let Some(&(_, component)) = COMPONENTS.iter().find(|(name, _)| *name == element.name) else {
+            // Namespaced elements are editor data such as `sodipodi:namedview`
+            if !IGNORED_ELEMENTS.contains(&element.name.as_str()) && !element.name.contains(':') {
+                self.warn(format!("<{}> is not supported and was removed", element.name));
+            }
+            return;
+        };
+        self.components.insert(component);
+
+        if let Some(indent) = indent {
+            out.push('\n');
+            out.push_str(&" ".repeat(indent));
+        }
+        out.push('<');
+        out.push_str(component);
+        for (key, value) in self.attributes(element) {
+            out.push_str(&format!(" {}=\"{}\"", key, value.replace('"', "&quot;")));
+        }
+        if root {
+            out.push_str(" {...props}");
+        }
+
+        let text = TEXT_ELEMENTS.contains(&element.name.as_str());
+        let mut children = String::new();
+        for child in &element.children {
+            match child {
+                Node::Element(child) => self.write_element(child, if text { None } else { indent.map(|i| i + 2) }, false, &mut children),
+                Node::Text(content) if text => push_text(content, &mut children),
+                Node::Text(content) if !content.trim().is_empty() => {
+                    self.warn(format!("text outside <text> was removed: `{}`", content.trim()));
+                }
+                Node::CData(_) if element.name != "style" => self.warn("CDATA content was removed".to_string()),
+                _ => {}
+            }
+        }
+
+        if children.is_empty() {
+            out.push_str(" />");
+            return;
+        }
+        out.push('>');
+        out.push_str(&children);
+        if let (Some(indent), false) = (indent, text) {
+            out.push('\n');
+            out.push_str(&" ".repeat(indent));
+        }
+        out.push_str("</");
+        out.push_str(component);
+        out.push('>');
+    }
+}
+
+/// `marker` sets all three marker props
+fn expand_shorthand((property, value): (String, String)) -> Vec<(String, String)> {
+    if property == "marker" {
+        return ["marker-start", "marker-mid", "marker-end"].map(|marker| (marker.to_string(), value.clone())).to_vec();
+    }
+    vec![(property, value)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_svg_to_native() {
+        let svg = r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" viewBox="0 0 24 24">
+  <title>Logo</title>
+  <style>.a{fill:#f00;stroke-width:2} #b.a{fill:#0f0} g > .a{fill:blue} @media (prefers-color-scheme:dark){.a{fill:#fff}}</style>
+  <defs>
+    <clipPath id="c"><rect width="24" height="24"/></clipPath>
+    <pattern id="p" width="4" height="4"><image xlink:href="data:image/png;base64,AA" width="4" height="4"/></pattern>
+  </defs>
+  <g clip-path="url(#c)" mask="url(#m)">
+    <path class="a" d="M0 0h24"/>
+    <path id="b" class="a" fill="#000" style="fill-opacity:.5;mix-blend-mode:multiply;line-height:1.25" d="M0 0v24"/>
+    <text font-size="4">a{b}<tspan>c</tspan></text>
+    <animate attributeName="opacity"/>
+  </g>
+</svg>"##;
+        let native = svg_to_native(svg);
+        assert_eq!(native.components, vec!["ClipPath", "Defs", "G", "Image", "Path", "Pattern", "Rect", "Svg", "TSpan", "Text"]);
+        assert!(native.jsx.starts_with("<Svg viewBox=\"0 0 24 24\" {...props}>\n      <Defs>\n        <ClipPath id=\"c\">"));
+        assert!(native.jsx.contains("<Image href=\"data:image/png;base64,AA\" width=\"4\" height=\"4\" />"));
+        assert!(native.jsx.contains("<G clipPath=\"url(#c)\" mask=\"url(#m)\">"));
+        assert!(native.jsx.contains("<Path d=\"M0 0h24\" fill=\"#f00\" strokeWidth=\"2\" />"));
+        assert!(native.jsx.contains("<Path id=\"b\" fill=\"#0f0\" d=\"M0 0v24\" strokeWidth=\"2\" fillOpacity=\".5\" />"));
+        assert!(native.jsx.contains("<Text fontSize=\"4\">a{'{'}b{'}'}<TSpan>c</TSpan></Text>"));
+        assert!(!native.jsx.contains("Title") && !native.jsx.contains("<Animate"));
+        assert_eq!(
+            native.warnings,
+            vec![
+                "CSS selector `g > .a` is too complex to inline",
+                "CSS at-rule `@media (prefers-color-scheme:dark)` was ignored",
+                "CSS property `mix-blend-mode` is not supported and was dropped",
+                "<animate> is not supported and was removed",
+            ]
+        );
+
+        let module = svg_to_react_native(svg, "Logo", false, true);
+        assert!(module.contains("import Svg, { type SvgProps, ClipPath, Defs, G, Image, Path, Pattern, Rect, TSpan, Text } from 'react-native-svg';"));
+        assert!(module.contains("// react-native-svg: <animate> is not supported and was removed\nexport function Logo(props: SvgProps) {"));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::converters::iconsets::IconTransform;
+
+/// Position and size of an icon's drawing area
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconBox {
+    pub left: f32,
+    pub top: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl IconBox {
+    #[allow(dead_code)]
+    pub fn new(left: f32, top: f32, width: f32, height: f32) -> Self {
+        IconBox { left, top, width, height }
+    }
+
+    /// Dimensions Iconify assumes when neither the icon nor its set provides them
+    #[allow(dead_code)]
+    pub fn iconify_default() -> Self {
+        IconBox::new(0.0, 0.0, 16.0, 16.0)
+    }
+
+    /// Parse an SVG `viewBox` attribute such as `0 0 24 24` or `0,0,24,24`
+    #[allow(dead_code)]
+    pub fn parse_view_box(value: &str) -> Option<Self> {
+        let parts: Vec<f32> = value
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|part| !part.is_empty())
+            .map(|part| part.parse().ok())
+            .collect::<Option<_>>()?;
+        match parts.as_slice() {
+            [left, top, width, height] => Some(IconBox::new(*left, *top, *width, *height)),
+            _ => None,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn view_box(&self) -> String {
+        format!("{} {} {} {}", self.left, self.top, self.width, self.height)
+    }
+}
+
+/// Icon body with transforms applied, ready to be wrapped in `<svg>`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedIcon {
+    pub body: String,
+    pub view_box: IconBox,
+}
+
+impl RenderedIcon {
+    /// Apply rotation and flips to an icon body, mirroring Iconify's `iconToSVG`
+    #[allow(dead_code)]
+    pub fn new(body: &str, icon_box: IconBox, transform: IconTransform) -> Self {
+        let mut view_box = icon_box;
+        let mut transformations = Vec::new();
+        let mut rotation = transform.rotate;
+
+        if transform.h_flip {
+            if transform.v_flip {
+                rotation += 2;
+            } else {
+                transformations.push(format!(
+                    "translate({} {})",
+                    view_box.width + view_box.left,
+                    0.0 - view_box.top
+                ));
+                transformations.push("scale(-1 1)".to_string());
+                view_box.left = 0.0;
+                view_box.top = 0.0;
+            }
+        } else if transform.v_flip {
+            transformations.push(format!(
+                "translate({} {})",
+                0.0 - view_box.left,
+                view_box.height + view_box.top
+            ));
+            transformations.push("scale(1 -1)".to_string());
+            view_box.left = 0.0;
+            view_box.top = 0.0;
+        }
+
+        rotation %= 4;
+        match rotation {
+            1 => {
+                let center = view_box.height / 2.0 + view_box.top;
+                transformations.insert(0, format!("rotate(90 {} {})", center, center));
+            }
+            2 => {
+                transformations.insert(0, format!(
+                    "rotate(180 {} {})",
+                    view_box.width / 2.0 + view_box.left,
+                    view_box.height / 2.0 + view_box.top
+                ));
+            }
+            3 => {
+                let center = view_box.width / 2.0 + view_box.left;
+                transformations.insert(0, format!("rotate(-90 {} {})", center, center));
+            }
+            _ => {}
+        }
+
+        if rotation % 2 == 1 {
+            std::mem::swap(&mut view_box.left, &mut view_box.top);
+            std::mem::swap(&mut view_box.width, &mut view_box.height);
+        }
+
+        let body = if transformations.is_empty() {
+            body.to_string()
+        } else {
+            format!("<g transform=\"{}\">{}</g>", transformations.join(" "), body)
+        };
+
+        RenderedIcon { body, view_box }
+    }
+
+    #[allow(dead_code)]
+    pub fn to_svg(&self) -> String {
+        format!(
+            "<svg viewBox=\"{}\" width=\"{}\" height=\"{}\" fill=\"currentColor\">{}</svg>",
+            self.view_box.view_box(),
+            self.view_box.width,
+            self.view_box.height,
+            self.body
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_transforms() {
+        let icon_box = IconBox::new(0.0, 0.0, 24.0, 16.0);
+        let plain = RenderedIcon::new("<path/>", icon_box, IconTransform::default());
+        assert_eq!(plain.to_svg(), "<svg viewBox=\"0 0 24 16\" width=\"24\" height=\"16\" fill=\"currentColor\"><path/></svg>");
+
+        let rotated = RenderedIcon::new("<path/>", icon_box, IconTransform { rotate: 1, ..Default::default() });
+        assert_eq!(rotated.body, "<g transform=\"rotate(90 8 8)\"><path/></g>");
+        assert_eq!(rotated.view_box, IconBox::new(0.0, 0.0, 16.0, 24.0));
+
+        let flipped = RenderedIcon::new("<path/>", IconBox::new(2.0, -1.0, 20.0, 20.0), IconTransform { h_flip: true, ..Default::default() });
+        assert_eq!(flipped.body, "<g transform=\"translate(22 1) scale(-1 1)\"><path/></g>");
+        assert_eq!(flipped.view_box.view_box(), "0 0 20 20");
+
+        let both = RenderedIcon::new("<path/>", icon_box, IconTransform { h_flip: true, v_flip: true, ..Default::default() });
+        assert_eq!(both.body, "<g transform=\"rotate(180 12 8)\"><path/></g>");
+    }
+}
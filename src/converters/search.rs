@@ -0,0 +1,199 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use crate::converters::iconsets::{IconSetJson, MAX_ALIAS_DEPTH};
+use crate::converters::svgl::SvgIcon;
+use crate::icon_generated::dx_icon::SearchField;
+
+/// Split text into lowercase alphanumeric search tokens
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+}
+
+#[derive(Default)]
+struct TermPostings {
+    /// Icon index -> best (lowest) field it matched through
+    icons: BTreeMap<u32, u8>,
+    sets: BTreeSet<u16>,
+}
+
+/// Accumulates icon sets and logos into a `SearchIndex` FlatBuffer at build time
+#[derive(Default)]
+pub struct SearchIndexWriter {
+    sets: Vec<(String, String)>,
+    icons: Vec<(u16, String)>,
+    terms: HashMap<String, TermPostings>,
+}
+
+impl SearchIndexWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_icon_set(&mut self, iconset: &IconSetJson) {
+        let set = self.add_set(&iconset.prefix, &iconset.info.name);
+        for token in tokenize(&iconset.prefix) {
+            self.add_set_term(token, set);
+        }
+        let set_terms = iconset
+            .info
+            .category
+            .iter()
+            .chain(&iconset.info.tags)
+            .flat_map(|text| tokenize(text))
+            .collect::<Vec<_>>();
+        for token in set_terms {
+            self.add_set_term(token, set);
+        }
+
+        let mut names: Vec<&String> = iconset
+            .icons
+            .iter()
+            .filter(|(_, icon)| !icon.hidden.unwrap_or(false))
+            .map(|(name, _)| name)
+            .collect();
+        names.sort();
+
+        let mut indices = HashMap::new();
+        for name in names {
+            let index = self.add_icon(set, name);
+            indices.insert(name.as_str(), index);
+        }
+
+        let mut aliases: Vec<&String> = iconset.aliases.keys().collect();
+        aliases.sort();
+        for alias in aliases {
+            // Alias names point at their source icon rather than adding duplicate results
+            if let Some(index) = source_icon(iconset, alias).and_then(|source| indices.get(source)) {
+                for token in tokenize(alias) {
+                    self.add_icon_term(token, *index, SearchField::Alias);
+                }
+            }
+        }
+
+        for (category, icons) in &iconset.categories {
+            for icon in icons {
+                if let Some(index) = indices.get(icon.as_str()) {
+                    for token in tokenize(category) {
+                        self.add_icon_term(token, *index, SearchField::Category);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn add_svgl(&mut self, prefix: &str, name: &str, icons: &[SvgIcon]) {
+        let set = self.add_set(prefix, name);
+        for token in tokenize(prefix).chain(tokenize(name)) {
+            self.add_set_term(token, set);
+        }
+
+        let mut sorted: Vec<&SvgIcon> = icons.iter().collect();
+        sorted.sort_by(|a, b| a.filename.cmp(&b.filename));
+        for icon in sorted {
+            self.add_icon(set, &icon.filename);
+        }
+    }
+
+    pub fn to_flatbuffer(&self) -> Vec<u8> {
+        use crate::icon_generated::dx_icon::{
+            SearchIcon, SearchIconArgs, SearchIndex, SearchIndexArgs, SearchSet, SearchSetArgs,
+            SearchTerm, SearchTermArgs,
+        };
+        use flatbuffers::FlatBufferBuilder;
+
+        let mut builder = FlatBufferBuilder::new();
+
+        let mut sets_vec = Vec::new();
+        for (prefix, name) in &self.sets {
+            let prefix = builder.create_string(prefix);
+            let name = builder.create_string(name);
+            sets_vec.push(SearchSet::create(&mut builder, &SearchSetArgs {
+                prefix: Some(prefix),
+                name: Some(name),
+            }));
+        }
+        let sets = builder.create_vector(&sets_vec);
+
+        let mut icons_vec = Vec::new();
+        for (set, name) in &self.icons {
+            let name = builder.create_string(name);
+            icons_vec.push(SearchIcon::create(&mut builder, &SearchIconArgs {
+                set: *set,
+                name: Some(name),
+            }));
+        }
+        let icons = builder.create_vector(&icons_vec);
+
+        // Sort terms for binary search by key
+        let mut keys: Vec<&String> = self.terms.keys().collect();
+        keys.sort();
+
+        let mut terms_vec = Vec::new();
+        for key in keys {
+            let postings = &self.terms[key];
+            let term = builder.create_string(key);
+            let icon_ids: Vec<u32> = postings.icons.keys().copied().collect();
+            let fields: Vec<SearchField> = postings.icons.values().map(|f| SearchField(*f)).collect();
+            let set_ids: Vec<u16> = postings.sets.iter().copied().collect();
+            let icons = builder.create_vector(&icon_ids);
+            let fields = builder.create_vector(&fields);
+            let sets = builder.create_vector(&set_ids);
+            terms_vec.push(SearchTerm::create(&mut builder, &SearchTermArgs {
+                term: Some(term),
+                icons: Some(icons),
+                fields: Some(fields),
+                sets: Some(sets),
+            }));
+        }
+        let terms = builder.create_vector(&terms_vec);
+
+        let index = SearchIndex::create(&mut builder, &SearchIndexArgs {
+            sets: Some(sets),
+            icons: Some(icons),
+            terms: Some(terms),
+        });
+
+        builder.finish(index, None);
+        builder.finished_data().to_vec()
+    }
+
+    fn add_set(&mut self, prefix: &str, name: &str) -> u16 {
+        let set = self.sets.len() as u16;
+        self.sets.push((prefix.to_string(), name.to_string()));
+        for token in tokenize(name) {
+            self.add_set_term(token, set);
+        }
+        set
+    }
+
+    fn add_icon(&mut self, set: u16, name: &str) -> u32 {
+        let index = self.icons.len() as u32;
+        self.icons.push((set, name.to_string()));
+        for token in tokenize(name) {
+            self.add_icon_term(token, index, SearchField::Name);
+        }
+        index
+    }
+
+    fn add_icon_term(&mut self, token: String, icon: u32, field: SearchField) {
+        let best = self.terms.entry(token).or_default().icons.entry(icon).or_insert(field.0);
+        *best = (*best).min(field.0);
+    }
+
+    fn add_set_term(&mut self, token: String, set: u16) {
+        self.terms.entry(token).or_default().sets.insert(set);
+    }
+}
+
+/// Follow an alias chain to the name of the icon that holds the body
+fn source_icon<'a>(iconset: &'a IconSetJson, name: &'a str) -> Option<&'a str> {
+    let mut current = name;
+    for _ in 0..=MAX_ALIAS_DEPTH {
+        if iconset.icons.contains_key(current) {
+            return Some(current);
+        }
+        current = &iconset.aliases.get(current)?.parent;
+    }
+    None
+}
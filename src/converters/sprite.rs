@@ -0,0 +1,203 @@
+use regex::{Captures, Regex};
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+use crate::converters::iconsets::IconSetJson;
+use crate::converters::render::IconBox;
+use crate::converters::utils::{root_attribute, svg_inner_content};
+
+static ID_ATTR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(\s)id\s*=\s*(["'])([^"']+)["']"#).unwrap());
+static URL_REF: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"url\(\s*(["']?)#([^"')\s]+)(["']?)\s*\)"#).unwrap());
+static HREF_REF: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(\s(?:xlink:)?href\s*=\s*)(["'])#([^"']+)["']"#).unwrap());
+static CLASS_ATTR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(\s)class\s*=\s*(["'])([^"']*)["']"#).unwrap());
+static STYLE_ELEMENT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)(<style\b[^>]*>)(.*?)(</style>)").unwrap());
+/// A declaration block without nested rules; whatever lies between two of them is selectors or at-rule preludes
+static DECLARATION_BLOCK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\{[^{}]*\}").unwrap());
+static SELECTOR_NAME: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"([.#])(-?[A-Za-z_][\w-]*)").unwrap());
+
+/// Combines icons into one `<svg>` of `<symbol>` elements, referenced with `<use href="#id">`
+#[derive(Debug, Default)]
+pub struct SpriteBuilder {
+    symbols: Vec<String>,
+    ids: HashSet<String>,
+}
+
+impl SpriteBuilder {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an icon body as a symbol; returns the symbol id, or `None` if it was already added
+    #[allow(dead_code)]
+    pub fn add(&mut self, id: &str, body: &str, view_box: IconBox) -> Option<String> {
+        let id = symbol_id(id);
+        if !self.ids.insert(id.clone()) {
+            return None;
+        }
+        self.symbols.push(format!(
+            "<symbol id=\"{}\" viewBox=\"{}\">{}</symbol>",
+            id,
+            view_box.view_box(),
+            namespace_ids(body, &id)
+        ));
+        Some(id)
+    }
+
+    /// Add a complete SVG document such as an svgl logo, keeping its viewBox
+    #[allow(dead_code)]
+    pub fn add_svg(&mut self, id: &str, document: &str) -> Option<String> {
+        let view_box = root_view_box(document)?;
+        self.add(id, svg_inner_content(document), view_box)
+    }
+
+    /// Add an icon or alias from an Iconify icon set as `prefix-name`
+    #[allow(dead_code)]
+    pub fn add_from_set(&mut self, iconset: &IconSetJson, name: &str) -> Option<String> {
+        let icon = iconset.render(name)?;
+        self.add(&format!("{}-{}", iconset.prefix, name), &icon.body, icon.view_box)
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    #[allow(dead_code)]
+    pub fn to_svg(&self) -> String {
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\">\n<defs>\n{}\n</defs>\n</svg>",
+            self.symbols.join("\n")
+        )
+    }
+}
+
+/// Markup that draws a symbol from a sprite served at `sprite_url` (empty for an inline sprite)
+#[allow(dead_code)]
+pub fn use_tag(sprite_url: &str, id: &str, view_box: IconBox) -> String {
+    format!(
+        "<svg viewBox=\"{}\" width=\"{}\" height=\"{}\"><use href=\"{}#{}\"/></svg>",
+        view_box.view_box(),
+        view_box.width,
+        view_box.height,
+        sprite_url,
+        symbol_id(id)
+    )
+}
+
+/// Turn `mdi:home` or `github_dark` into a valid fragment identifier
+fn symbol_id(id: &str) -> String {
+    id.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect()
+}
+
+fn root_view_box(document: &str) -> Option<IconBox> {
+    let size = |name: &str| root_attribute(document, name).and_then(|value| value.trim_end_matches("px").parse::<f32>().ok());
+    root_attribute(document, "viewBox")
+        .and_then(IconBox::parse_view_box)
+        .or_else(|| Some(IconBox::new(0.0, 0.0, size("width")?, size("height")?)))
+}
+
+/// Prefix every `id` and `class` in a body with the symbol id and update `url(#..)` and `href="#.."`
+/// references and `<style>` selectors, so gradients, clip paths and class rules from different icons
+/// can't collide in one document
+#[allow(dead_code)]
+pub fn namespace_ids(body: &str, prefix: &str) -> String {
+    let renamed: HashMap<&str, String> = ID_ATTR
+        .captures_iter(body)
+        .map(|caps| {
+            let id = caps.get(3).unwrap().as_str();
+            (id, format!("{}-{}", prefix, id))
+        })
+        .collect();
+    let classes: HashMap<&str, String> = CLASS_ATTR
+        .captures_iter(body)
+        .flat_map(|caps| caps.get(3).unwrap().as_str().split_whitespace())
+        .map(|class| (class, format!("{}-{}", prefix, class)))
+        .collect();
+    if renamed.is_empty() && classes.is_empty() {
+        return body.to_string();
+    }
+
+    let rename = |id: &str| renamed.get(id).cloned().unwrap_or_else(|| id.to_string());
+    let body = ID_ATTR.replace_all(body, |caps: &Captures| format!("{}id={}{}{}", &caps[1], &caps[2], rename(&caps[3]), &caps[2]));
+    let body = CLASS_ATTR.replace_all(&body, |caps: &Captures| {
+        let names: Vec<&str> = caps[3].split_whitespace().map(|class| classes[class].as_str()).collect();
+        format!("{}class={}{}{}", &caps[1], &caps[2], names.join(" "), &caps[2])
+    });
+    let body = STYLE_ELEMENT.replace_all(&body, |caps: &Captures| {
+        format!("{}{}{}", &caps[1], namespace_selectors(&caps[2], &renamed, &classes), &caps[3])
+    });
+    let body = URL_REF.replace_all(&body, |caps: &Captures| format!("url({}#{}{})", &caps[1], rename(&caps[2]), &caps[3]));
+    HREF_REF.replace_all(&body, |caps: &Captures| format!("{}{}#{}{}", &caps[1], &caps[2], rename(&caps[3]), &caps[2]))
+        .into_owned()
+}
+
+/// Rename the `.class` and `#id` selectors of a stylesheet, leaving declarations such as `#fff` alone
+fn namespace_selectors(css: &str, ids: &HashMap<&str, String>, classes: &HashMap<&str, String>) -> String {
+    let rename = |selectors: &str| {
+        SELECTOR_NAME
+            .replace_all(selectors, |caps: &Captures| {
+                let names = if &caps[1] == "." { classes } else { ids };
+                match names.get(&caps[2]) {
+                    Some(name) => format!("{}{}", &caps[1], name),
+                    None => caps[0].to_string(),
+                }
+            })
+            .into_owned()
+    };
+    let mut out = String::new();
+    let mut last = 0;
+    for block in DECLARATION_BLOCK.find_iter(css) {
+        out.push_str(&rename(&css[last..block.start()]));
+        out.push_str(block.as_str());
+        last = block.end();
+    }
+    out.push_str(&rename(&css[last..]));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sprite_rewrites_ids() {
+        let logo = r##"<svg xmlns="http://www.w3.org/2000/svg" width="32" height="16"><defs><linearGradient id="a"/><clipPath id='b'/></defs><path data-id="a" fill="url(#a)" clip-path="url('#b')"/><use xlink:href="#a"/></svg>"##;
+        let mut sprite = SpriteBuilder::new();
+        assert_eq!(sprite.add_svg("svgl:logo", logo).as_deref(), Some("svgl-logo"));
+        assert!(sprite.add_svg("svgl:logo", logo).is_none());
+        sprite.add("mdi:home", "<path d=\"M0 0\"/>", IconBox::new(0.0, 0.0, 24.0, 24.0));
+
+        let svg = sprite.to_svg();
+        assert_eq!(sprite.len(), 2);
+        assert!(svg.contains("<symbol id=\"svgl-logo\" viewBox=\"0 0 32 16\"><defs><linearGradient id=\"svgl-logo-a\"/><clipPath id='svgl-logo-b'/></defs>"));
+        assert!(svg.contains("<path data-id=\"a\" fill=\"url(#svgl-logo-a)\" clip-path=\"url('#svgl-logo-b')\"/><use xlink:href=\"#svgl-logo-a\"/>"));
+        assert!(svg.contains("<symbol id=\"mdi-home\" viewBox=\"0 0 24 24\"><path d=\"M0 0\"/></symbol>"));
+        assert_eq!(
+            use_tag("/sprite.svg", "mdi:home", IconBox::new(0.0, 0.0, 24.0, 24.0)),
+            "<svg viewBox=\"0 0 24 24\" width=\"24\" height=\"24\"><use href=\"/sprite.svg#mdi-home\"/></svg>"
+        );
+    }
+
+    #[test]
+    fn test_sprite_namespaces_styles() {
+        // Illustrator exports name their classes `cls-1`, `cls-2`, ... in every file
+        let first = r##"<svg viewBox="0 0 24 24"><defs><style>.cls-1{fill:#fff}#a,.cls-1 .cls-2{fill:url(#a)}</style><linearGradient id="a"/></defs><path class="cls-1" d="M0 0"/><path class="cls-1 cls-2" id="a2"/></svg>"##;
+        let second = r##"<svg viewBox="0 0 32 32"><style><![CDATA[ .cls-1 { fill: #000; opacity: .5 } @media (prefers-color-scheme: dark) { .cls-1 { fill: #fff } } ]]></style><path class='cls-1' d="M1 1"/></svg>"##;
+        let mut sprite = SpriteBuilder::new();
+        sprite.add_svg("svgl:first", first);
+        sprite.add_svg("svgl:second", second);
+
+        let svg = sprite.to_svg();
+        assert!(svg.contains("<style>.svgl-first-cls-1{fill:#fff}#svgl-first-a,.svgl-first-cls-1 .svgl-first-cls-2{fill:url(#svgl-first-a)}</style>"));
+        assert!(svg.contains("<path class=\"svgl-first-cls-1\" d=\"M0 0\"/><path class=\"svgl-first-cls-1 svgl-first-cls-2\" id=\"svgl-first-a2\"/>"));
+        assert!(svg.contains("<style><![CDATA[ .svgl-second-cls-1 { fill: #000; opacity: .5 } @media (prefers-color-scheme: dark) { .svgl-second-cls-1 { fill: #fff } } ]]></style><path class='svgl-second-cls-1' d=\"M1 1\"/>"));
+        assert!(!svg.contains(".cls-1"));
+    }
+}
@@ -0,0 +1,151 @@
+use std::sync::LazyLock;
+use regex::Regex;
+use crate::converters::jsx::{split_declarations, unescape};
+use crate::converters::xml::{Element, Node};
+
+static COMMENTS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)/\*.*?\*/").unwrap());
+/// A tag name followed by classes and ids, e.g. `path.st0#a`
+static COMPOUND: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([A-Za-z][\w-]*)?((?:[.#][\w-]+)*)$").unwrap());
+static SIMPLE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"([.#])([\w-]+)").unwrap());
+
+/// Rules from an SVG's `<style>` elements, for converters whose targets only understand attributes
+#[derive(Debug, Default)]
+pub(crate) struct Stylesheet {
+    rules: Vec<CssRule>,
+    /// At-rules and selectors that could not be inlined
+    pub(crate) warnings: Vec<String>,
+}
+
+/// Declarations of one CSS rule with a simple selector
+#[derive(Debug)]
+struct CssRule {
+    tag: Option<String>,
+    classes: Vec<String>,
+    id: Option<String>,
+    declarations: Vec<(String, String)>,
+}
+
+impl CssRule {
+    fn matches(&self, element: &Element) -> bool {
+        let classes: Vec<&str> = element.attr("class").unwrap_or_default().split_whitespace().collect();
+        self.tag.as_ref().is_none_or(|tag| *tag == element.name)
+            && self.id.as_deref().is_none_or(|id| element.attr("id") == Some(id))
+            && self.classes.iter().all(|class| classes.contains(&class.as_str()))
+    }
+
+    fn specificity(&self) -> (usize, usize, usize) {
+        (self.id.is_some() as usize, self.classes.len(), self.tag.is_some() as usize)
+    }
+}
+
+impl Stylesheet {
+    /// Collect the rules of every `<style>` element in the tree
+    pub(crate) fn collect(nodes: &[Node]) -> Self {
+        let mut stylesheet = Stylesheet::default();
+        for node in nodes {
+            if let Node::Element(element) = node {
+                stylesheet.collect_styles(element);
+            }
+        }
+        stylesheet
+    }
+
+    /// CSS declarations for an element: matching rules by specificity, then its `style` attribute
+    pub(crate) fn declarations(&self, element: &Element) -> Vec<(String, String)> {
+        let mut rules: Vec<&CssRule> = self.rules.iter().filter(|rule| rule.matches(element)).collect();
+        rules.sort_by_key(|rule| rule.specificity());
+        let mut declarations: Vec<(String, String)> =
+            rules.iter().flat_map(|rule| rule.declarations.iter().cloned()).collect();
+        declarations.extend(element.attr("style").map(parse_declarations).unwrap_or_default());
+        declarations
+    }
+
+    fn warn(&mut self, warning: String) {
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    fn collect_styles(&mut self, element: &Element) {
+        if element.name == "style" {
+            let mut css = String::new();
+            for child in &element.children {
+                match child {
+                    Node::Text(text) => css.push_str(&unescape(text)),
+                    Node::CData(data) => css.push_str(data),
+                    _ => {}
+                }
+            }
+            self.parse_css(&css);
+        }
+        for child in &element.children {
+            if let Node::Element(child) = child {
+                self.collect_styles(child);
+            }
+        }
+    }
+
+    fn parse_css(&mut self, css: &str) {
+        let css = COMMENTS.replace_all(css, "");
+        let mut rest = css.as_ref();
+        while let Some(open) = rest.find('{') {
+            let prelude = rest[..open].trim();
+            // At-rules such as `@media` can nest blocks, so skip to the matching brace
+            let mut depth = 0;
+            let mut close = rest.len();
+            for (i, c) in rest[open..].char_indices() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            close = open + i;
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            let body = &rest[open + 1..close];
+            rest = rest.get(close + 1..).unwrap_or_default();
+
+            if prelude.starts_with('@') {
+                self.warn(format!("CSS at-rule `{}` was ignored", prelude));
+                continue;
+            }
+            let declarations = parse_declarations(body);
+            for selector in prelude.split(',').map(str::trim) {
+                let Some(caps) = COMPOUND.captures(selector).filter(|_| !selector.is_empty()) else {
+                    self.warn(format!("CSS selector `{}` is too complex to inline", selector));
+                    continue;
+                };
+                let mut rule = CssRule {
+                    tag: caps.get(1).map(|tag| tag.as_str().to_string()),
+                    classes: Vec::new(),
+                    id: None,
+                    declarations: declarations.clone(),
+                };
+                for part in SIMPLE.captures_iter(&caps[2]) {
+                    match &part[1] {
+                        "." => rule.classes.push(part[2].to_string()),
+                        _ => rule.id = Some(part[2].to_string()),
+                    }
+                }
+                self.rules.push(rule);
+            }
+        }
+    }
+}
+
+/// `property: value` pairs of a declaration block, with `!important` dropped
+pub(crate) fn parse_declarations(block: &str) -> Vec<(String, String)> {
+    split_declarations(&unescape(block))
+        .into_iter()
+        .filter_map(|declaration| {
+            let (property, value) = declaration.split_once(':')?;
+            let value = value.trim().trim_end_matches("!important").trim_end();
+            let property = property.trim().to_ascii_lowercase();
+            (!property.is_empty() && !value.is_empty()).then(|| (property, value.to_string()))
+        })
+        .collect()
+}
@@ -1,8 +1,255 @@
 use quick_xml::events::Event;
 use quick_xml::Reader;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::Path;
-use crate::converters::utils::{extract_styles, svg_to_jsx, to_pascal_case, svg_to_react_native, svg_to_qwik, svg_to_solid, svg_to_astro};
+use std::str::FromStr;
+use crate::converters::jsx::svg_to_jsx;
+use crate::converters::optimize::{optimize_svg, OptimizeOptions, OptimizeStats};
+use crate::converters::react_native::svg_to_react_native;
+use crate::converters::sprite::namespace_ids;
+use crate::converters::android::{svg_to_compose, svg_to_vector_drawable};
+use crate::converters::flutter::{svg_to_flutter, svg_to_flutter_painter};
+use crate::converters::swift::{svg_to_swiftui, swift_type_name};
+use crate::converters::utils::{extract_styles, to_pascal_case, svg_to_qwik, svg_to_solid, svg_to_astro, svg_to_leptos, svg_to_dioxus, svg_to_yew};
+
+/// Color scheme a logo variant is drawn for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+impl Theme {
+    #[allow(dead_code)]
+    pub fn opposite(self) -> Self {
+        match self {
+            Theme::Light => Theme::Dark,
+            Theme::Dark => Theme::Light,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+
+    /// Split a theme suffix off a file stem, e.g. `github_dark` -> (`github`, Some(Dark))
+    pub fn split_suffix(id: &str) -> (&str, Option<Theme>) {
+        for theme in [Theme::Light, Theme::Dark] {
+            for separator in ['-', '_'] {
+                if let Some(base) = id.strip_suffix(theme.as_str()).and_then(|rest| rest.strip_suffix(separator)) {
+                    if !base.is_empty() {
+                        return (base, Some(theme));
+                    }
+                }
+            }
+        }
+        (id, None)
+    }
+
+    /// File stems to try for this theme, best match first: themed, neutral, then the opposite theme
+    #[allow(dead_code)]
+    pub fn candidates(self, base: &str) -> Vec<String> {
+        let themed = |theme: Theme| [format!("{}-{}", base, theme.as_str()), format!("{}_{}", base, theme.as_str())];
+        let mut candidates = themed(self).to_vec();
+        candidates.push(base.to_string());
+        candidates.extend(themed(self.opposite()));
+        candidates
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "light" => Ok(Theme::Light),
+            "dark" => Ok(Theme::Dark),
+            _ => Err(format!("unknown theme '{}', expected light or dark", s)),
+        }
+    }
+}
+
+/// A logo route in svgs.ts: either one file or a light/dark pair
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum SvglRoute {
+    Single(String),
+    Themed { light: String, dark: String },
+}
+
+impl SvglRoute {
+    /// Turn `/library/nvidia-icon-light.svg` into the icon id `nvidia-icon-light`
+    pub fn icon_id(path: &str) -> String {
+        let file = path.rsplit('/').next().unwrap_or(path);
+        file.strip_suffix(".svg").unwrap_or(file).to_string()
+    }
+
+    /// Id of the variant used to name the entry, with any theme suffix removed
+    fn base_id(&self) -> String {
+        let id = match self {
+            SvglRoute::Single(path) => Self::icon_id(path),
+            SvglRoute::Themed { light, .. } => Self::icon_id(light),
+        };
+        Theme::split_suffix(&id).0.to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+fn one_or_many<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(value) => vec![value],
+        OneOrMany::Many(values) => values,
+    })
+}
+
+/// Logo metadata from svgl's `src/data/svgs.ts`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SvglEntry {
+    pub title: String,
+    #[serde(deserialize_with = "one_or_many")]
+    pub category: Vec<String>,
+    pub route: SvglRoute,
+    #[serde(default)]
+    pub wordmark: Option<SvglRoute>,
+    pub url: String,
+    #[serde(default)]
+    pub brand_url: Option<String>,
+}
+
+impl SvglEntry {
+    pub fn from_ts_file<P: AsRef<Path>>(path: P) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path)?;
+        Self::parse_ts(&content)
+    }
+
+    /// Parse the `svgs` array literal out of svgs.ts
+    pub fn parse_ts(content: &str) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let declaration = Regex::new(r"\bsvgs\b[^=]*=\s*\[").unwrap();
+        let start = declaration.find(content).ok_or("svgs array not found")?.end() - 1;
+        let json = js_literal_to_json(&content[start..])?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Stable ids for a list of entries, derived from their route file names
+    pub fn ids(entries: &[SvglEntry]) -> Vec<String> {
+        let mut seen = HashSet::new();
+        entries
+            .iter()
+            .map(|entry| {
+                let base = entry.route.base_id();
+                let mut id = base.clone();
+                let mut n = 2;
+                while !seen.insert(id.clone()) {
+                    id = format!("{}-{}", base, n);
+                    n += 1;
+                }
+                id
+            })
+            .collect()
+    }
+}
+
+/// Convert the JavaScript array or object literal at the start of `source` to JSON.
+/// Handles comments, single-quoted and template strings, unquoted keys and trailing commas;
+/// anything else, such as spreads or identifiers, is an error rather than silently skipped.
+fn js_literal_to_json(source: &str) -> Result<String, String> {
+    let bytes = source.as_bytes();
+    let line = |i: usize| source[..i].matches('\n').count() + 1;
+    let mut json = String::with_capacity(source.len());
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = source[i..].find('\n').map_or(bytes.len(), |end| i + end);
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let end = source[i + 2..].find("*/").ok_or_else(|| format!("unterminated comment on line {}", line(i)))?;
+                i += end + 4;
+                continue;
+            }
+            b'"' | b'\'' | b'`' => {
+                let mut value = String::new();
+                let mut chars = source[i + 1..].char_indices();
+                loop {
+                    let (offset, ch) = chars.next().ok_or_else(|| format!("unterminated string on line {}", line(i)))?;
+                    match ch {
+                        '\\' => {
+                            let (_, escaped) = chars.next().ok_or_else(|| format!("unterminated string on line {}", line(i)))?;
+                            match escaped {
+                                'n' => value.push('\n'),
+                                't' => value.push('\t'),
+                                'r' => value.push('\r'),
+                                '\n' => {}
+                                other => value.push(other),
+                            }
+                        }
+                        '$' if c == b'`' && source[i + 1 + offset..].starts_with("${") => {
+                            return Err(format!("template interpolation on line {} is not supported", line(i)));
+                        }
+                        ch if ch as u32 == c as u32 => {
+                            i += 1 + offset + 1;
+                            break;
+                        }
+                        ch => value.push(ch),
+                    }
+                }
+                json.push_str(&serde_json::to_string(&value).map_err(|e| e.to_string())?);
+                continue;
+            }
+            b'[' | b'{' => depth += 1,
+            b']' | b'}' => {
+                depth = depth.checked_sub(1).ok_or_else(|| format!("unbalanced `{}` on line {}", c as char, line(i)))?;
+                // Drop a trailing comma before the closing bracket
+                if json.trim_end().ends_with(',') {
+                    json.truncate(json.trim_end().len() - 1);
+                }
+                json.push(c as char);
+                i += 1;
+                if depth == 0 {
+                    return Ok(json);
+                }
+                continue;
+            }
+            b',' | b':' => {}
+            c if c.is_ascii_whitespace() => {}
+            c if c.is_ascii_digit() || c == b'-' || c == b'.' => {}
+            c if c.is_ascii_alphabetic() || c == b'_' || c == b'$' => {
+                let end = source[i..]
+                    .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_' || ch == '$'))
+                    .map_or(bytes.len(), |end| i + end);
+                let word = &source[i..end];
+                let is_key = source[end..].trim_start().starts_with(':');
+                match word {
+                    _ if is_key => json.push_str(&format!("\"{}\"", word)),
+                    "true" | "false" | "null" => json.push_str(word),
+                    _ => return Err(format!("unsupported value `{}` on line {}", word, line(i))),
+                }
+                i = end;
+                continue;
+            }
+            _ => return Err(format!("unexpected `{}` on line {}", c as char, line(i))),
+        }
+        json.push(c as char);
+        i += 1;
+    }
+    Err("literal is not terminated".to_string())
+}
 
 #[derive(Debug, Clone)]
 pub struct SvgIcon {
@@ -36,31 +283,29 @@ impl SvgIcon {
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) if e.name().as_ref() == b"svg" => {
-                    for attr in e.attributes() {
-                        if let Ok(attr) = attr {
-                            match attr.key.as_ref() {
-                                b"viewBox" => {
-                                    viewbox = Some(
-                                        String::from_utf8_lossy(attr.value.as_ref()).to_string(),
-                                    );
-                                }
-                                b"width" => {
-                                    let w_str = String::from_utf8_lossy(attr.value.as_ref());
-                                    // Try to parse, removing "px" suffix if present
-                                    width = w_str
-                                        .trim_end_matches("px")
-                                        .parse::<u32>()
-                                        .ok();
-                                }
-                                b"height" => {
-                                    let h_str = String::from_utf8_lossy(attr.value.as_ref());
-                                    height = h_str
-                                        .trim_end_matches("px")
-                                        .parse::<u32>()
-                                        .ok();
-                                }
-                                _ => {}
+                    for attr in e.attributes().flatten() {
+                        match attr.key.as_ref() {
+                            b"viewBox" => {
+                                viewbox = Some(
+                                    String::from_utf8_lossy(attr.value.as_ref()).to_string(),
+                                );
+                            }
+                            b"width" => {
+                                let w_str = String::from_utf8_lossy(attr.value.as_ref());
+                                // Try to parse, removing "px" suffix if present
+                                width = w_str
+                                    .trim_end_matches("px")
+                                    .parse::<u32>()
+                                    .ok();
+                            }
+                            b"height" => {
+                                let h_str = String::from_utf8_lossy(attr.value.as_ref());
+                                height = h_str
+                                    .trim_end_matches("px")
+                                    .parse::<u32>()
+                                    .ok();
                             }
+                            _ => {}
                         }
                     }
                     break;
@@ -149,15 +394,15 @@ impl SvgIcon {
     }
 
     #[allow(dead_code)]
-    pub fn to_react_native_component(&self, _typescript: bool) -> String {
+    pub fn to_react_native_component(&self, typescript: bool) -> String {
         let name = to_pascal_case(&self.filename);
-        svg_to_react_native(&self.svg_content, &name, false)
+        svg_to_react_native(&self.svg_content, &name, false, typescript)
     }
 
     #[allow(dead_code)]
-    pub fn to_qwik_component(&self, _typescript: bool) -> String {
+    pub fn to_qwik_component(&self, typescript: bool) -> String {
         let name = to_pascal_case(&self.filename);
-        svg_to_qwik(&self.svg_content, &name, false)
+        svg_to_qwik(&self.svg_content, &name, false, typescript)
     }
 
     #[allow(dead_code)]
@@ -171,11 +416,73 @@ impl SvgIcon {
         svg_to_astro(&self.svg_content)
     }
 
-    pub fn build_collection(icons: &[SvgIcon]) -> Vec<u8> {
-        use crate::icon_generated::dx_icon::{SvglIcon, SvglIconArgs, SvglCollection, SvglCollectionArgs};
-        use flatbuffers::FlatBufferBuilder;
+    #[allow(dead_code)]
+    pub fn to_leptos_component(&self) -> String {
+        svg_to_leptos(&self.svg_content, &self.filename)
+    }
+
+    #[allow(dead_code)]
+    pub fn to_dioxus_component(&self) -> String {
+        svg_to_dioxus(&self.svg_content, &self.filename)
+    }
+
+    #[allow(dead_code)]
+    pub fn to_yew_component(&self) -> String {
+        svg_to_yew(&self.svg_content, &self.filename)
+    }
+
+    #[allow(dead_code)]
+    pub fn to_vector_drawable(&self) -> String {
+        svg_to_vector_drawable(&self.svg_content)
+    }
+
+    #[allow(dead_code)]
+    pub fn to_compose_image_vector(&self) -> String {
+        svg_to_compose(&self.svg_content, &to_pascal_case(&self.filename))
+    }
+
+    #[allow(dead_code)]
+    pub fn to_swiftui_shape(&self) -> String {
+        svg_to_swiftui(&self.svg_content, &swift_type_name(&self.filename))
+    }
+
+    #[allow(dead_code)]
+    pub fn to_flutter_widget(&self) -> String {
+        svg_to_flutter(&self.svg_content, &self.filename, false)
+    }
+
+    #[allow(dead_code)]
+    pub fn to_flutter_painter(&self) -> String {
+        svg_to_flutter_painter(&self.svg_content, &self.filename, false)
+    }
+
+    /// Serialize logos and their metadata, optimizing each SVG when `optimize` is set
+    pub fn build_collection(
+        icons: &[SvgIcon],
+        entries: &[SvglEntry],
+        optimize: Option<&OptimizeOptions>,
+    ) -> (Vec<u8>, OptimizeStats) {
+        use crate::icon_generated::dx_icon::{
+            SvglIcon, SvglIconArgs, SvglCollection, SvglCollectionArgs,
+            SvglEntry as FbSvglEntry, SvglEntryArgs, SvglRoute as FbSvglRoute, SvglRouteArgs,
+        };
+        use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+        fn create_route<'a>(builder: &mut FlatBufferBuilder<'a>, route: &SvglRoute) -> WIPOffset<FbSvglRoute<'a>> {
+            let (icon, light, dark) = match route {
+                SvglRoute::Single(path) => (Some(SvglRoute::icon_id(path)), None, None),
+                SvglRoute::Themed { light, dark } => {
+                    (None, Some(SvglRoute::icon_id(light)), Some(SvglRoute::icon_id(dark)))
+                }
+            };
+            let icon = icon.map(|id| builder.create_string(&id));
+            let light = light.map(|id| builder.create_string(&id));
+            let dark = dark.map(|id| builder.create_string(&id));
+            FbSvglRoute::create(builder, &SvglRouteArgs { icon, light, dark })
+        }
 
         let mut builder = FlatBufferBuilder::new();
+        let mut stats = OptimizeStats::default();
         
         let mut fb_icons = Vec::new();
         // Sort by filename for determinism
@@ -185,7 +492,14 @@ impl SvgIcon {
         for icon in sorted_icons {
             let id = builder.create_string(&icon.filename);
             let filename = builder.create_string(&icon.filename);
-            let svg_content = builder.create_string(&icon.svg_content);
+            let svg_content = match optimize {
+                Some(options) => {
+                    let (optimized, icon_stats) = optimize_svg(&icon.svg_content, options);
+                    stats += icon_stats;
+                    builder.create_string(&optimized)
+                }
+                None => builder.create_string(&icon.svg_content),
+            };
             let viewbox = icon.viewbox.as_ref().map(|v| builder.create_string(v));
             
             let fb_icon = SvglIcon::create(&mut builder, &SvglIconArgs {
@@ -200,15 +514,216 @@ impl SvgIcon {
         }
         
         let icons_vec = builder.create_vector(&fb_icons);
+
+        // Sort entries by id for binary search by key
+        let mut sorted_entries: Vec<(String, &SvglEntry)> =
+            SvglEntry::ids(entries).into_iter().zip(entries).collect();
+        sorted_entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut fb_entries = Vec::new();
+        for (id, entry) in sorted_entries {
+            let id = builder.create_string(&id);
+            let title = builder.create_string(&entry.title);
+            let categories: Vec<_> = entry.category.iter().map(|c| builder.create_string(c)).collect();
+            let categories = builder.create_vector(&categories);
+            let route = create_route(&mut builder, &entry.route);
+            let wordmark = entry.wordmark.as_ref().map(|w| create_route(&mut builder, w));
+            let url = builder.create_string(&entry.url);
+            let brand_url = entry.brand_url.as_ref().map(|u| builder.create_string(u));
+
+            fb_entries.push(FbSvglEntry::create(&mut builder, &SvglEntryArgs {
+                id: Some(id),
+                title: Some(title),
+                categories: Some(categories),
+                route: Some(route),
+                wordmark,
+                url: Some(url),
+                brand_url,
+            }));
+        }
+        let entries_vec = builder.create_vector(&fb_entries);
+
         let collection = SvglCollection::create(&mut builder, &SvglCollectionArgs {
             icons: Some(icons_vec),
+            entries: Some(entries_vec),
         });
         
         builder.finish(collection, None);
-        builder.finished_data().to_vec()
+        (builder.finished_data().to_vec(), stats)
     }
 }
 
+/// A logo with separate light and dark artwork, rendered as one theme-switching component
+#[derive(Debug, Clone)]
+pub struct ThemedSvgIcon {
+    pub name: String,
+    pub light: SvgIcon,
+    pub dark: SvgIcon,
+}
+
+/// Hides the variant that doesn't match the user's color scheme when `theme` is `system`
+const THEME_CSS: &str = ".dx-logo-dark { display: none; }\n\
+@media (prefers-color-scheme: dark) {\n  \
+.dx-logo-light { display: none; }\n  \
+.dx-logo-dark { display: contents; }\n\
+}";
+
+impl ThemedSvgIcon {
+    /// Group `name-light`/`name_dark` style file pairs into themed logos
+    #[allow(dead_code)]
+    pub fn pair(icons: &[SvgIcon]) -> Vec<ThemedSvgIcon> {
+        let mut variants: BTreeMap<&str, (Option<&SvgIcon>, Option<&SvgIcon>)> = BTreeMap::new();
+        for icon in icons {
+            match Theme::split_suffix(&icon.filename) {
+                (base, Some(Theme::Light)) => variants.entry(base).or_default().0 = Some(icon),
+                (base, Some(Theme::Dark)) => variants.entry(base).or_default().1 = Some(icon),
+                (_, None) => {}
+            }
+        }
+
+        variants
+            .into_iter()
+            .filter_map(|(name, pair)| match pair {
+                (Some(light), Some(dark)) => Some(ThemedSvgIcon {
+                    name: name.to_string(),
+                    light: light.clone(),
+                    dark: dark.clone(),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[allow(dead_code)]
+    pub fn variant(&self, theme: Theme) -> &SvgIcon {
+        match theme {
+            Theme::Light => &self.light,
+            Theme::Dark => &self.dark,
+        }
+    }
+
+    /// Both variants with their ids prefixed, so that with `theme` set to `system`
+    /// a `url(#a)` in one can't resolve to an element of the hidden other
+    fn namespaced(&self) -> (String, String) {
+        (
+            namespace_ids(&self.light.svg_content, &format!("{}-light", self.name)),
+            namespace_ids(&self.dark.svg_content, &format!("{}-dark", self.name)),
+        )
+    }
+
+    #[allow(dead_code)]
+    pub fn to_react_component(&self, typescript: bool) -> String {
+        let name = to_pascal_case(&self.name);
+        let (light, dark) = self.namespaced();
+        let light = spread_root(&svg_to_jsx(&light), "{...props}");
+        let dark = spread_root(&svg_to_jsx(&dark), "{...props}");
+        let (types, variant_props, signature) = if typescript {
+            (
+                "type Theme = 'light' | 'dark' | 'system';\n\n\
+                type Props = React.SVGProps<SVGSVGElement> & { theme?: Theme };\n\n",
+                "props: React.SVGProps<SVGSVGElement>",
+                "{ theme = 'system', ...props }: Props",
+            )
+        } else {
+            ("", "props", "{ theme = 'system', ...props }")
+        };
+
+        format!(
+            "import * as React from 'react';\n\n\
+            {types}\
+            const Light = ({variant_props}) => (\n{light}\n);\n\n\
+            const Dark = ({variant_props}) => (\n{dark}\n);\n\n\
+            const {name} = ({signature}) => {{\n  \
+            if (theme === 'light') return <Light {{...props}} />;\n  \
+            if (theme === 'dark') return <Dark {{...props}} />;\n  \
+            return (\n    \
+            <>\n      \
+            <style>{{`{css}`}}</style>\n      \
+            <span className=\"dx-logo-light\"><Light {{...props}} /></span>\n      \
+            <span className=\"dx-logo-dark\"><Dark {{...props}} /></span>\n    \
+            </>\n  \
+            );\n\
+            }};\n\n\
+            export default {name};",
+            types = types,
+            variant_props = variant_props,
+            light = light,
+            dark = dark,
+            name = name,
+            signature = signature,
+            css = THEME_CSS,
+        )
+    }
+
+    #[allow(dead_code)]
+    pub fn to_vue_component(&self, typescript: bool) -> String {
+        let (light, dark) = self.namespaced();
+        let (light, light_styles) = extract_styles(&light);
+        let (dark, dark_styles) = extract_styles(&dark);
+        let (light, dark) = (spread_root(&light, "v-bind=\"$attrs\""), spread_root(&dark, "v-bind=\"$attrs\""));
+        // Attributes go to the <svg> of each variant rather than the wrapping <span>
+        let script = if typescript {
+            "<script setup lang=\"ts\">\n\
+            defineOptions({ inheritAttrs: false });\n\
+            withDefaults(defineProps<{ theme?: 'light' | 'dark' | 'system' }>(), { theme: 'system' });\n\
+            </script>"
+        } else {
+            "<script setup>\n\
+            defineOptions({ inheritAttrs: false });\n\
+            defineProps({ theme: { type: String, default: 'system' } });\n\
+            </script>"
+        };
+
+        format!(
+            "{}\n\n\
+            <template>\n\
+            <span v-if=\"theme !== 'dark'\" :class=\"{{ 'dx-logo-light': theme === 'system' }}\">{}</span>\n\
+            <span v-if=\"theme !== 'light'\" :class=\"{{ 'dx-logo-dark': theme === 'system' }}\">{}</span>\n\
+            </template>\n\n\
+            <style scoped>\n{}\n</style>",
+            script,
+            light,
+            dark,
+            join_styles(&[THEME_CSS, &light_styles, &dark_styles])
+        )
+    }
+
+    #[allow(dead_code)]
+    pub fn to_svelte_component(&self, typescript: bool) -> String {
+        let (light, dark) = self.namespaced();
+        let (light, light_styles) = extract_styles(&light);
+        let (dark, dark_styles) = extract_styles(&dark);
+        let (light, dark) = (spread_root(&light, "{...$$restProps}"), spread_root(&dark, "{...$$restProps}"));
+        let script = if typescript {
+            "<script lang=\"ts\">\n  export let theme: 'light' | 'dark' | 'system' = 'system';\n</script>"
+        } else {
+            "<script>\n  export let theme = 'system';\n</script>"
+        };
+
+        format!(
+            "{}\n\n\
+            {{#if theme !== 'dark'}}<span class:dx-logo-light={{theme === 'system'}}>{}</span>{{/if}}\n\
+            {{#if theme !== 'light'}}<span class:dx-logo-dark={{theme === 'system'}}>{}</span>{{/if}}\n\n\
+            <style>\n{}\n</style>",
+            script,
+            light,
+            dark,
+            join_styles(&[THEME_CSS, &light_styles, &dark_styles])
+        )
+    }
+}
+
+/// Add `spread`, such as `{...props}`, to the root `<svg>` tag so callers' attributes reach it
+fn spread_root(markup: &str, spread: &str) -> String {
+    let root = Regex::new(r"(?s)<svg\b(.*?)(\s*/?)>").unwrap();
+    root.replacen(markup, 1, |caps: &regex::Captures| format!("<svg{} {}{}>", &caps[1], spread, &caps[2]))
+        .into_owned()
+}
+
+fn join_styles(styles: &[&str]) -> String {
+    styles.iter().filter(|style| !style.is_empty()).copied().collect::<Vec<_>>().join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,4 +745,91 @@ mod tests {
         
         std::fs::remove_file(temp_file).ok();
     }
+
+    #[test]
+    fn test_parse_svgs_ts() {
+        let ts = r#"import type { iSVG } from "@/types/svg";
+
+export const svgs: iSVG[] = [
+  {
+    title: "NVIDIA",
+    category: ["AI", "Hardware"],
+    route: {
+      light: "/library/nvidia-icon-light.svg",
+      dark: "/library/nvidia-icon-dark.svg",
+    },
+    wordmark: "/library/nvidia-wordmark.svg",
+    url: "https://www.nvidia.com",
+    brandUrl:
+      "https://www.nvidia.com/en-us/about-nvidia/legal-info/logo-brand-usage",
+  },
+  {
+    title: "Zyft",
+    category: "Library",
+    route: "/library/zyft.svg",
+    url: "https://zyft.com",
+  },
+];
+"#;
+        let entries = SvglEntry::parse_ts(ts).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].category, ["AI", "Hardware"]);
+        assert_eq!(entries[0].wordmark, Some(SvglRoute::Single("/library/nvidia-wordmark.svg".to_string())));
+        assert!(entries[0].brand_url.as_deref().unwrap().ends_with("logo-brand-usage"));
+        assert_eq!(entries[1].category, ["Library"]);
+        assert_eq!(SvglEntry::ids(&entries), ["nvidia-icon", "zyft"]);
+
+        // Comments, single quotes and keys sharing a line are fine; a `]` after the array is ignored
+        let ts = "export const svgs = [ // logos\n  { title: 'It\\'s', category: ['A'], /* [x] */ route: `/library/a.svg`, url: \"https://a.dev\" },\n] satisfies iSVG[];\nconst other = [1];";
+        let entries = SvglEntry::parse_ts(ts).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].title, "It's");
+        assert!(SvglEntry::parse_ts("export const svgs = [{ title: \"A\", ...rest }];").is_err());
+    }
+
+    #[test]
+    fn test_themed_pairs() {
+        let icon = |filename: &str| SvgIcon {
+            filename: filename.to_string(),
+            svg_content: format!(
+                "<svg viewBox=\"0 0 24 24\"><defs><style>.cls-1{{opacity:.5}}</style><linearGradient id=\"a\"/></defs><path class=\"cls-1 {}\" fill=\"url(#a)\"/></svg>",
+                filename
+            ),
+            viewbox: Some("0 0 24 24".to_string()),
+            width: None,
+            height: None,
+        };
+        let icons = [icon("github_light"), icon("github_dark"), icon("bun"), icon("vercel-dark")];
+
+        assert_eq!(Theme::split_suffix("1password-dark"), ("1password", Some(Theme::Dark)));
+        assert_eq!(Theme::split_suffix("dark"), ("dark", None));
+        assert_eq!(Theme::Dark.candidates("x"), ["x-dark", "x_dark", "x", "x-light", "x_light"]);
+
+        let themed = ThemedSvgIcon::pair(&icons);
+        assert_eq!(themed.len(), 1);
+        assert_eq!(themed[0].name, "github");
+        assert_eq!(themed[0].variant(Theme::Dark).filename, "github_dark");
+
+        let react = themed[0].to_react_component(true);
+        assert!(react.contains("const Github = ({ theme = 'system', ...props }: Props)"));
+        assert!(react.contains("const Dark = (props: React.SVGProps<SVGSVGElement>) => (\n<svg viewBox=\"0 0 24 24\" {...props}>"));
+        assert!(react.contains("<span className=\"dx-logo-light\"><Light {...props} /></span>"));
+        assert!(react.contains("className=\"github-dark-cls-1 github-dark-github_dark\""));
+        assert!(react.contains("prefers-color-scheme: dark"));
+
+        // Each variant's gradient keeps its own id, so the hidden one can't shadow the visible one
+        assert!(react.contains("id=\"github-light-a\"") && react.contains("fill=\"url(#github-light-a)\""));
+        assert!(react.contains("id=\"github-dark-a\"") && react.contains("fill=\"url(#github-dark-a)\""));
+        // and so do their stylesheet classes
+        assert!(react.contains(".github-light-cls-1{opacity:.5}") && react.contains(".github-dark-cls-1{opacity:.5}"));
+
+        let vue = themed[0].to_vue_component(false);
+        assert!(vue.contains("defineOptions({ inheritAttrs: false });"));
+        assert!(vue.contains("<svg viewBox=\"0 0 24 24\" v-bind=\"$attrs\">"));
+        assert!(vue.contains("url(#github-dark-a)"));
+
+        let svelte = themed[0].to_svelte_component(false);
+        assert!(svelte.contains("export let theme = 'system';"));
+        assert_eq!(svelte.matches("<svg viewBox=\"0 0 24 24\" {...$$restProps}>").count(), 2);
+    }
 }
@@ -0,0 +1,215 @@
+use usvg::tiny_skia_path::{Path, PathSegment, Point, Transform};
+use usvg::FillRule;
+use crate::converters::outline::{outline, Outline};
+use crate::converters::utils::{number, svg_with_xmlns, type_name};
+
+/// SwiftUI and CoreGraphics types the generated shapes use, which a shape of the same name would shadow
+const SWIFT_TYPES: &[&str] = &[
+    "CGAffineTransform", "CGFloat", "CGPoint", "CGRect", "Color", "FillStyle", "Image", "Path", "Shape", "Text", "View",
+];
+
+/// `Contents.json` at the root of an `.xcassets` catalog
+#[allow(dead_code)]
+pub const CATALOG_CONTENTS: &str = "{\n  \"info\" : {\n    \"author\" : \"xcode\",\n    \"version\" : 1\n  }\n}\n";
+
+/// `Contents.json` of an image set holding one SVG. Template images are tinted with the
+/// foreground color like SF Symbols; palette icons keep their colors.
+#[allow(dead_code)]
+pub fn imageset_contents(filename: &str, template: bool) -> String {
+    let contents = serde_json::json!({
+        "images": [{ "filename": filename, "idiom": "universal" }],
+        "info": { "author": "xcode", "version": 1 },
+        "properties": {
+            "preserves-vector-representation": true,
+            "template-rendering-intent": if template { "template" } else { "original" },
+        },
+    });
+    // Xcode writes ` : ` between keys and values; matching it keeps catalogs diff-free after Xcode touches them
+    let json = serde_json::to_string_pretty(&contents).unwrap_or_default();
+    format!("{}\n", json.replace("\": ", "\" : "))
+}
+
+/// Swift type name for an icon, e.g. `home-outline` -> `HomeOutline`, `2fa` -> `Icon2fa`, `shape` -> `ShapeIcon`
+#[allow(dead_code)]
+pub fn swift_type_name(name: &str) -> String {
+    type_name(name, is_swift_type)
+}
+
+/// Whether a shape called `name` would shadow a type the generated code uses
+#[allow(dead_code)]
+pub fn is_swift_type(name: &str) -> bool {
+    SWIFT_TYPES.contains(&name)
+}
+
+/// A SwiftUI `Shape` converted from an SVG document
+#[derive(Debug, Default)]
+#[allow(dead_code)]
+pub struct SwiftShape {
+    /// The `struct`, without imports
+    pub code: String,
+    /// Constructs that were dropped or could only be partly converted
+    pub warnings: Vec<String>,
+}
+
+/// Convert an SVG document to a SwiftUI `Shape` whose path fills the frame it is given.
+/// Groups and transforms are applied to the coordinates and strokes become filled outlines;
+/// colors are left to the caller.
+#[allow(dead_code)]
+pub fn svg_to_swiftui_shape(content: &str, name: &str) -> SwiftShape {
+    let outline = outline(&svg_with_xmlns(content), Transform::identity()).unwrap_or_else(|error| Outline {
+        width: 24.0,
+        height: 24.0,
+        warnings: vec![format!("markup could not be parsed: {}", error)],
+        ..Default::default()
+    });
+    let mut paints = Vec::new();
+    for path in &outline.paths {
+        let fill = path.fill.as_ref().map(|fill| fill.color);
+        for color in fill.into_iter().chain(path.stroke.as_ref().map(|stroke| stroke.color)) {
+            if !paints.contains(&color) {
+                paints.push(color);
+            }
+        }
+    }
+
+    let mut warnings: Vec<String> = outline.warnings.iter().filter(|warning| !warning.starts_with("gradients")).cloned().collect();
+    if paints.len() > 1 {
+        warnings.push(format!("{} colors were merged into one shape", paints.len()));
+    }
+
+    let mut code = format!(
+        "/// Drawn in a {}×{} viewBox and scaled to fill its frame",
+        number(outline.width as f64, 3),
+        number(outline.height as f64, 3)
+    );
+    if outline.paths.iter().any(|path| path.fill.as_ref().is_some_and(|fill| fill.rule == FillRule::EvenOdd)) {
+        code.push_str("; fill it with `FillStyle(eoFill: true)`");
+    }
+    code.push_str(&format!(
+        "\nstruct {}: Shape {{\n    \
+            func path(in rect: CGRect) -> Path {{\n        \
+                var path = Path()\n\
+        {}        \
+                return path\n            \
+                    .applying(CGAffineTransform(scaleX: rect.width / {}, y: rect.height / {}))\n            \
+                    .offsetBy(dx: rect.minX, dy: rect.minY)\n    \
+            }}\n\
+        }}\n",
+        name,
+        path_calls(&shape_paths(&outline)),
+        number(outline.width as f64, 3),
+        number(outline.height as f64, 3),
+    ));
+    SwiftShape { code, warnings }
+}
+
+/// A Swift file declaring one `Shape`, with unsupported constructs listed in comments
+#[allow(dead_code)]
+pub fn svg_to_swiftui(content: &str, name: &str) -> String {
+    let shape = svg_to_swiftui_shape(content, name);
+    let mut swift = String::from("import SwiftUI\n\n");
+    for warning in &shape.warnings {
+        swift.push_str(&format!("// SwiftUI: {}\n", warning));
+    }
+    swift.push_str(&shape.code);
+    swift
+}
+
+/// Fills followed by their stroke outlines, since a `Shape` can only be filled
+fn shape_paths(outline: &Outline) -> Vec<Path> {
+    let mut paths = Vec::new();
+    for path in &outline.paths {
+        if path.fill.is_some() {
+            paths.push(path.data.clone());
+        }
+        paths.extend(path.stroke_outline());
+    }
+    paths
+}
+
+/// Path calls drawing every path
+fn path_calls(paths: &[Path]) -> String {
+    let point = |p: Point| format!("CGPoint(x: {}, y: {})", number(p.x as f64, 3), number(p.y as f64, 3));
+    let mut out = String::new();
+    for segment in paths.iter().flat_map(|path| path.segments()) {
+        let call = match segment {
+            PathSegment::MoveTo(to) => format!("move(to: {})", point(to)),
+            PathSegment::LineTo(to) => format!("addLine(to: {})", point(to)),
+            PathSegment::CubicTo(control1, control2, to) => {
+                format!("addCurve(to: {}, control1: {}, control2: {})", point(to), point(control1), point(control2))
+            }
+            PathSegment::QuadTo(control, to) => format!("addQuadCurve(to: {}, control: {})", point(to), point(control)),
+            PathSegment::Close => "closeSubpath()".to_string(),
+        };
+        out.push_str(&format!("        path.{}\n", call));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_imageset_contents() {
+        let contents = imageset_contents("home.svg", true);
+        assert!(contents.contains("\"filename\" : \"home.svg\""));
+        assert!(contents.contains("\"preserves-vector-representation\" : true"));
+        assert!(contents.contains("\"template-rendering-intent\" : \"template\""));
+        assert!(imageset_contents("logo.svg", false).contains("\"template-rendering-intent\" : \"original\""));
+    }
+
+    #[test]
+    fn test_swiftui_shape() {
+        let svg = r#"<svg viewBox="0 0 24 24" fill="currentColor"><path d="M2 2h4v4H2z m10 0c1 0 2 1 2 2s-1 2-2 2q2 2 4 0t4 0"/><g transform="translate(10 0) scale(2)"><circle cx="2" cy="2" r="2"/></g></svg>"#;
+        let shape = svg_to_swiftui_shape(svg, "Demo");
+        assert!(shape.warnings.is_empty());
+        let code = shape.code;
+        assert!(code.starts_with("/// Drawn in a 24×24 viewBox and scaled to fill its frame\nstruct Demo: Shape {\n    func path(in rect: CGRect) -> Path {\n        var path = Path()\n"));
+        assert!(code.contains(
+            "        path.move(to: CGPoint(x: 2, y: 2))\n\
+            \x20       path.addLine(to: CGPoint(x: 6, y: 2))\n\
+            \x20       path.addLine(to: CGPoint(x: 6, y: 6))\n\
+            \x20       path.addLine(to: CGPoint(x: 2, y: 6))\n\
+            \x20       path.closeSubpath()\n\
+            \x20       path.move(to: CGPoint(x: 12, y: 2))\n\
+            \x20       path.addCurve(to: CGPoint(x: 14, y: 4), control1: CGPoint(x: 13, y: 2), control2: CGPoint(x: 14, y: 3))\n\
+            \x20       path.addCurve(to: CGPoint(x: 12, y: 6), control1: CGPoint(x: 14, y: 5), control2: CGPoint(x: 13, y: 6))\n\
+            \x20       path.addQuadCurve(to: CGPoint(x: 16, y: 6), control: CGPoint(x: 14, y: 8))\n\
+            \x20       path.addQuadCurve(to: CGPoint(x: 20, y: 6), control: CGPoint(x: 18, y: 4))\n"
+        ));
+        // The circle becomes four quarter curves, translated and scaled by its group
+        assert!(code.contains("        path.move(to: CGPoint(x: 18, y: 4))\n        path.addCurve(to: CGPoint(x: 14, y: 8), control1: CGPoint(x: 18, y: 6.209), control2: CGPoint(x: 16.209, y: 8))\n"));
+        assert_eq!(code.matches("addCurve").count(), 6);
+        assert!(code.contains("        path.addCurve(to: CGPoint(x: 18, y: 4), control1: CGPoint(x: 16.209, y: 0), control2: CGPoint(x: 18, y: 1.791))\n        path.closeSubpath()\n"));
+        assert!(code.ends_with("        return path\n            .applying(CGAffineTransform(scaleX: rect.width / 24, y: rect.height / 24))\n            .offsetBy(dx: rect.minX, dy: rect.minY)\n    }\n}\n"));
+
+        let lines = svg_to_swiftui(r##"<svg viewBox="0 0 24 12"><path fill="none" stroke="#000" d="M0 0h4"/><path fill="#f00" fill-rule="evenodd" d="M0 0h1v1z"/></svg>"##, "Lines");
+        assert!(lines.starts_with("import SwiftUI\n\n// SwiftUI: 2 colors were merged into one shape\n"));
+        assert!(lines.contains("/// Drawn in a 24×12 viewBox and scaled to fill its frame; fill it with `FillStyle(eoFill: true)`\n"));
+    }
+
+    #[test]
+    fn test_swift_type_name() {
+        assert_eq!(swift_type_name("home-outline"), "HomeOutline");
+        assert_eq!(swift_type_name("2fa"), "Icon2fa");
+        assert_eq!(swift_type_name("shape"), "ShapeIcon");
+    }
+
+    #[test]
+    fn test_swiftui_stroked_shape() {
+        // Lucide-style icons are strokes only, so the shape has to fill their outlines
+        let svg = r#"<svg viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round"><path d="M5 12h14"/></svg>"#;
+        let shape = svg_to_swiftui_shape(svg, "Minus");
+        assert!(shape.warnings.is_empty());
+        assert!(shape.code.contains("        path.move(to: CGPoint(x: 5, y: 11))\n        path.addLine(to: CGPoint(x: 19, y: 11))\n"));
+        assert!(shape.code.contains("CGPoint(x: 19, y: 13)"));
+        assert!(shape.code.contains("path.addQuadCurve"));
+        assert!(shape.code.contains("path.closeSubpath()"));
+
+        // A path that is both filled and stroked keeps its fill and gains the stroke outline
+        let both = svg_to_swiftui_shape(r#"<svg viewBox="0 0 24 24" fill="currentColor" stroke="currentColor" stroke-width="2"><path d="M4 4h16v16H4z"/></svg>"#, "Square");
+        assert!(both.code.contains("        path.move(to: CGPoint(x: 4, y: 4))\n        path.addLine(to: CGPoint(x: 20, y: 4))\n"));
+        assert!(both.code.contains("        path.move(to: CGPoint(x: 4, y: 3))\n        path.addLine(to: CGPoint(x: 21, y: 3))\n"));
+    }
+}
@@ -1,4 +1,11 @@
 use regex::{Regex, Captures};
+use std::borrow::Cow;
+use std::sync::LazyLock;
+use crate::converters::jsx::{svg_to_jsx, unescape};
+use crate::converters::xml::{parse, Node};
+
+static ROOT_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)<svg\b[^>]*>").unwrap());
+static ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"\s([\w:-]+)\s*=\s*["']([^"']*)["']"#).unwrap());
 
 #[allow(dead_code)]
 pub fn extract_styles(content: &str) -> (String, String) {
@@ -12,138 +19,63 @@ pub fn extract_styles(content: &str) -> (String, String) {
     (template, styles.join("\n"))
 }
 
+/// Markup between the root `<svg ...>` start tag and its closing `</svg>`
 #[allow(dead_code)]
-pub fn svg_to_jsx(content: &str) -> String {
-    let mut jsx = content.to_string();
-    
-    // Replace class= with className=
-    let re_class = Regex::new(r"class=").unwrap();
-    jsx = re_class.replace_all(&jsx, "className=").to_string();
-    
-    // Replace kebab-case attributes with camelCase
-    let re_attr = Regex::new(r"([a-z]+-[a-z0-9-]+)=").unwrap();
-    jsx = re_attr.replace_all(&jsx, |caps: &Captures| {
-        let attr = &caps[1];
-        // Skip data-* and aria-* attributes
-        if attr.starts_with("data-") || attr.starts_with("aria-") {
-            return format!("{}=", attr);
-        }
-        
-        let parts: Vec<&str> = attr.split('-').collect();
-        let mut camel = parts[0].to_string();
-        for part in parts.iter().skip(1) {
-            let mut chars = part.chars();
-            if let Some(f) = chars.next() {
-                camel.push(f.to_uppercase().next().unwrap());
-                camel.push_str(chars.as_str());
-            }
-        }
-        format!("{}=", camel)
-    }).to_string();
+pub fn svg_inner_content(content: &str) -> &str {
+    let Some(open) = content.find("<svg") else {
+        return content;
+    };
 
-    // Replace comments <!-- --> with {/* */}
-    jsx = jsx.replace("<!--", "{/*").replace("-->", "*/}");
-    
-    jsx
-}
-
-#[allow(dead_code)]
-pub fn svg_to_react_native(content: &str, name: &str, snippet: bool) -> String {
-    let mut svg = svg_to_jsx(content);
-
-    // Replacements map
-    let replacements = vec![
-        ("svg", "Svg"),
-        ("path", "Path"),
-        ("g", "G"),
-        ("circle", "Circle"),
-        ("rect", "Rect"),
-        ("line", "Line"),
-        ("polyline", "Polyline"),
-        ("polygon", "Polygon"),
-        ("ellipse", "Ellipse"),
-        ("text", "Text"),
-        ("tspan", "Tspan"),
-        ("textPath", "TextPath"),
-        ("defs", "Defs"),
-        ("use", "Use"),
-        ("symbol", "Symbol"),
-        ("linearGradient", "LinearGradient"),
-        ("radialGradient", "RadialGradient"),
-        ("stop", "Stop"),
-    ];
-
-    let mut used_components = Vec::new();
-
-    for (from, to) in &replacements {
-        let re_open = Regex::new(&format!(r"<{}([\s>])", from)).unwrap();
-        let re_close = Regex::new(&format!(r"</{}>", from)).unwrap();
-        
-        if re_open.is_match(&svg) || re_close.is_match(&svg) {
-            used_components.push(to.to_string());
-            svg = re_open.replace_all(&svg, format!("<{}$1", to).as_str()).to_string();
-            svg = re_close.replace_all(&svg, format!("</{}>", to).as_str()).to_string();
+    // Find the end of the start tag, skipping `>` inside quoted attribute values
+    let mut quote = None;
+    let mut start = None;
+    for (i, c) in content[open..].char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '>') => {
+                start = Some(open + i + 1);
+                break;
+            }
+            _ => {}
         }
     }
 
-    // Specific attribute replacements for React Native
-    svg = svg.replace("className=", "");
-    svg = svg.replace("href=", "xlinkHref=");
-    svg = svg.replace("clipPath=", "clipPath="); // Already camelCase from svg_to_jsx?
-    // svg_to_jsx handles kebab-case to camelCase, so stroke-width -> strokeWidth is already done.
-
-    // Generate imports
-    let mut imports = String::from("import Svg");
-    let other_components: Vec<String> = used_components.iter()
-        .filter(|c| c.as_str() != "Svg")
-        .cloned()
-        .collect();
-    
-    if !other_components.is_empty() {
-        imports.push_str(", { ");
-        imports.push_str(&other_components.join(", "));
-        imports.push_str(" }");
-    }
-    imports.push_str(" from 'react-native-svg';");
-
-    let code = format!(
-        r#"
-export function {}(props) {{
-  return (
-    {}
-  )
-}}"#,
-        name, svg
-    );
-
-    if snippet {
-        code
-    } else {
-        format!("import React from 'react';\n{}\n\n{}\nexport default {};", imports, code, name)
+    let Some(start) = start else {
+        return "";
+    };
+    if content[..start].ends_with("/>") {
+        return "";
     }
+    let end = content.rfind("</svg>").filter(|end| *end >= start).unwrap_or(content.len());
+    content[start..end].trim()
 }
 
 #[allow(dead_code)]
-pub fn svg_to_qwik(content: &str, name: &str, snippet: bool) -> String {
+pub fn svg_to_qwik(content: &str, name: &str, snippet: bool, typescript: bool) -> String {
     let svg = svg_to_jsx(content); // Qwik uses JSX-like syntax
     // Inject props and key
     let re_svg = Regex::new(r"<svg (.*?)>").unwrap();
     let svg_with_props = re_svg.replace(&svg, "<svg $1 {...props} key={key}>").to_string();
+    let params = if typescript { "props: QwikIntrinsicElements['svg'], key: string" } else { "props, key" };
 
     let code = format!(
         r#"
-export function {}(props: QwikIntrinsicElements['svg'], key: string) {{
+export function {}({}) {{
   return (
     {}
   )
 }}"#,
-        name, svg_with_props
+        name, params, svg_with_props
     );
 
     if snippet {
         code
-    } else {
+    } else if typescript {
         format!("import type {{ QwikIntrinsicElements }} from '@builder.io/qwik'\n{}\nexport default {}", code, name)
+    } else {
+        format!("{}\nexport default {}", code.trim_start(), name)
     }
 }
 
@@ -193,6 +125,183 @@ const props = Astro.props
     )
 }
 
+/// Root attributes a Rust component keeps from the source document; size, class and namespaces come from props
+const SKIPPED_ROOT_ATTRS: &[&str] = &["width", "height", "class", "style", "id", "version", "role", "aria-hidden"];
+
+/// Static root `<svg>` attributes (unescaped) and the markup inside it
+fn component_parts(content: &str) -> (Vec<(String, String)>, &str) {
+    let attrs = parse(content)
+        .and_then(|nodes| {
+            nodes.into_iter().find_map(|node| match node {
+                Node::Element(root) if root.name == "svg" => Some(root.attrs),
+                _ => None,
+            })
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(key, _)| !SKIPPED_ROOT_ATTRS.contains(&key.as_str()) && !key.starts_with("xmlns") && !key.contains(':'))
+        .map(|(key, value)| (key, unescape(&value)))
+        .collect();
+    (attrs, svg_inner_content(content))
+}
+
+/// Whether the root viewBox is square, so `size` can set both width and height
+fn is_square(attrs: &[(String, String)]) -> bool {
+    let view_box = attrs.iter().find(|(key, _)| key == "viewBox").map(|(_, value)| value.as_str());
+    let numbers: Vec<f32> = view_box
+        .unwrap_or_default()
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter_map(|n| n.parse().ok())
+        .collect();
+    numbers.len() != 4 || (numbers[2] - numbers[3]).abs() < 1e-3
+}
+
+/// Raw string literal with enough `#`s for any markup
+fn raw_string(text: &str) -> String {
+    let mut hashes = 1;
+    while text.contains(&format!("\"{}", "#".repeat(hashes))) {
+        hashes += 1;
+    }
+    let hashes = "#".repeat(hashes);
+    format!("r{}\"{}\"{}", hashes, text, hashes)
+}
+
+/// Code shared by the Rust components: the icon body, and its markup with an escaped `<title>`
+fn rust_body(body: &str) -> String {
+    format!(
+        "const BODY: &str = {};\n\n\
+        fn inner_html(title: Option<&str>) -> String {{\n    \
+            match title {{\n        \
+                Some(title) => format!(\"<title>{{}}</title>{{}}\", title.replace('&', \"&amp;\").replace('<', \"&lt;\"), BODY),\n        \
+                None => BODY.to_string(),\n    \
+            }}\n\
+        }}\n",
+        raw_string(body)
+    )
+}
+
+/// Leptos component with `size`, `color`, `class` and `title` props
+#[allow(dead_code)]
+pub fn svg_to_leptos(content: &str, name: &str) -> String {
+    let (attrs, body) = component_parts(content);
+    let name = type_name(name, |_| false);
+    let width = if is_square(&attrs) { " width=size.clone()" } else { "" };
+    let attrs: String = attrs.iter().map(|(key, value)| format!(" {}={:?}", key, value)).collect();
+    format!(
+        "use leptos::prelude::*;\n\n\
+        {body}\n\
+        /// Icon sized by `size` and drawn in `color` (or the current text color)\n\
+        #[component]\n\
+        pub fn {name}(\n    \
+            /// Height, and width for square icons, e.g. `\"24px\"`\n    \
+            #[prop(into, default = \"1em\".to_string())] size: String,\n    \
+            #[prop(into, optional)] color: Option<String>,\n    \
+            #[prop(into, optional)] class: Option<String>,\n    \
+            /// Accessible name; without one the icon is hidden from assistive technology\n    \
+            #[prop(into, optional)] title: Option<String>,\n\
+        ) -> impl IntoView {{\n    \
+            let role = title.is_some().then_some(\"img\");\n    \
+            let hidden = title.is_none().then_some(\"true\");\n    \
+            let inner = inner_html(title.as_deref());\n    \
+            view! {{\n        \
+                <svg xmlns=\"http://www.w3.org/2000/svg\"{attrs}{width} height=size color=color class=class role=role aria-hidden=hidden inner_html=inner></svg>\n    \
+            }}\n\
+        }}\n",
+        body = rust_body(body),
+        name = name,
+        attrs = attrs,
+        width = width,
+    )
+}
+
+/// Dioxus component with `size`, `color`, `class` and `title` props
+#[allow(dead_code)]
+pub fn svg_to_dioxus(content: &str, name: &str) -> String {
+    let (attrs, body) = component_parts(content);
+    let name = type_name(name, |_| false);
+    let mut attributes = String::from("            \"xmlns\": \"http://www.w3.org/2000/svg\",\n");
+    for (key, value) in &attrs {
+        attributes.push_str(&format!("            {:?}: {:?},\n", key, value));
+    }
+    if is_square(&attrs) {
+        attributes.push_str("            \"width\": \"{size}\",\n");
+    }
+    format!(
+        "use dioxus::prelude::*;\n\n\
+        {body}\n\
+        /// Icon sized by `size` and drawn in `color` (or the current text color)\n\
+        #[component]\n\
+        pub fn {name}(\n    \
+            /// Height, and width for square icons, e.g. `\"24px\"`\n    \
+            #[props(into, default = \"1em\".to_string())] size: String,\n    \
+            #[props(into)] color: Option<String>,\n    \
+            #[props(into)] class: Option<String>,\n    \
+            /// Accessible name; without one the icon is hidden from assistive technology\n    \
+            #[props(into)] title: Option<String>,\n\
+        ) -> Element {{\n    \
+            let role = title.is_some().then_some(\"img\");\n    \
+            let hidden = title.is_none().then_some(\"true\");\n    \
+            let inner = inner_html(title.as_deref());\n    \
+            rsx! {{\n        \
+                svg {{\n\
+        {attributes}            \
+                    \"height\": \"{{size}}\",\n            \
+                    \"color\": color,\n            \
+                    class: class,\n            \
+                    \"role\": role,\n            \
+                    \"aria-hidden\": hidden,\n            \
+                    dangerous_inner_html: inner,\n        \
+                }}\n    \
+            }}\n\
+        }}\n",
+        body = rust_body(body),
+        name = name,
+        attributes = attributes,
+    )
+}
+
+/// Yew function component with `size`, `color`, `class` and `title` props
+#[allow(dead_code)]
+pub fn svg_to_yew(content: &str, name: &str) -> String {
+    let (attrs, body) = component_parts(content);
+    let name = type_name(name, |_| false);
+    let width = if is_square(&attrs) { " width={props.size.clone()}" } else { "" };
+    let attrs: String = attrs.iter().map(|(key, value)| format!(" {}={:?}", key, value)).collect();
+    format!(
+        "use yew::prelude::*;\n\n\
+        {body}\n\
+        #[derive(Properties, PartialEq)]\n\
+        pub struct {name}Props {{\n    \
+            /// Height, and width for square icons, e.g. `\"24px\"`\n    \
+            #[prop_or(AttrValue::Static(\"1em\"))]\n    \
+            pub size: AttrValue,\n    \
+            #[prop_or_default]\n    \
+            pub color: Option<AttrValue>,\n    \
+            #[prop_or_default]\n    \
+            pub class: Classes,\n    \
+            /// Accessible name; without one the icon is hidden from assistive technology\n    \
+            #[prop_or_default]\n    \
+            pub title: Option<AttrValue>,\n\
+        }}\n\n\
+        /// Icon sized by `size` and drawn in `color` (or the current text color)\n\
+        #[function_component]\n\
+        pub fn {name}(props: &{name}Props) -> Html {{\n    \
+            let role = props.title.is_some().then_some(\"img\");\n    \
+            let hidden = props.title.is_none().then_some(\"true\");\n    \
+            let inner = Html::from_html_unchecked(inner_html(props.title.as_deref()).into());\n    \
+            html! {{\n        \
+                <svg xmlns=\"http://www.w3.org/2000/svg\"{attrs}{width} height={{props.size.clone()}} color={{props.color.clone()}} class={{props.class.clone()}} role={{role}} aria-hidden={{hidden}}>\n            \
+                    {{ inner }}\n        \
+                </svg>\n    \
+            }}\n\
+        }}\n",
+        body = rust_body(body),
+        name = name,
+        attrs = attrs,
+        width = width,
+    )
+}
+
 #[allow(dead_code)]
 pub fn to_pascal_case(s: &str) -> String {
     let s = s.replace("-", " ");
@@ -206,3 +315,254 @@ pub fn to_pascal_case(s: &str) -> String {
         })
         .collect::<String>()
 }
+
+/// Type name for an icon, e.g. `github_dark` -> `GithubDark`, `2fa` -> `Icon2fa`.
+/// Names that `reserved` holds for, such as types the generated code uses, get an `Icon` suffix.
+#[allow(dead_code)]
+pub(crate) fn type_name(name: &str, reserved: impl Fn(&str) -> bool) -> String {
+    let name = to_pascal_case(&name.replace('_', "-"));
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Icon{}", name)
+    } else if reserved(&name) {
+        format!("{}Icon", name)
+    } else {
+        name
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// A coordinate or length with at most `decimals` digits after the point and no trailing zeros
+#[allow(dead_code)]
+pub(crate) fn number(value: impl Into<f64>, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value.into());
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" { "0".to_string() } else { text.to_string() }
+}
+
+/// How `to_data_uri` encodes an SVG document
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataUriEncoding {
+    /// Base64, safe in any context but about a third larger than the markup
+    Base64,
+    /// Minimal percent-encoding, the compact form Iconify uses for CSS
+    Url,
+    /// Whichever of the two comes out shorter
+    Shortest,
+}
+
+/// Add the SVG namespace to the root element, which standalone documents in `<img>` and CSS require
+#[allow(dead_code)]
+pub fn svg_with_xmlns(svg: &str) -> Cow<'_, str> {
+    if svg.contains("http://www.w3.org/2000/svg") {
+        return Cow::Borrowed(svg);
+    }
+    Cow::Owned(svg.replacen("<svg", "<svg xmlns=\"http://www.w3.org/2000/svg\"", 1))
+}
+
+/// `data:image/svg+xml` URI for an SVG document
+#[allow(dead_code)]
+pub fn to_data_uri(svg: &str, encoding: DataUriEncoding) -> String {
+    let svg = svg_with_xmlns(svg);
+    match encoding {
+        DataUriEncoding::Base64 => format!("data:image/svg+xml;base64,{}", base64(svg.as_bytes())),
+        DataUriEncoding::Url => format!("data:image/svg+xml,{}", encode_svg_for_url(&svg)),
+        DataUriEncoding::Shortest => {
+            let base64 = to_data_uri(&svg, DataUriEncoding::Base64);
+            let url = to_data_uri(&svg, DataUriEncoding::Url);
+            if base64.len() < url.len() { base64 } else { url }
+        }
+    }
+}
+
+/// CSS `url("..")` value for `background-image`, `mask-image` and similar properties
+#[allow(dead_code)]
+pub fn to_css_url(svg: &str) -> String {
+    format!("url(\"{}\")", to_data_uri(svg, DataUriEncoding::Url))
+}
+
+/// `<img>` tag embedding an SVG document, sized like its root element
+#[allow(dead_code)]
+pub fn to_img_tag(svg: &str, alt: &str) -> String {
+    let mut img = format!("<img src=\"{}\" alt=\"{}\"", to_data_uri(svg, DataUriEncoding::Url), escape_attribute(alt));
+    for name in ["width", "height"] {
+        if let Some(value) = root_attribute(svg, name) {
+            img.push_str(&format!(" {}=\"{}\"", name, value.trim_end_matches("px")));
+        }
+    }
+    img.push_str(" />");
+    img
+}
+
+/// Value of an attribute of the root `<svg>` element
+#[allow(dead_code)]
+pub(crate) fn root_attribute<'a>(svg: &'a str, name: &str) -> Option<&'a str> {
+    let tag = ROOT_TAG.find(svg)?.as_str();
+    ATTRIBUTE
+        .captures_iter(tag)
+        .find(|caps| &caps[1] == name)
+        .map(|caps| caps.get(2).unwrap().as_str())
+}
+
+/// Iconify's `encodeSvgForCss`: prefer single quotes around attribute values and escape only what
+/// breaks a URL. Values that already hold a `'` keep double quotes as `%22`, and only whitespace
+/// between tags is dropped so text content survives.
+fn encode_svg_for_url(svg: &str) -> String {
+    let mut encoded = String::with_capacity(svg.len());
+    let mut chars = svg.char_indices().peekable();
+    let mut in_tag = false;
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            '"' | '\'' if in_tag => {
+                let end = svg[i + 1..].find(c).map_or(svg.len(), |end| i + 1 + end);
+                let value = &svg[i + 1..end];
+                let quote = if value.contains('\'') { "%22" } else { "'" };
+                encoded.push_str(quote);
+                for c in value.chars() {
+                    encode_url_char(c, &mut encoded);
+                }
+                if end < svg.len() {
+                    encoded.push_str(quote);
+                }
+                while chars.next_if(|&(j, _)| j <= end).is_some() {}
+                continue;
+            }
+            c if c.is_whitespace() && in_tag => {
+                while chars.next_if(|&(_, c)| c.is_whitespace()).is_some() {}
+                encoded.push(' ');
+                continue;
+            }
+            c if c.is_whitespace() => {
+                let end = svg[i..].find(|c: char| !c.is_whitespace()).map_or(svg.len(), |end| i + end);
+                // Indentation between tags is dropped; whitespace inside text is kept
+                let between_tags = (i == 0 || svg[..i].ends_with('>')) && (end == svg.len() || svg[end..].starts_with('<'));
+                if !between_tags {
+                    svg[i..end].chars().for_each(|c| encode_url_char(c, &mut encoded));
+                }
+                while chars.next_if(|&(j, _)| j < end).is_some() {}
+                continue;
+            }
+            _ => {}
+        }
+        encode_url_char(c, &mut encoded);
+    }
+    encoded
+}
+
+fn encode_url_char(c: char, encoded: &mut String) {
+    match c {
+        '"' => encoded.push_str("%22"),
+        '%' => encoded.push_str("%25"),
+        '#' => encoded.push_str("%23"),
+        '<' => encoded.push_str("%3C"),
+        '>' => encoded.push_str("%3E"),
+        '\n' => encoded.push_str("%0A"),
+        '\r' => encoded.push_str("%0D"),
+        '\t' => encoded.push_str("%09"),
+        _ => encoded.push(c),
+    }
+}
+
+/// Escape text for a double-quoted attribute
+#[allow(dead_code)]
+pub(crate) fn escape_attribute(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_uris() {
+        let svg = "<svg viewBox=\"0 0 24 24\" width=\"24px\" height=\"24\">\n  <path fill=\"#f00\" d=\"M0 0h24v24z\"/>\n</svg>";
+        assert_eq!(
+            to_data_uri(svg, DataUriEncoding::Url),
+            "data:image/svg+xml,%3Csvg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 24 24' width='24px' height='24'%3E%3Cpath fill='%23f00' d='M0 0h24v24z'/%3E%3C/svg%3E"
+        );
+        // Values holding single quotes keep double quotes, and text keeps its spaces
+        let quoted = "<svg><text font-family=\"'Inter'\" style='fill: url(\"#a\")'>a  b\n c</text></svg>";
+        assert_eq!(
+            to_data_uri(quoted, DataUriEncoding::Url),
+            "data:image/svg+xml,%3Csvg xmlns='http://www.w3.org/2000/svg'%3E%3Ctext font-family=%22'Inter'%22 style='fill: url(%22%23a%22)'%3Ea  b%0A c%3C/text%3E%3C/svg%3E"
+        );
+        assert!(to_css_url(svg).starts_with("url(\"data:image/svg+xml,%3Csvg xmlns="));
+        assert_eq!(base64(b"Man"), "TWFu");
+        assert_eq!(base64(b"Ma"), "TWE=");
+        assert_eq!(base64(b"M"), "TQ==");
+        assert_eq!(
+            to_data_uri("<svg xmlns=\"http://www.w3.org/2000/svg\"/>", DataUriEncoding::Base64),
+            "data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciLz4="
+        );
+        assert!(to_data_uri(svg, DataUriEncoding::Shortest).starts_with("data:image/svg+xml,"));
+
+        let img = to_img_tag(svg, "Tom & \"Jerry\"");
+        assert!(img.starts_with("<img src=\"data:image/svg+xml,%3Csvg"));
+        assert!(img.ends_with(" alt=\"Tom &amp; &quot;Jerry&quot;\" width=\"24\" height=\"24\" />"));
+    }
+
+    #[test]
+    fn test_rust_components() {
+        let svg = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 32 16" width="32" fill="currentColor" aria-label="a &quot;b&quot;"><path d="M0 0h32v16z"/><text>"#</text></svg>"##;
+        let leptos = svg_to_leptos(svg, "wide_logo");
+        assert!(leptos.starts_with("use leptos::prelude::*;\n\nconst BODY: &str = r##\"<path d=\"M0 0h32v16z\"/><text>\"#</text>\"##;"));
+        assert!(leptos.contains("pub fn WideLogo(\n"));
+        assert!(leptos.contains("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 32 16\" fill=\"currentColor\" aria-label=\"a \\\"b\\\"\" height=size color=color"));
+
+        let dioxus = svg_to_dioxus(svg, "2fa");
+        assert!(dioxus.contains("pub fn Icon2fa(\n"));
+        assert!(dioxus.contains("            \"viewBox\": \"0 0 32 16\",\n            \"fill\": \"currentColor\",\n"));
+        assert!(!dioxus.contains("\"width\""));
+        assert!(dioxus.contains("dangerous_inner_html: inner,"));
+
+        let yew = svg_to_yew("<svg viewBox=\"0 0 24 24\"><path d=\"M0 0h24\"/></svg>", "home");
+        assert!(yew.contains("pub struct HomeProps {"));
+        assert!(yew.contains("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 24 24\" width={props.size.clone()} height={props.size.clone()}"));
+        assert!(yew.contains("const BODY: &str = r#\"<path d=\"M0 0h24\"/>\"#;"));
+    }
+
+    #[test]
+    fn test_export_leptos() {
+        use crate::export::{export_set, ExportOptions, Framework, IconSelection};
+        let registry = crate::IconRegistry::new();
+        let out = std::env::temp_dir().join(format!("dx-icon-leptos-export-test-{}", std::process::id()));
+        std::fs::remove_dir_all(&out).ok();
+
+        let leptos = ExportOptions { framework: Framework::Leptos, ..Default::default() };
+        let names = IconSelection::Names(vec!["home-outline".to_string(), "numeric-1".to_string(), "loop".to_string()]);
+        let report = export_set(&registry, "mdi", &names, &leptos, &out).unwrap();
+        assert!(std::fs::read_to_string(out.join("home_outline.rs")).unwrap().contains("pub fn HomeOutline("));
+        let modules = std::fs::read_to_string(report.barrel.unwrap()).unwrap();
+        assert!(modules.contains("mod home_outline;\npub use home_outline::HomeOutline;\n"));
+        assert!(modules.contains("mod numeric_1;\npub use numeric_1::Numeric1;\n"));
+        assert!(modules.contains("mod icon_loop;\npub use icon_loop::IconLoop;\n"));
+
+        std::fs::remove_dir_all(out).ok();
+    }
+}
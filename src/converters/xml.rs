@@ -0,0 +1,125 @@
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// Minimal XML tree shared by the SVG optimizer and the JSX transformer
+#[derive(Debug, Clone)]
+pub(crate) enum Node {
+    Element(Element),
+    Text(String),
+    Comment(String),
+    CData(String),
+    /// Declarations, doctypes and processing instructions, kept verbatim
+    Prolog(String),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Element {
+    pub(crate) name: String,
+    /// Attribute values stay escaped, exactly as written
+    pub(crate) attrs: Vec<(String, String)>,
+    pub(crate) children: Vec<Node>,
+}
+
+impl Element {
+    pub(crate) fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+
+    pub(crate) fn set_attr(&mut self, name: &str, value: String) {
+        match self.attrs.iter_mut().find(|(key, _)| key == name) {
+            Some(attr) => attr.1 = value,
+            None => self.attrs.push((name.to_string(), value)),
+        }
+    }
+
+    pub(crate) fn has_element_children(&self) -> bool {
+        self.children.iter().any(|child| matches!(child, Node::Element(_)))
+    }
+}
+
+/// Parse markup into a tree; `None` when it isn't well-formed
+pub(crate) fn parse(svg: &str) -> Option<Vec<Node>> {
+    let mut reader = Reader::from_str(svg);
+    let mut stack: Vec<Element> = Vec::new();
+    let mut roots = Vec::new();
+    loop {
+        let text = |bytes: &[u8]| String::from_utf8(bytes.to_vec()).ok();
+        let node = match reader.read_event().ok()? {
+            Event::Start(e) => {
+                stack.push(element(&e)?);
+                continue;
+            }
+            Event::End(_) => Node::Element(stack.pop()?),
+            Event::Empty(e) => Node::Element(element(&e)?),
+            Event::Text(e) => Node::Text(text(&e)?),
+            Event::CData(e) => Node::CData(text(&e)?),
+            Event::Comment(e) => Node::Comment(text(&e)?),
+            Event::Decl(e) => Node::Prolog(format!("<?{}?>", text(&e)?)),
+            Event::PI(e) => Node::Prolog(format!("<?{}?>", text(&e)?)),
+            Event::DocType(e) => Node::Prolog(format!("<!DOCTYPE {}>", text(&e)?)),
+            Event::Eof => break,
+        };
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => roots.push(node),
+        }
+    }
+    if stack.is_empty() {
+        Some(roots)
+    } else {
+        None
+    }
+}
+
+fn element(start: &BytesStart) -> Option<Element> {
+    let name = String::from_utf8(start.name().as_ref().to_vec()).ok()?;
+    let mut attrs = Vec::new();
+    for attr in start.attributes() {
+        let attr = attr.ok()?;
+        let key = String::from_utf8(attr.key.as_ref().to_vec()).ok()?;
+        let value = String::from_utf8(attr.value.to_vec()).ok()?;
+        attrs.push((key, value));
+    }
+    Some(Element { name, attrs, children: Vec::new() })
+}
+
+pub(crate) fn write_nodes(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Element(element) => {
+                out.push('<');
+                out.push_str(&element.name);
+                for (key, value) in &element.attrs {
+                    let quote = if value.contains('"') { '\'' } else { '"' };
+                    out.push(' ');
+                    out.push_str(key);
+                    out.push('=');
+                    out.push(quote);
+                    out.push_str(value);
+                    out.push(quote);
+                }
+                if element.children.is_empty() {
+                    out.push_str("/>");
+                } else {
+                    out.push('>');
+                    write_nodes(&element.children, out);
+                    out.push_str("</");
+                    out.push_str(&element.name);
+                    out.push('>');
+                }
+            }
+            Node::Text(text) => out.push_str(text),
+            Node::Comment(comment) => {
+                out.push_str("<!--");
+                out.push_str(comment);
+                out.push_str("-->");
+            }
+            Node::CData(data) => {
+                out.push_str("<![CDATA[");
+                out.push_str(data);
+                out.push_str("]]>");
+            }
+            Node::Prolog(prolog) => out.push_str(prolog),
+        }
+    }
+}
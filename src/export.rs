@@ -0,0 +1,526 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use crate::converters::android::{svg_to_android, KOTLIN_NAMES};
+use crate::converters::flutter::{dx_icons_library, is_dart_class, svg_to_custom_painter};
+use crate::converters::optimize::{OptimizeOptions, OptimizeStats};
+use crate::converters::react_native::{imports_name, svg_to_native};
+use crate::converters::svgl::{SvgIcon, Theme};
+use crate::converters::utils::to_pascal_case;
+use crate::registry::{IconRegistry, ResolvedIcon, SVGL_PREFIX};
+
+/// Output format for exported icons
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Framework {
+    Svg,
+    React,
+    Vue,
+    Svelte,
+    ReactNative,
+    Qwik,
+    Solid,
+    Astro,
+    Leptos,
+    Dioxus,
+    Yew,
+    VectorDrawable,
+    Compose,
+    Flutter,
+    FlutterPainter,
+}
+
+impl Framework {
+    pub const ALL: [Framework; 15] = [
+        Framework::Svg,
+        Framework::React,
+        Framework::Vue,
+        Framework::Svelte,
+        Framework::ReactNative,
+        Framework::Qwik,
+        Framework::Solid,
+        Framework::Astro,
+        Framework::Leptos,
+        Framework::Dioxus,
+        Framework::Yew,
+        Framework::VectorDrawable,
+        Framework::Compose,
+        Framework::Flutter,
+        Framework::FlutterPainter,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Framework::Svg => "svg",
+            Framework::React => "react",
+            Framework::Vue => "vue",
+            Framework::Svelte => "svelte",
+            Framework::ReactNative => "react-native",
+            Framework::Qwik => "qwik",
+            Framework::Solid => "solid",
+            Framework::Astro => "astro",
+            Framework::Leptos => "leptos",
+            Framework::Dioxus => "dioxus",
+            Framework::Yew => "yew",
+            Framework::VectorDrawable => "vector-drawable",
+            Framework::Compose => "compose",
+            Framework::Flutter => "flutter",
+            Framework::FlutterPainter => "flutter-painter",
+        }
+    }
+
+    pub fn extension(self, typescript: bool) -> &'static str {
+        match self {
+            Framework::Svg => "svg",
+            Framework::Vue => "vue",
+            Framework::Svelte => "svelte",
+            Framework::Astro => "astro",
+            Framework::Leptos | Framework::Dioxus | Framework::Yew => "rs",
+            Framework::VectorDrawable => "xml",
+            Framework::Compose => "kt",
+            Framework::Flutter | Framework::FlutterPainter => "dart",
+            Framework::React | Framework::ReactNative | Framework::Qwik | Framework::Solid => {
+                if typescript { "tsx" } else { "jsx" }
+            }
+        }
+    }
+
+    /// Render an icon, using `stem` for the component name
+    pub fn render(self, icon: &ResolvedIcon, stem: &str, typescript: bool) -> String {
+        let svg = SvgIcon {
+            filename: stem.to_string(),
+            svg_content: icon.to_svg().into_owned(),
+            viewbox: Some(icon.view_box.view_box()),
+            width: None,
+            height: None,
+        };
+        match self {
+            Framework::Svg => svg.svg_content,
+            Framework::React => svg.to_react_component(typescript),
+            Framework::Vue => svg.to_vue_component(typescript),
+            Framework::Svelte => svg.to_svelte_component(typescript),
+            Framework::ReactNative => svg.to_react_native_component(typescript),
+            Framework::Qwik => svg.to_qwik_component(typescript),
+            Framework::Solid => svg.to_solid_component(typescript),
+            Framework::Astro => svg.to_astro_component(),
+            Framework::Leptos => svg.to_leptos_component(),
+            Framework::Dioxus => svg.to_dioxus_component(),
+            Framework::Yew => svg.to_yew_component(),
+            Framework::VectorDrawable => svg.to_vector_drawable(),
+            Framework::Compose => svg.to_compose_image_vector(),
+            Framework::Flutter => svg.to_flutter_widget(),
+            Framework::FlutterPainter => svg.to_flutter_painter(),
+        }
+    }
+
+    /// Prepend a comment in this format's syntax
+    fn with_header(self, header: &str, content: &str) -> String {
+        match self {
+            Framework::Svg | Framework::Vue | Framework::Svelte | Framework::VectorDrawable => {
+                format!("<!--\n{}\n-->\n{}", header, content)
+            }
+            // Astro only allows code comments inside its frontmatter fence
+            Framework::Astro => match content.strip_prefix("---\n") {
+                Some(rest) => format!("---\n{}\n{}", line_comments(header), rest),
+                None => format!("---\n{}\n---\n{}", line_comments(header), content),
+            },
+            _ => format!("/*\n{}\n*/\n{}", header, content),
+        }
+    }
+
+    /// Whether components can be re-exported from an `index` barrel
+    fn has_barrel(self) -> bool {
+        !matches!(self, Framework::Svg | Framework::VectorDrawable | Framework::Compose)
+    }
+
+    /// Whether the generated module imports `name`, so a component can't also be called that
+    fn reserves(self, name: &str) -> bool {
+        match self {
+            Framework::React => name == "React",
+            Framework::ReactNative => imports_name(name),
+            Framework::Qwik => name == "QwikIntrinsicElements",
+            Framework::Solid => name == "JSX",
+            Framework::Compose => KOTLIN_NAMES.contains(&name),
+            Framework::Flutter | Framework::FlutterPainter => is_dart_class(name),
+            _ => false,
+        }
+    }
+
+    /// Rust components are modules named in snake_case, re-exported from a `mod.rs`
+    fn is_rust(self) -> bool {
+        matches!(self, Framework::Leptos | Framework::Dioxus | Framework::Yew)
+    }
+
+    /// Android resource names and Dart libraries are lowercase snake_case
+    fn snake_case_stems(self) -> bool {
+        self.is_rust() || matches!(self, Framework::VectorDrawable | Framework::Flutter | Framework::FlutterPainter)
+    }
+
+    /// What the converter for this format could not represent
+    fn warnings(self, icon: &ResolvedIcon) -> Vec<String> {
+        match self {
+            Framework::ReactNative => svg_to_native(&icon.to_svg()).warnings,
+            Framework::VectorDrawable | Framework::Compose => svg_to_android(&icon.to_svg()).warnings,
+            Framework::FlutterPainter => svg_to_custom_painter(&icon.to_svg(), "").warnings,
+            _ => Vec::new(),
+        }
+    }
+
+    fn barrel_file(self, typescript: bool) -> &'static str {
+        match self {
+            _ if self.is_rust() => "mod.rs",
+            Framework::Flutter | Framework::FlutterPainter => "dx_icons.dart",
+            _ if typescript => "index.ts",
+            _ => "index.js",
+        }
+    }
+}
+
+impl FromStr for Framework {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Framework::ALL
+            .into_iter()
+            .find(|framework| framework.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = Framework::ALL.iter().map(|f| f.as_str()).collect();
+                format!("unknown framework '{}', expected one of: {}", s, names.join(", "))
+            })
+    }
+}
+
+fn line_comments(text: &str) -> String {
+    text.lines().map(|line| format!("// {}", line)).collect::<Vec<_>>().join("\n")
+}
+
+/// Which icons of a set to export
+#[derive(Debug, Clone)]
+pub enum IconSelection {
+    /// Every visible icon in the set
+    All,
+    /// Exact icon or alias names
+    Names(Vec<String>),
+    /// Shell-style patterns such as `arrow-*` or `chevron-?-*`
+    Globs(Vec<String>),
+}
+
+impl IconSelection {
+    /// `prefix:name` ids of the selected icons, or `None` if the set doesn't exist
+    pub fn ids(&self, registry: &IconRegistry, prefix: &str) -> Option<Vec<String>> {
+        let available = set_icon_names(registry, prefix)?;
+        let names = match self {
+            IconSelection::All => available,
+            IconSelection::Names(names) => names.clone(),
+            IconSelection::Globs(patterns) => available
+                .into_iter()
+                .filter(|name| patterns.iter().any(|pattern| glob_match(pattern, name)))
+                .collect(),
+        };
+        Some(names.iter().map(|name| format!("{}:{}", prefix, name)).collect())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    pub framework: Framework,
+    pub typescript: bool,
+    /// Variant for svgl logos with light and dark files
+    pub theme: Option<Theme>,
+    /// Write an `index.ts`/`index.js` (or `mod.rs`, `dx_icons.dart`) re-exporting every component
+    pub barrel: bool,
+    /// Start every file with a comment naming the icon and its license
+    pub license_header: bool,
+    /// Optimizer passes applied to each icon before rendering; `None` exports markup as stored
+    pub optimize: Option<OptimizeOptions>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            framework: Framework::Svg,
+            typescript: true,
+            theme: None,
+            barrel: true,
+            license_header: true,
+            optimize: Some(OptimizeOptions::default()),
+        }
+    }
+}
+
+/// Files written by an export
+#[derive(Debug, Default)]
+pub struct ExportReport {
+    pub files: Vec<PathBuf>,
+    pub barrel: Option<PathBuf>,
+    /// Requested ids or names that did not resolve to an icon
+    pub missing: Vec<String>,
+    /// Icon sizes before and after optimization
+    pub stats: OptimizeStats,
+    /// Markup that the target framework cannot represent and was dropped, as `prefix:name: reason`
+    pub warnings: Vec<String>,
+}
+
+/// Export a selection of one icon set, naming files after the icons
+pub fn export_set(
+    registry: &IconRegistry,
+    prefix: &str,
+    selection: &IconSelection,
+    options: &ExportOptions,
+    out_dir: &Path,
+) -> Result<ExportReport, Box<dyn Error>> {
+    let ids = selection.ids(registry, prefix).ok_or_else(|| format!("icon set '{}' not found", prefix))?;
+    export_icons(registry, &ids, options, out_dir)
+}
+
+/// Export icons by `prefix:name` id; file names include the prefix when ids span several sets
+pub fn export_icons(
+    registry: &IconRegistry,
+    ids: &[String],
+    options: &ExportOptions,
+    out_dir: &Path,
+) -> Result<ExportReport, Box<dyn Error>> {
+    let mut report = ExportReport::default();
+    let mut icons = Vec::new();
+    for id in ids {
+        let icon = match options.theme {
+            Some(theme) => registry.resolve_themed(id, theme),
+            None => registry.resolve(id),
+        };
+        match icon {
+            Some(mut icon) => {
+                if let Some(optimize) = &options.optimize {
+                    report.stats += icon.optimize(optimize);
+                }
+                icons.push(icon);
+            }
+            None => report.missing.push(id.clone()),
+        }
+    }
+
+    let prefixed = icons.windows(2).any(|pair| pair[0].prefix != pair[1].prefix);
+    let framework = options.framework;
+    let extension = framework.extension(options.typescript);
+
+    fs::create_dir_all(out_dir)?;
+    let mut used = HashSet::new();
+    let mut exports = Vec::new();
+    for icon in &icons {
+        let stem = unique_stem(&mut used, icon, prefixed, framework);
+        let mut content = framework.render(icon, &stem, options.typescript);
+        for warning in framework.warnings(icon) {
+            report.warnings.push(format!("{}: {}", icon.id(), warning));
+        }
+        if options.license_header {
+            content = framework.with_header(&license_header(icon), &content);
+        }
+
+        let file_name = format!("{}.{}", stem, extension);
+        let path = out_dir.join(&file_name);
+        fs::write(&path, content)?;
+        report.files.push(path);
+        exports.push((to_pascal_case(&stem.replace('_', "-")), stem, file_name));
+    }
+
+    if options.barrel && framework.has_barrel() && !exports.is_empty() {
+        let path = out_dir.join(framework.barrel_file(options.typescript));
+        fs::write(&path, barrel(&exports, framework))?;
+        report.barrel = Some(path);
+    }
+
+    Ok(report)
+}
+
+/// Visible icon names of a set, sorted
+fn set_icon_names(registry: &IconRegistry, prefix: &str) -> Option<Vec<String>> {
+    if prefix == SVGL_PREFIX {
+        let svgl = registry.svgl()?;
+        return Some(svgl.iter().map(|icon| icon.id().to_string()).collect());
+    }
+
+    let set = registry.icon_sets().get(prefix)?;
+    Some(
+        set.view()
+            .iter()
+            .filter(|icon| !icon.hidden())
+            .map(|icon| icon.name().to_string())
+            .collect(),
+    )
+}
+
+/// Words that can't name a Rust module
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self",
+    "static", "struct", "super", "trait", "true", "try", "type", "unsafe", "use", "where", "while", "yield",
+];
+
+/// File stem that is also a valid, unique component name once PascalCased
+pub(crate) fn unique_stem(used: &mut HashSet<String>, icon: &ResolvedIcon, prefixed: bool, framework: Framework) -> String {
+    unique_name(used, icon, prefixed, framework, |name| framework.reserves(name))
+}
+
+/// `unique_stem` for generated code outside `Framework`, with `reserved` telling which
+/// PascalCase names the surrounding code already uses
+pub(crate) fn unique_name(
+    used: &mut HashSet<String>,
+    icon: &ResolvedIcon,
+    prefixed: bool,
+    framework: Framework,
+    reserved: impl Fn(&str) -> bool,
+) -> String {
+    let name = icon.name.replace('_', "-");
+    let mut base = if prefixed { format!("{}-{}", icon.prefix, name) } else { name };
+    if framework != Framework::Svg {
+        // Names like `123` or `2fa` aren't valid identifiers
+        if base.starts_with(|c: char| c.is_ascii_digit()) {
+            base = format!("icon-{}", base);
+        }
+        base = if framework.snake_case_stems() {
+            let module = base.to_lowercase().replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+            if framework.is_rust() && RUST_KEYWORDS.contains(&module.as_str()) { format!("icon_{}", module) } else { module }
+        } else {
+            to_pascal_case(&base)
+        };
+        // An icon named `react` can't be `const React` next to `import * as React`
+        if reserved(&to_pascal_case(&base.replace('_', "-"))) {
+            base.push_str(if framework.snake_case_stems() { "_icon" } else { "Icon" });
+        }
+    }
+
+    let mut stem = base.clone();
+    let mut n = 2;
+    while !used.insert(stem.to_lowercase()) {
+        let separator = if framework.snake_case_stems() { "_" } else { "" };
+        stem = format!("{}{}{}", base, separator, n);
+        n += 1;
+    }
+    stem
+}
+
+fn license_header(icon: &ResolvedIcon) -> String {
+    let mut header = icon.id();
+    if let Some(license) = &icon.license {
+        header.push_str(&format!("\nLicense: {}", license.title));
+        if !license.spdx.is_empty() && license.spdx != license.title {
+            header.push_str(&format!(" ({})", license.spdx));
+        }
+        if let Some(url) = license.url {
+            header.push_str(&format!("\n{}", url));
+        }
+    }
+    header
+}
+
+fn barrel(exports: &[(String, String, String)], framework: Framework) -> String {
+    if matches!(framework, Framework::Flutter | Framework::FlutterPainter) {
+        let classes: Vec<_> = exports.iter().map(|(class, _, file_name)| (class.clone(), file_name.clone())).collect();
+        return dx_icons_library(&classes);
+    }
+    let mut barrel = String::new();
+    for (component, stem, file_name) in exports {
+        if framework.is_rust() {
+            barrel.push_str(&format!("mod {};\npub use {}::{};\n", stem, stem, component));
+            continue;
+        }
+        // Single-file components are imported with their extension
+        let module = match framework {
+            Framework::Vue | Framework::Svelte | Framework::Astro => file_name.as_str(),
+            _ => stem.as_str(),
+        };
+        barrel.push_str(&format!("export {{ default as {} }} from './{}';\n", component, module));
+    }
+    barrel
+}
+
+/// Match `*` (any run of characters) and `?` (any one character) against a name
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` swallow one more character and retry
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("arrow-*", "arrow-left"));
+        assert!(glob_match("*-outline", "home-outline"));
+        assert!(glob_match("a?c*", "abc"));
+        assert!(!glob_match("arrow-*", "narrow-left"));
+        assert!(!glob_match("a?c", "ac"));
+    }
+
+    #[test]
+    fn test_export_set() {
+        let registry = IconRegistry::new();
+        let out = std::env::temp_dir().join(format!("dx-icon-export-test-{}", std::process::id()));
+        fs::remove_dir_all(&out).ok();
+
+        let options = ExportOptions { framework: Framework::React, ..Default::default() };
+        let selection = IconSelection::Globs(vec!["home-*".to_string(), "numeric-1".to_string()]);
+        let report = export_set(&registry, "mdi", &selection, &options, &out).unwrap();
+
+        assert!(report.files.len() > 5);
+        assert!(report.missing.is_empty());
+        let home = fs::read_to_string(out.join("HomeOutline.tsx")).unwrap();
+        assert!(home.starts_with("/*\nmdi:home-outline\nLicense: Apache 2.0 (Apache-2.0)"));
+        assert!(home.contains("const HomeOutline = "));
+
+        let barrel = fs::read_to_string(report.barrel.unwrap()).unwrap();
+        assert!(barrel.contains("export { default as HomeOutline } from './HomeOutline';\n"));
+        assert!(barrel.contains("export { default as Numeric1 } from './Numeric1';\n"));
+
+        let names = IconSelection::Names(vec!["home".to_string(), "missing".to_string()]);
+        let svg = ExportOptions { framework: Framework::Svg, ..Default::default() };
+        let report = export_set(&registry, "mdi", &names, &svg, &out).unwrap();
+        assert_eq!(report.missing, ["mdi:missing"]);
+        assert!(report.barrel.is_none());
+        assert!(fs::read_to_string(out.join("home.svg")).unwrap().starts_with("<!--\nmdi:home"));
+
+        let qwik = ExportOptions { framework: Framework::Qwik, typescript: false, barrel: false, ..Default::default() };
+        export_set(&registry, "mdi", &names, &qwik, &out).unwrap();
+        let home = fs::read_to_string(out.join("Home.jsx")).unwrap();
+        assert!(home.contains("export function Home(props, key) {"));
+        assert!(!home.contains("QwikIntrinsicElements"));
+
+        // Components can't take the names of what their modules import
+        let react = IconSelection::Names(vec!["react".to_string()]);
+        export_set(&registry, "mdi", &react, &options, &out).unwrap();
+        let component = fs::read_to_string(out.join("ReactIcon.tsx")).unwrap();
+        assert!(component.contains("import * as React from 'react';") && component.contains("const ReactIcon = "));
+        let native = ExportOptions { framework: Framework::ReactNative, barrel: false, ..Default::default() };
+        let svg_icon = IconSelection::Names(vec!["svg".to_string()]);
+        export_set(&registry, "mdi", &svg_icon, &native, &out).unwrap();
+        assert!(fs::read_to_string(out.join("SvgIcon.tsx")).unwrap().contains("export default SvgIcon;"));
+
+        fs::remove_dir_all(out).ok();
+    }
+}
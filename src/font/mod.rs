@@ -0,0 +1,278 @@
+mod outline;
+mod sfnt;
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use crate::converters::iconsets::IconSetJson;
+use crate::converters::render::IconBox;
+use outline::Contour;
+
+/// First codepoint handed out to icons without a `chars` mapping
+pub const PUA_START: u32 = 0xE000;
+pub const PUA_END: u32 = 0xF8FF;
+
+/// Glyph added to an `IconFont`
+#[derive(Debug, Clone)]
+pub struct FontGlyph {
+    pub name: String,
+    pub codepoint: u32,
+    advance: u16,
+    contours: Vec<Contour>,
+}
+
+/// Builds TrueType and WOFF2 icon fonts, plus CSS classes for each glyph
+#[derive(Debug)]
+pub struct IconFont {
+    family: String,
+    units_per_em: u16,
+    glyphs: Vec<FontGlyph>,
+    names: HashSet<String>,
+    codepoints: HashSet<u32>,
+    next_codepoint: u32,
+}
+
+impl IconFont {
+    pub fn new(family: &str) -> Self {
+        IconFont {
+            family: family.to_string(),
+            units_per_em: 1000,
+            glyphs: Vec::new(),
+            names: HashSet::new(),
+            codepoints: HashSet::new(),
+            next_codepoint: PUA_START,
+        }
+    }
+
+    /// Add an icon body as a glyph and return its codepoint.
+    /// Without a codepoint, or when it is already taken, the next free Private Use Area codepoint is used.
+    pub fn add(&mut self, name: &str, body: &str, view_box: IconBox, codepoint: Option<u32>) -> Result<u32, Box<dyn Error>> {
+        if self.names.contains(name) {
+            return Err(format!("duplicate glyph name '{}'", name).into());
+        }
+        let contours = outline::icon_contours(body, view_box, self.units_per_em)?;
+        if contours.is_empty() {
+            return Err(format!("icon '{}' has no fillable outlines", name).into());
+        }
+
+        let codepoint = match codepoint.filter(|c| !self.codepoints.contains(c)) {
+            Some(codepoint) => codepoint,
+            None => self.next_free_codepoint()?,
+        };
+        self.names.insert(name.to_string());
+        self.codepoints.insert(codepoint);
+        self.glyphs.push(FontGlyph {
+            name: name.to_string(),
+            codepoint,
+            advance: outline::advance_width(view_box, self.units_per_em),
+            contours,
+        });
+        Ok(codepoint)
+    }
+
+    /// Add an icon from an Iconify set, using its codepoint from the set's `chars` map when present
+    pub fn add_from_set(&mut self, iconset: &IconSetJson, name: &str) -> Result<u32, Box<dyn Error>> {
+        let icon = iconset.render(name).ok_or_else(|| format!("icon '{}' not found", name))?;
+        self.add(name, &icon.body, icon.view_box, char_codepoints(iconset.chars.iter().map(|(chars, name)| (chars.as_str(), name.as_str()))).get(name).copied())
+    }
+
+    pub fn family(&self) -> &str {
+        &self.family
+    }
+
+    pub fn glyphs(&self) -> &[FontGlyph] {
+        &self.glyphs
+    }
+
+    pub fn to_ttf(&self) -> Vec<u8> {
+        let glyphs: Vec<sfnt::GlyphData> = self
+            .glyphs
+            .iter()
+            .map(|glyph| sfnt::GlyphData {
+                name: &glyph.name,
+                codepoint: glyph.codepoint,
+                advance: glyph.advance,
+                contours: &glyph.contours,
+            })
+            .collect();
+        sfnt::build_ttf(&self.family, self.units_per_em, &glyphs)
+    }
+
+    pub fn to_woff2(&self) -> Vec<u8> {
+        sfnt::ttf_to_woff2(&self.to_ttf())
+    }
+
+    /// `@font-face` rule for `{font_url}.woff2`/`.ttf` and a `.{class_prefix}-{name}::before` class per glyph
+    pub fn to_css(&self, font_url: &str, class_prefix: &str) -> String {
+        let mut css = format!(
+            "@font-face {{\n  \
+            font-family: \"{family}\";\n  \
+            src: url(\"{url}.woff2\") format(\"woff2\"), url(\"{url}.ttf\") format(\"truetype\");\n  \
+            font-weight: normal;\n  \
+            font-style: normal;\n  \
+            font-display: block;\n\
+            }}\n\n\
+            [class^=\"{prefix}-\"]::before,\n\
+            [class*=\" {prefix}-\"]::before {{\n  \
+            font-family: \"{family}\" !important;\n  \
+            font-style: normal;\n  \
+            font-weight: normal;\n  \
+            font-variant: normal;\n  \
+            text-transform: none;\n  \
+            line-height: 1;\n  \
+            vertical-align: -0.125em;\n  \
+            -webkit-font-smoothing: antialiased;\n  \
+            -moz-osx-font-smoothing: grayscale;\n\
+            }}\n",
+            family = self.family,
+            url = font_url,
+            prefix = class_prefix,
+        );
+        for glyph in &self.glyphs {
+            css.push_str(&format!(
+                "\n.{}-{}::before {{\n  content: \"\\{:x}\";\n}}\n",
+                class_prefix, glyph.name, glyph.codepoint
+            ));
+        }
+        css
+    }
+
+    fn next_free_codepoint(&mut self) -> Result<u32, Box<dyn Error>> {
+        while self.codepoints.contains(&self.next_codepoint) {
+            self.next_codepoint += 1;
+        }
+        if self.next_codepoint > PUA_END {
+            return Err("no free codepoints left in the Private Use Area".into());
+        }
+        Ok(self.next_codepoint)
+    }
+}
+
+/// Icon name -> codepoint for single-codepoint entries of a `chars` map such as `{"f101": "home"}`
+pub fn char_codepoints<'a, I>(chars: I) -> HashMap<&'a str, u32>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    let mut codepoints = HashMap::new();
+    for (chars, name) in chars {
+        if let Ok(codepoint) = u32::from_str_radix(chars, 16) {
+            let entry = codepoints.entry(name).or_insert(codepoint);
+            *entry = (*entry).min(codepoint);
+        }
+    }
+    codepoints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    /// Undo `ttf_to_woff2`: read the table directory, decompress the tables and lay them out as an sfnt.
+    /// Returns the tags in stream order along with the font.
+    fn decode_woff2(woff2: &[u8]) -> (Vec<[u8; 4]>, Vec<u8>) {
+        const KNOWN_TAGS: [&[u8; 4]; 12] =
+            [b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", b"cvt ", b"fpgm", b"glyf", b"loca"];
+        let count = u16::from_be_bytes([woff2[12], woff2[13]]) as usize;
+        let compressed_len = u32::from_be_bytes(woff2[20..24].try_into().unwrap()) as usize;
+
+        let mut at = 48;
+        let mut tables = Vec::new();
+        for _ in 0..count {
+            let flags = woff2[at];
+            at += 1;
+            let tag = if flags & 0x3F == 63 {
+                at += 4;
+                woff2[at - 4..at].try_into().unwrap()
+            } else {
+                *KNOWN_TAGS[(flags & 0x3F) as usize]
+            };
+            let mut length = 0u32;
+            loop {
+                let byte = woff2[at];
+                at += 1;
+                length = length << 7 | (byte & 0x7F) as u32;
+                if byte & 0x80 == 0 {
+                    break;
+                }
+            }
+            tables.push((tag, length as usize));
+        }
+        let mut stream = Vec::new();
+        brotli::Decompressor::new(&woff2[at..at + compressed_len], 4096).read_to_end(&mut stream).unwrap();
+        assert_eq!(stream.len(), tables.iter().map(|(_, length)| length).sum::<usize>());
+
+        let mut sorted = tables.clone();
+        sorted.sort();
+        let mut ttf = Vec::new();
+        ttf.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+        ttf.extend_from_slice(&(count as u16).to_be_bytes());
+        ttf.extend_from_slice(&[0; 6]); // ttf_parser ignores the binary search hints
+        let mut data = Vec::new();
+        let data_start = 12 + 16 * count;
+        for (tag, length) in &sorted {
+            let start: usize = tables.iter().take_while(|(other, _)| other != tag).map(|(_, length)| length).sum();
+            ttf.extend_from_slice(tag);
+            ttf.extend_from_slice(&[0; 4]);
+            ttf.extend_from_slice(&((data_start + data.len()) as u32).to_be_bytes());
+            ttf.extend_from_slice(&(*length as u32).to_be_bytes());
+            data.extend_from_slice(&stream[start..start + length]);
+            data.resize(data.len().next_multiple_of(4), 0);
+        }
+        ttf.extend_from_slice(&data);
+        (tables.into_iter().map(|(tag, _)| tag).collect(), ttf)
+    }
+
+    #[test]
+    fn test_icon_font() {
+        let json = r#"{
+            "prefix": "test",
+            "info": { "name": "Test", "total": 3 },
+            "width": 24,
+            "height": 24,
+            "icons": {
+                "ring": { "body": "<path fill-rule='evenodd' d='M2 12a10 10 0 1 0 20 0a10 10 0 1 0-20 0zm5 0a5 5 0 1 0 10 0a5 5 0 1 0-10 0z'/>" },
+                "line": { "body": "<path fill='none' stroke='currentColor' stroke-width='2' d='M4 12h16'/>" },
+                "wide": { "body": "<rect width='48' height='24'/>", "width": 48 }
+            },
+            "chars": { "f101": "ring" }
+        }"#;
+        let iconset: IconSetJson = serde_json::from_str(json).unwrap();
+        let mut font = IconFont::new("Test Icons");
+        assert_eq!(font.add_from_set(&iconset, "ring").unwrap(), 0xF101);
+        assert_eq!(font.add_from_set(&iconset, "line").unwrap(), PUA_START);
+        assert_eq!(font.add_from_set(&iconset, "wide").unwrap(), PUA_START + 1);
+        assert!(font.add_from_set(&iconset, "ring").is_err());
+
+        let ttf = font.to_ttf();
+        let face = ttf_parser::Face::parse(&ttf, 0).unwrap();
+        assert_eq!(face.number_of_glyphs(), 4);
+        assert_eq!(face.units_per_em(), 1000);
+
+        let ring = face.glyph_index(char::from_u32(0xF101).unwrap()).unwrap();
+        assert_eq!(face.glyph_name(ring), Some("ring"));
+        let bbox = face.glyph_bounding_box(ring).unwrap();
+        assert_eq!((bbox.x_min, bbox.y_min, bbox.x_max, bbox.y_max), (83, 83, 917, 917));
+
+        let wide = face.glyph_index(char::from_u32(PUA_START + 1).unwrap()).unwrap();
+        assert_eq!(face.glyph_hor_advance(wide), Some(2000));
+        let line = face.glyph_index(char::from_u32(PUA_START).unwrap()).unwrap();
+        let bbox = face.glyph_bounding_box(line).unwrap();
+        assert_eq!((bbox.y_min, bbox.y_max), (458, 542));
+
+        let woff2 = font.to_woff2();
+        assert_eq!(&woff2[..4], b"wOF2");
+        assert_eq!(woff2.len() % 4, 0);
+        assert!(woff2.len() < ttf.len());
+        let (tags, decoded) = decode_woff2(&woff2);
+        let glyf = tags.iter().position(|tag| tag == b"glyf").unwrap();
+        assert_eq!(&tags[glyf + 1], b"loca");
+        let face = ttf_parser::Face::parse(&decoded, 0).unwrap();
+        assert_eq!(face.number_of_glyphs(), 4);
+        assert_eq!(face.glyph_index(char::from_u32(0xF101).unwrap()), Some(ring));
+        assert_eq!(face.glyph_index(char::from_u32(PUA_START + 1).unwrap()), Some(wide));
+
+        let css = font.to_css("./test-icons", "icon");
+        assert!(css.contains("src: url(\"./test-icons.woff2\") format(\"woff2\")"));
+        assert!(css.contains(".icon-ring::before {\n  content: \"\\f101\";\n}"));
+    }
+}
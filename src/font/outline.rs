@@ -0,0 +1,206 @@
+use std::error::Error;
+use usvg::tiny_skia_path::{Path, PathSegment, Point, Transform};
+use usvg::FillRule;
+use crate::converters::render::IconBox;
+use crate::converters::outline::outline;
+
+/// Maximum distance, in font units, between a cubic curve and its quadratic approximation
+const CURVE_TOLERANCE: f32 = 0.5;
+
+/// Closed TrueType contour as `(x, y, on_curve)` points in font units
+pub(crate) type Contour = Vec<(i16, i16, bool)>;
+
+type FloatContour = Vec<(f32, f32, bool)>;
+
+/// Convert an icon body into glyph contours scaled so the icon height fills the em square
+pub(crate) fn icon_contours(body: &str, view_box: IconBox, units_per_em: u16) -> Result<Vec<Contour>, Box<dyn Error>> {
+    let document = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:xlink=\"http://www.w3.org/1999/xlink\" viewBox=\"{}\" width=\"{}\" height=\"{}\">{}</svg>",
+        view_box.view_box(),
+        view_box.width,
+        view_box.height,
+        body
+    );
+
+    // Fonts are y-up with the baseline at the bottom of the icon
+    let scale = units_per_em as f32 / view_box.height;
+    let to_font = Transform::from_row(scale, 0.0, 0.0, -scale, 0.0, units_per_em as f32);
+
+    let mut contours = Vec::new();
+    for path in outline(&document, to_font)?.paths {
+        if let Some(fill) = &path.fill {
+            contours.extend(oriented(path_contours(&path.data), fill.rule));
+        }
+        // Strokes have no font equivalent, so they become filled outlines
+        if let Some(stroke) = path.stroke_outline() {
+            contours.extend(oriented(path_contours(&stroke), FillRule::NonZero));
+        }
+    }
+    Ok(contours.into_iter().filter_map(round_contour).collect())
+}
+
+/// Advance width in font units for an icon box
+pub(crate) fn advance_width(view_box: IconBox, units_per_em: u16) -> u16 {
+    (view_box.width * units_per_em as f32 / view_box.height).round() as u16
+}
+
+fn path_contours(path: &Path) -> Vec<FloatContour> {
+    let mut contours = Vec::new();
+    let mut current: FloatContour = Vec::new();
+    let mut last = Point::zero();
+
+    for segment in path.segments() {
+        match segment {
+            PathSegment::MoveTo(p) => {
+                contours.push(std::mem::take(&mut current));
+                current.push((p.x, p.y, true));
+                last = p;
+            }
+            PathSegment::LineTo(p) => {
+                current.push((p.x, p.y, true));
+                last = p;
+            }
+            PathSegment::QuadTo(c, p) => {
+                current.push((c.x, c.y, false));
+                current.push((p.x, p.y, true));
+                last = p;
+            }
+            PathSegment::CubicTo(c1, c2, p) => {
+                cubic_to_quads(last, c1, c2, p, 0, &mut current);
+                last = p;
+            }
+            PathSegment::Close => {}
+        }
+    }
+    contours.push(current);
+    contours.retain(|contour| contour.len() >= 3);
+    contours
+}
+
+/// Approximate a cubic with quadratics, splitting until each piece is within tolerance
+fn cubic_to_quads(p0: Point, c1: Point, c2: Point, p3: Point, depth: u8, out: &mut FloatContour) {
+    let error = ((p3 - p0) + scaled(c1 - c2, 3.0)).length() * 3f32.sqrt() / 36.0;
+    if error <= CURVE_TOLERANCE || depth >= 8 {
+        let control = scaled(scaled(c1 + c2, 3.0) - p0 - p3, 0.25);
+        out.push((control.x, control.y, false));
+        out.push((p3.x, p3.y, true));
+        return;
+    }
+
+    let mid = |a: Point, b: Point| scaled(a + b, 0.5);
+    let (ab, bc, cd) = (mid(p0, c1), mid(c1, c2), mid(c2, p3));
+    let (abc, bcd) = (mid(ab, bc), mid(bc, cd));
+    let center = mid(abc, bcd);
+    cubic_to_quads(p0, ab, abc, center, depth + 1, out);
+    cubic_to_quads(center, bcd, cd, p3, depth + 1, out);
+}
+
+fn scaled(point: Point, factor: f32) -> Point {
+    Point::from_xy(point.x * factor, point.y * factor)
+}
+
+/// Rewind one SVG path's contours so filled regions run clockwise and holes counter-clockwise.
+/// Fonts fill with the nonzero rule across every contour of a glyph, so this keeps
+/// overlapping paths unioned and even-odd holes open.
+fn oriented(contours: Vec<FloatContour>, rule: FillRule) -> Vec<FloatContour> {
+    let clockwise: Vec<bool> = contours.iter().map(|contour| signed_area(contour) < 0.0).collect();
+    let filled = |winding: i32| match rule {
+        FillRule::NonZero => winding != 0,
+        FillRule::EvenOdd => winding % 2 != 0,
+    };
+    let direction = |i: usize| match rule {
+        FillRule::NonZero if clockwise[i] => 1,
+        FillRule::NonZero => -1,
+        FillRule::EvenOdd => 1,
+    };
+    // Winding of the largest contour, used for contours that only partly overlap others
+    let outer = (0..contours.len())
+        .max_by(|&a, &b| signed_area(&contours[a]).abs().total_cmp(&signed_area(&contours[b]).abs()))
+        .map_or(1, direction);
+
+    let mut result = Vec::new();
+    for (i, contour) in contours.iter().enumerate() {
+        let sides: Vec<(bool, bool)> = contour
+            .iter()
+            .filter(|point| point.2)
+            .map(|&(x, y, _)| {
+                let outside: i32 = contours
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, other)| j != i && contains(other, x, y))
+                    .map(|(j, _)| direction(j))
+                    .sum();
+                (filled(outside + direction(i)), filled(outside))
+            })
+            .collect();
+        let Some(&first) = sides.first() else { continue };
+
+        let fills_inside = if sides.iter().all(|&side| side == first) {
+            match first {
+                (true, false) => true,
+                (false, true) => false,
+                // Contours with fill on both sides (or neither) don't change the shape
+                _ => continue,
+            }
+        } else {
+            direction(i) == outer
+        };
+        let mut contour = contour.clone();
+        if fills_inside != clockwise[i] {
+            contour.reverse();
+        }
+        result.push(contour);
+    }
+    result
+}
+
+/// Shoelace area over the control polygon; positive is counter-clockwise in y-up space
+fn signed_area(contour: &FloatContour) -> f32 {
+    let mut area = 0.0;
+    for (i, &(x0, y0, _)) in contour.iter().enumerate() {
+        let (x1, y1, _) = contour[(i + 1) % contour.len()];
+        area += x0 * y1 - x1 * y0;
+    }
+    area / 2.0
+}
+
+/// Ray-casting point-in-polygon test against a contour's control polygon
+fn contains(contour: &FloatContour, x: f32, y: f32) -> bool {
+    let mut inside = false;
+    for (i, &(x0, y0, _)) in contour.iter().enumerate() {
+        let (x1, y1, _) = contour[(i + 1) % contour.len()];
+        if (y0 > y) != (y1 > y) && x < (x1 - x0) * (y - y0) / (y1 - y0) + x0 {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+fn round_contour(contour: FloatContour) -> Option<Contour> {
+    let mut points: Contour = Vec::with_capacity(contour.len());
+    for (x, y, on_curve) in contour {
+        let point = (x.round() as i16, y.round() as i16, on_curve);
+        match points.last() {
+            // Collapse repeated points, preferring the on-curve one
+            Some(last) if (last.0, last.1) == (point.0, point.1) => {
+                if on_curve {
+                    *points.last_mut().unwrap() = point;
+                }
+            }
+            _ => points.push(point),
+        }
+    }
+
+    // Contours close implicitly, so drop an explicit return to the start
+    if points.len() > 1 {
+        let (first, last) = (points[0], points[points.len() - 1]);
+        if (first.0, first.1) == (last.0, last.1) {
+            points.pop();
+            points[0].2 |= last.2;
+        }
+    }
+    if points.len() < 3 || !points.iter().any(|point| point.2) {
+        return None;
+    }
+    Some(points)
+}
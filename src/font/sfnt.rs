@@ -0,0 +1,524 @@
+use std::io::Write;
+use crate::font::outline::Contour;
+
+/// Glyph ready to be written into the `glyf` table
+pub(crate) struct GlyphData<'a> {
+    pub name: &'a str,
+    pub codepoint: u32,
+    pub advance: u16,
+    pub contours: &'a [Contour],
+}
+
+#[derive(Clone, Copy)]
+struct BBox {
+    x_min: i16,
+    y_min: i16,
+    x_max: i16,
+    y_max: i16,
+}
+
+impl BBox {
+    fn union(self, other: BBox) -> BBox {
+        BBox {
+            x_min: self.x_min.min(other.x_min),
+            y_min: self.y_min.min(other.y_min),
+            x_max: self.x_max.max(other.x_max),
+            y_max: self.y_max.max(other.y_max),
+        }
+    }
+}
+
+trait BigEndian {
+    fn u8(&mut self, value: u8);
+    fn u16(&mut self, value: u16);
+    fn i16(&mut self, value: i16);
+    fn u32(&mut self, value: u32);
+}
+
+impl BigEndian for Vec<u8> {
+    fn u8(&mut self, value: u8) {
+        self.push(value);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn i16(&mut self, value: i16) {
+        self.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+/// Assemble a TrueType font; glyph 0 (`.notdef`) is added in front of `glyphs`
+pub(crate) fn build_ttf(family: &str, units_per_em: u16, glyphs: &[GlyphData]) -> Vec<u8> {
+    let notdef = GlyphData { name: ".notdef", codepoint: 0, advance: units_per_em / 2, contours: &[] };
+    let all: Vec<&GlyphData> = std::iter::once(&notdef).chain(glyphs).collect();
+
+    // glyf and loca
+    let mut glyf = Vec::new();
+    let mut loca = Vec::new();
+    let mut boxes = Vec::new();
+    let (mut max_points, mut max_contours) = (0u16, 0u16);
+    for glyph in &all {
+        loca.u32(glyf.len() as u32);
+        let bbox = encode_glyph(glyph.contours, &mut glyf);
+        boxes.push(bbox);
+        max_points = max_points.max(glyph.contours.iter().map(|c| c.len() as u16).sum());
+        max_contours = max_contours.max(glyph.contours.len() as u16);
+        while glyf.len() % 4 != 0 {
+            glyf.push(0);
+        }
+    }
+    loca.u32(glyf.len() as u32);
+
+    let font_box = boxes.iter().flatten().copied().reduce(BBox::union).unwrap_or(BBox {
+        x_min: 0,
+        y_min: 0,
+        x_max: 0,
+        y_max: 0,
+    });
+    let num_glyphs = all.len() as u16;
+    let advance_max = all.iter().map(|glyph| glyph.advance).max().unwrap_or(0);
+    let min_lsb = boxes.iter().flatten().map(|b| b.x_min).min().unwrap_or(0);
+    let min_rsb = all
+        .iter()
+        .zip(&boxes)
+        .filter_map(|(glyph, bbox)| bbox.map(|b| glyph.advance as i16 - b.x_max))
+        .min()
+        .unwrap_or(0);
+    let ascender = units_per_em as i16;
+
+    let mut head = Vec::new();
+    head.u32(0x0001_0000);
+    head.u32(0x0001_0000); // fontRevision 1.0
+    head.u32(0); // checkSumAdjustment, patched once the font is assembled
+    head.u32(0x5F0F_3CF5);
+    head.u16(0b1011); // baseline at y=0, lsb at x=0, integer ppem
+    head.u16(units_per_em);
+    head.extend_from_slice(&[0; 16]); // created, modified
+    head.i16(font_box.x_min);
+    head.i16(font_box.y_min);
+    head.i16(font_box.x_max);
+    head.i16(font_box.y_max);
+    head.u16(0); // macStyle
+    head.u16(8); // lowestRecPPEM
+    head.i16(2); // fontDirectionHint
+    head.i16(1); // indexToLocFormat: 32-bit offsets
+    head.i16(0);
+
+    let mut hhea = Vec::new();
+    hhea.u32(0x0001_0000);
+    hhea.i16(ascender);
+    hhea.i16(0); // descender
+    hhea.i16(0); // lineGap
+    hhea.u16(advance_max);
+    hhea.i16(min_lsb);
+    hhea.i16(min_rsb);
+    hhea.i16(font_box.x_max);
+    hhea.i16(1); // caretSlopeRise
+    hhea.i16(0);
+    hhea.i16(0);
+    hhea.extend_from_slice(&[0; 8]);
+    hhea.i16(0); // metricDataFormat
+    hhea.u16(num_glyphs);
+
+    let mut hmtx = Vec::new();
+    for (glyph, bbox) in all.iter().zip(&boxes) {
+        hmtx.u16(glyph.advance);
+        hmtx.i16(bbox.map_or(0, |b| b.x_min));
+    }
+
+    let mut maxp = Vec::new();
+    maxp.u32(0x0001_0000);
+    maxp.u16(num_glyphs);
+    maxp.u16(max_points);
+    maxp.u16(max_contours);
+    maxp.u16(0); // maxCompositePoints
+    maxp.u16(0); // maxCompositeContours
+    maxp.u16(2); // maxZones
+    maxp.extend_from_slice(&[0; 16]);
+
+    let mapped: Vec<(u32, u16)> = {
+        let mut mapped: Vec<(u32, u16)> = all
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(gid, glyph)| (glyph.codepoint, gid as u16))
+            .collect();
+        mapped.sort();
+        mapped
+    };
+    let first_char = mapped.first().map_or(0, |m| m.0.min(0xFFFF) as u16);
+    let last_char = mapped.last().map_or(0, |m| m.0.min(0xFFFF) as u16);
+    let average = all.iter().map(|glyph| glyph.advance as u32).sum::<u32>() / all.len() as u32;
+
+    let mut os2 = Vec::new();
+    os2.u16(4);
+    os2.i16(average as i16);
+    os2.u16(400); // usWeightClass
+    os2.u16(5); // usWidthClass
+    os2.u16(0); // fsType: installable
+    for value in [650, 600, 0, 75, 650, 600, 0, 350, 50, 300] {
+        os2.i16(value); // sub/superscript sizes and offsets, strikeout size and position
+    }
+    os2.i16(0); // sFamilyClass
+    os2.extend_from_slice(&[0; 10]); // panose
+    os2.u32(0);
+    os2.u32(1 << 28); // Private Use Area
+    os2.u32(0);
+    os2.u32(0);
+    os2.extend_from_slice(b"DXIC");
+    os2.u16(0x00C0); // REGULAR | USE_TYPO_METRICS
+    os2.u16(first_char);
+    os2.u16(last_char);
+    os2.i16(ascender);
+    os2.i16(0);
+    os2.i16(0);
+    os2.u16(ascender as u16); // usWinAscent
+    os2.u16(0); // usWinDescent
+    os2.u32(1); // Latin 1
+    os2.u32(0);
+    os2.i16(0); // sxHeight
+    os2.i16(0); // sCapHeight
+    os2.u16(0); // usDefaultChar
+    os2.u16(0x20); // usBreakChar
+    os2.u16(0); // usMaxContext
+
+    let postscript_name: String = family.chars().filter(|c| c.is_ascii_alphanumeric() || *c == '-').take(63).collect();
+    let name = name_table(&[
+        (1, family),
+        (2, "Regular"),
+        (3, &format!("{}:Regular", postscript_name)),
+        (4, family),
+        (5, "Version 1.0"),
+        (6, &postscript_name),
+    ]);
+
+    let tables: Vec<([u8; 4], Vec<u8>)> = vec![
+        (*b"OS/2", os2),
+        (*b"cmap", cmap_table(&mapped)),
+        (*b"glyf", glyf),
+        (*b"head", head),
+        (*b"hhea", hhea),
+        (*b"hmtx", hmtx),
+        (*b"loca", loca),
+        (*b"maxp", maxp),
+        (*b"name", name),
+        (*b"post", post_table(&all)),
+    ];
+    assemble(tables)
+}
+
+/// Wrap TrueType tables in an sfnt directory and fill in `head.checkSumAdjustment`
+fn assemble(tables: Vec<([u8; 4], Vec<u8>)>) -> Vec<u8> {
+    let count = tables.len() as u16;
+    let entry_selector = 15 - count.leading_zeros() as u16;
+    let search_range = (1 << entry_selector) * 16;
+
+    let mut font = Vec::new();
+    font.u32(0x0001_0000);
+    font.u16(count);
+    font.u16(search_range);
+    font.u16(entry_selector);
+    font.u16(count * 16 - search_range);
+
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, data) in &tables {
+        font.extend_from_slice(tag);
+        font.u32(checksum(data));
+        font.u32(offset as u32);
+        font.u32(data.len() as u32);
+        offset += data.len().next_multiple_of(4);
+    }
+
+    let mut head_offset = 0;
+    for (tag, data) in &tables {
+        if tag == b"head" {
+            head_offset = font.len();
+        }
+        font.extend_from_slice(data);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+
+    let adjustment = 0xB1B0_AFBAu32.wrapping_sub(checksum(&font));
+    font[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+    font
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// Append a simple glyph to `glyf`, returning its bounding box (`None` for empty glyphs)
+fn encode_glyph(contours: &[Contour], glyf: &mut Vec<u8>) -> Option<BBox> {
+    let points: Vec<&(i16, i16, bool)> = contours.iter().flatten().collect();
+    let first = points.first()?;
+    let bbox = points.iter().fold(
+        BBox { x_min: first.0, y_min: first.1, x_max: first.0, y_max: first.1 },
+        |b, p| b.union(BBox { x_min: p.0, y_min: p.1, x_max: p.0, y_max: p.1 }),
+    );
+
+    glyf.i16(contours.len() as i16);
+    glyf.i16(bbox.x_min);
+    glyf.i16(bbox.y_min);
+    glyf.i16(bbox.x_max);
+    glyf.i16(bbox.y_max);
+    let mut end = 0u16;
+    for contour in contours {
+        end += contour.len() as u16;
+        glyf.u16(end - 1);
+    }
+    glyf.u16(0); // no instructions
+
+    let (mut flags, mut xs, mut ys) = (Vec::new(), Vec::new(), Vec::new());
+    let (mut last_x, mut last_y) = (0i16, 0i16);
+    for &&(x, y, on_curve) in &points {
+        let mut flag = if on_curve { 0x01 } else { 0x00 };
+        flag |= encode_delta(x.wrapping_sub(last_x), 0x02, 0x10, &mut xs);
+        flag |= encode_delta(y.wrapping_sub(last_y), 0x04, 0x20, &mut ys);
+        flags.push(flag);
+        (last_x, last_y) = (x, y);
+    }
+    glyf.extend_from_slice(&flags);
+    glyf.extend_from_slice(&xs);
+    glyf.extend_from_slice(&ys);
+    Some(bbox)
+}
+
+/// Encode one coordinate delta, returning the flag bits that describe it
+fn encode_delta(delta: i16, short: u8, same_or_positive: u8, out: &mut Vec<u8>) -> u8 {
+    if delta == 0 {
+        same_or_positive
+    } else if delta.unsigned_abs() < 256 {
+        out.u8(delta.unsigned_abs() as u8);
+        short | if delta > 0 { same_or_positive } else { 0 }
+    } else {
+        out.i16(delta);
+        0
+    }
+}
+
+/// Format 4 for the BMP, plus format 12 when codepoints go beyond it
+fn cmap_table(mapped: &[(u32, u16)]) -> Vec<u8> {
+    let runs = |limit: u32| {
+        let mut runs: Vec<(u32, u32, u16)> = Vec::new();
+        for &(codepoint, gid) in mapped.iter().filter(|m| m.0 <= limit) {
+            match runs.last_mut() {
+                Some((start, end, first)) if codepoint == *end + 1 && gid as u32 == *first as u32 + (codepoint - *start) => {
+                    *end = codepoint;
+                }
+                _ => runs.push((codepoint, codepoint, gid)),
+            }
+        }
+        runs
+    };
+
+    let mut bmp = runs(0xFFFE);
+    bmp.push((0xFFFF, 0xFFFF, 0));
+    let seg_count = bmp.len() as u16;
+    let entry_selector = 15 - seg_count.leading_zeros() as u16;
+    let search_range = 2 * (1 << entry_selector);
+    let mut format4 = Vec::new();
+    format4.u16(4);
+    format4.u16(16 + 8 * seg_count);
+    format4.u16(0);
+    format4.u16(seg_count * 2);
+    format4.u16(search_range);
+    format4.u16(entry_selector);
+    format4.u16(seg_count * 2 - search_range);
+    for &(_, end, _) in &bmp {
+        format4.u16(end as u16);
+    }
+    format4.u16(0);
+    for &(start, _, _) in &bmp {
+        format4.u16(start as u16);
+    }
+    for &(start, end, gid) in &bmp {
+        // The terminating segment maps 0xFFFF to glyph 0
+        let delta = if end == 0xFFFF { 1 } else { gid.wrapping_sub(start as u16) };
+        format4.u16(delta);
+    }
+    for _ in &bmp {
+        format4.u16(0);
+    }
+
+    let supplementary = mapped.iter().any(|m| m.0 > 0xFFFF);
+    let mut subtables = vec![format4];
+    if supplementary {
+        let groups = runs(u32::MAX);
+        let mut format12 = Vec::new();
+        format12.u16(12);
+        format12.u16(0);
+        format12.u32(16 + 12 * groups.len() as u32);
+        format12.u32(0);
+        format12.u32(groups.len() as u32);
+        for (start, end, gid) in groups {
+            format12.u32(start);
+            format12.u32(end);
+            format12.u32(gid as u32);
+        }
+        subtables.push(format12);
+    }
+
+    // (platform, encoding, subtable)
+    let mut records = vec![(0, 3, 0), (3, 1, 0)];
+    if supplementary {
+        records = vec![(0, 3, 0), (0, 4, 1), (3, 1, 0), (3, 10, 1)];
+    }
+
+    let mut cmap = Vec::new();
+    cmap.u16(0);
+    cmap.u16(records.len() as u16);
+    let mut offsets = Vec::new();
+    let mut offset = 4 + 8 * records.len() as u32;
+    for subtable in &subtables {
+        offsets.push(offset);
+        offset += subtable.len() as u32;
+    }
+    for (platform, encoding, subtable) in records {
+        cmap.u16(platform);
+        cmap.u16(encoding);
+        cmap.u32(offsets[subtable]);
+    }
+    for subtable in subtables {
+        cmap.extend_from_slice(&subtable);
+    }
+    cmap
+}
+
+fn name_table(names: &[(u16, &str)]) -> Vec<u8> {
+    let mut table = Vec::new();
+    table.u16(0);
+    table.u16(names.len() as u16);
+    table.u16(6 + 12 * names.len() as u16);
+
+    let mut strings = Vec::new();
+    for (id, text) in names {
+        let encoded: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+        table.u16(3); // Windows
+        table.u16(1); // Unicode BMP
+        table.u16(0x0409); // en-US
+        table.u16(*id);
+        table.u16(encoded.len() as u16);
+        table.u16(strings.len() as u16);
+        strings.extend_from_slice(&encoded);
+    }
+    table.extend_from_slice(&strings);
+    table
+}
+
+/// Version 2 `post` table so tools can show icon names for glyphs
+fn post_table(glyphs: &[&GlyphData]) -> Vec<u8> {
+    let mut post = Vec::new();
+    post.u32(0x0002_0000);
+    post.u32(0); // italicAngle
+    post.i16(-75); // underlinePosition
+    post.i16(50); // underlineThickness
+    post.extend_from_slice(&[0; 20]); // isFixedPitch, memory usage
+
+    post.u16(glyphs.len() as u16);
+    let mut names = Vec::new();
+    for (index, glyph) in glyphs.iter().enumerate() {
+        if index == 0 {
+            post.u16(0); // standard Macintosh `.notdef`
+            continue;
+        }
+        post.u16(258 + names.len() as u16);
+        let name: String = glyph
+            .name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '.' { c } else { '_' })
+            .take(63)
+            .collect();
+        names.push(name);
+    }
+    for name in names {
+        post.u8(name.len() as u8);
+        post.extend_from_slice(name.as_bytes());
+    }
+    post
+}
+
+/// Repackage a TrueType font as WOFF2, keeping every table untransformed
+pub(crate) fn ttf_to_woff2(ttf: &[u8]) -> Vec<u8> {
+    const KNOWN_TAGS: [&[u8; 4]; 10] =
+        [b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", b"cvt ", b"fpgm"];
+
+    let read_u16 = |at: usize| u16::from_be_bytes([ttf[at], ttf[at + 1]]);
+    let read_u32 = |at: usize| u32::from_be_bytes([ttf[at], ttf[at + 1], ttf[at + 2], ttf[at + 3]]);
+    let count = read_u16(4) as usize;
+    let tag_at = |index: usize| &ttf[12 + 16 * index..16 + 16 * index];
+
+    // Decoders rebuild loca from the glyf table, so loca has to follow glyf directly
+    // instead of sitting in tag order behind head and hmtx
+    let mut order: Vec<usize> = (0..count).filter(|&index| tag_at(index) != b"loca").collect();
+    if let Some(loca) = (0..count).find(|&index| tag_at(index) == b"loca") {
+        let glyf = order.iter().position(|&index| tag_at(index) == b"glyf").map_or(order.len(), |at| at + 1);
+        order.insert(glyf, loca);
+    }
+
+    let mut directory = Vec::new();
+    let mut stream = Vec::new();
+    for i in order {
+        let record = 12 + 16 * i;
+        let tag: [u8; 4] = ttf[record..record + 4].try_into().unwrap();
+        let offset = read_u32(record + 8) as usize;
+        let length = read_u32(record + 12) as usize;
+
+        // Transform version 3 is the null transform for glyf/loca; 0 is null for everything else
+        let flags = match &tag {
+            b"glyf" => 10 | 0xC0,
+            b"loca" => 11 | 0xC0,
+            _ => KNOWN_TAGS.iter().position(|known| **known == tag).map_or(63, |index| index as u8),
+        };
+        directory.u8(flags);
+        if flags & 0x3F == 63 {
+            directory.extend_from_slice(&tag);
+        }
+        write_base128(&mut directory, length as u32);
+        stream.extend_from_slice(&ttf[offset..offset + length]);
+    }
+
+    let mut compressed = Vec::new();
+    {
+        let mut writer = brotli::CompressorWriter::new(&mut compressed, 4096, 11, 22);
+        writer.write_all(&stream).expect("writing to memory cannot fail");
+    }
+
+    let data_len = (48 + directory.len() + compressed.len()).next_multiple_of(4);
+    let mut woff2 = Vec::with_capacity(data_len);
+    woff2.extend_from_slice(b"wOF2");
+    woff2.u32(0x0001_0000);
+    woff2.u32(data_len as u32);
+    woff2.u16(count as u16);
+    woff2.u16(0);
+    woff2.u32(ttf.len() as u32); // totalSfntSize
+    woff2.u32(compressed.len() as u32);
+    woff2.u16(1); // majorVersion
+    woff2.u16(0);
+    woff2.extend_from_slice(&[0; 20]); // no metadata or private data
+    woff2.extend_from_slice(&directory);
+    woff2.extend_from_slice(&compressed);
+    woff2.resize(data_len, 0);
+    woff2
+}
+
+/// WOFF2 `UIntBase128`: big-endian groups of 7 bits, high bit set on all but the last byte
+fn write_base128(out: &mut Vec<u8>, mut value: u32) {
+    let mut bytes = vec![(value & 0x7F) as u8];
+    value >>= 7;
+    while value > 0 {
+        bytes.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.reverse();
+    out.extend_from_slice(&bytes);
+}
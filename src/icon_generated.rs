@@ -19,6 +19,95 @@ pub mod dx_icon {
   extern crate flatbuffers;
   use self::flatbuffers::{EndianScalar, Follow};
 
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_SEARCH_FIELD: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_SEARCH_FIELD: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_SEARCH_FIELD: [SearchField; 3] = [
+  SearchField::Name,
+  SearchField::Alias,
+  SearchField::Category,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct SearchField(pub u8);
+#[allow(non_upper_case_globals)]
+impl SearchField {
+  pub const Name: Self = Self(0);
+  pub const Alias: Self = Self(1);
+  pub const Category: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Name,
+    Self::Alias,
+    Self::Category,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Name => Some("Name"),
+      Self::Alias => Some("Alias"),
+      Self::Category => Some("Category"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for SearchField {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for SearchField {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for SearchField {
+    type Output = SearchField;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for SearchField {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for SearchField {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for SearchField {}
 pub enum LicenseOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -288,6 +377,8 @@ impl<'a> IconInfo<'a> {
   pub const VT_HEIGHT: flatbuffers::VOffsetT = 14;
   pub const VT_CATEGORY: flatbuffers::VOffsetT = 16;
   pub const VT_PALETTE: flatbuffers::VOffsetT = 18;
+  pub const VT_SAMPLES: flatbuffers::VOffsetT = 20;
+  pub const VT_TAGS: flatbuffers::VOffsetT = 22;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -299,6 +390,8 @@ impl<'a> IconInfo<'a> {
     args: &'args IconInfoArgs<'args>
   ) -> flatbuffers::WIPOffset<IconInfo<'bldr>> {
     let mut builder = IconInfoBuilder::new(_fbb);
+    if let Some(x) = args.tags { builder.add_tags(x); }
+    if let Some(x) = args.samples { builder.add_samples(x); }
     if let Some(x) = args.category { builder.add_category(x); }
     builder.add_height(args.height);
     if let Some(x) = args.license { builder.add_license(x); }
@@ -367,6 +460,20 @@ impl<'a> IconInfo<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<bool>(IconInfo::VT_PALETTE, Some(false)).unwrap()}
   }
+  #[inline]
+  pub fn samples(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(IconInfo::VT_SAMPLES, None)}
+  }
+  #[inline]
+  pub fn tags(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(IconInfo::VT_TAGS, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for IconInfo<'_> {
@@ -384,6 +491,8 @@ impl flatbuffers::Verifiable for IconInfo<'_> {
      .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("category", Self::VT_CATEGORY, false)?
      .visit_field::<bool>("palette", Self::VT_PALETTE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("samples", Self::VT_SAMPLES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("tags", Self::VT_TAGS, false)?
      .finish();
     Ok(())
   }
@@ -397,6 +506,8 @@ pub struct IconInfoArgs<'a> {
     pub height: u32,
     pub category: Option<flatbuffers::WIPOffset<&'a str>>,
     pub palette: bool,
+    pub samples: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub tags: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
 }
 impl<'a> Default for IconInfoArgs<'a> {
   #[inline]
@@ -410,6 +521,8 @@ impl<'a> Default for IconInfoArgs<'a> {
       height: 16,
       category: None,
       palette: false,
+      samples: None,
+      tags: None,
     }
   }
 }
@@ -452,6 +565,14 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IconInfoBuilder<'a, 'b, A> {
     self.fbb_.push_slot::<bool>(IconInfo::VT_PALETTE, palette, false);
   }
   #[inline]
+  pub fn add_samples(&mut self, samples: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IconInfo::VT_SAMPLES, samples);
+  }
+  #[inline]
+  pub fn add_tags(&mut self, tags: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IconInfo::VT_TAGS, tags);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IconInfoBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     IconInfoBuilder {
@@ -477,6 +598,8 @@ impl core::fmt::Debug for IconInfo<'_> {
       ds.field("height", &self.height());
       ds.field("category", &self.category());
       ds.field("palette", &self.palette());
+      ds.field("samples", &self.samples());
+      ds.field("tags", &self.tags());
       ds.finish()
   }
 }
@@ -500,6 +623,12 @@ impl<'a> Icon<'a> {
   pub const VT_BODY: flatbuffers::VOffsetT = 6;
   pub const VT_WIDTH: flatbuffers::VOffsetT = 8;
   pub const VT_HEIGHT: flatbuffers::VOffsetT = 10;
+  pub const VT_LEFT: flatbuffers::VOffsetT = 12;
+  pub const VT_TOP: flatbuffers::VOffsetT = 14;
+  pub const VT_ROTATE: flatbuffers::VOffsetT = 16;
+  pub const VT_H_FLIP: flatbuffers::VOffsetT = 18;
+  pub const VT_V_FLIP: flatbuffers::VOffsetT = 20;
+  pub const VT_HIDDEN: flatbuffers::VOffsetT = 22;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -511,10 +640,16 @@ impl<'a> Icon<'a> {
     args: &'args IconArgs<'args>
   ) -> flatbuffers::WIPOffset<Icon<'bldr>> {
     let mut builder = IconBuilder::new(_fbb);
-    builder.add_height(args.height);
-    builder.add_width(args.width);
+    if let Some(x) = args.top { builder.add_top(x); }
+    if let Some(x) = args.left { builder.add_left(x); }
+    if let Some(x) = args.height { builder.add_height(x); }
+    if let Some(x) = args.width { builder.add_width(x); }
     if let Some(x) = args.body { builder.add_body(x); }
     if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_hidden(args.hidden);
+    builder.add_v_flip(args.v_flip);
+    builder.add_h_flip(args.h_flip);
+    builder.add_rotate(args.rotate);
     builder.finish()
   }
 
@@ -544,18 +679,60 @@ impl<'a> Icon<'a> {
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Icon::VT_BODY, None)}
   }
   #[inline]
-  pub fn width(&self) -> u32 {
+  pub fn width(&self) -> Option<f32> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(Icon::VT_WIDTH, Some(0)).unwrap()}
+    unsafe { self._tab.get::<f32>(Icon::VT_WIDTH, None)}
   }
   #[inline]
-  pub fn height(&self) -> u32 {
+  pub fn height(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Icon::VT_HEIGHT, None)}
+  }
+  #[inline]
+  pub fn left(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Icon::VT_LEFT, None)}
+  }
+  #[inline]
+  pub fn top(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Icon::VT_TOP, None)}
+  }
+  #[inline]
+  pub fn rotate(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(Icon::VT_ROTATE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn h_flip(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Icon::VT_H_FLIP, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn v_flip(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Icon::VT_V_FLIP, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn hidden(&self) -> bool {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(Icon::VT_HEIGHT, Some(0)).unwrap()}
+    unsafe { self._tab.get::<bool>(Icon::VT_HIDDEN, Some(false)).unwrap()}
   }
 }
 
@@ -568,8 +745,14 @@ impl flatbuffers::Verifiable for Icon<'_> {
     v.visit_table(pos)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, true)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("body", Self::VT_BODY, false)?
-     .visit_field::<u32>("width", Self::VT_WIDTH, false)?
-     .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+     .visit_field::<f32>("width", Self::VT_WIDTH, false)?
+     .visit_field::<f32>("height", Self::VT_HEIGHT, false)?
+     .visit_field::<f32>("left", Self::VT_LEFT, false)?
+     .visit_field::<f32>("top", Self::VT_TOP, false)?
+     .visit_field::<u8>("rotate", Self::VT_ROTATE, false)?
+     .visit_field::<bool>("h_flip", Self::VT_H_FLIP, false)?
+     .visit_field::<bool>("v_flip", Self::VT_V_FLIP, false)?
+     .visit_field::<bool>("hidden", Self::VT_HIDDEN, false)?
      .finish();
     Ok(())
   }
@@ -577,8 +760,14 @@ impl flatbuffers::Verifiable for Icon<'_> {
 pub struct IconArgs<'a> {
     pub id: Option<flatbuffers::WIPOffset<&'a str>>,
     pub body: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub width: u32,
-    pub height: u32,
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub left: Option<f32>,
+    pub top: Option<f32>,
+    pub rotate: u8,
+    pub h_flip: bool,
+    pub v_flip: bool,
+    pub hidden: bool,
 }
 impl<'a> Default for IconArgs<'a> {
   #[inline]
@@ -586,8 +775,14 @@ impl<'a> Default for IconArgs<'a> {
     IconArgs {
       id: None, // required field
       body: None,
-      width: 0,
-      height: 0,
+      width: None,
+      height: None,
+      left: None,
+      top: None,
+      rotate: 0,
+      h_flip: false,
+      v_flip: false,
+      hidden: false,
     }
   }
 }
@@ -606,12 +801,36 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IconBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Icon::VT_BODY, body);
   }
   #[inline]
-  pub fn add_width(&mut self, width: u32) {
-    self.fbb_.push_slot::<u32>(Icon::VT_WIDTH, width, 0);
+  pub fn add_width(&mut self, width: f32) {
+    self.fbb_.push_slot_always::<f32>(Icon::VT_WIDTH, width);
   }
   #[inline]
-  pub fn add_height(&mut self, height: u32) {
-    self.fbb_.push_slot::<u32>(Icon::VT_HEIGHT, height, 0);
+  pub fn add_height(&mut self, height: f32) {
+    self.fbb_.push_slot_always::<f32>(Icon::VT_HEIGHT, height);
+  }
+  #[inline]
+  pub fn add_left(&mut self, left: f32) {
+    self.fbb_.push_slot_always::<f32>(Icon::VT_LEFT, left);
+  }
+  #[inline]
+  pub fn add_top(&mut self, top: f32) {
+    self.fbb_.push_slot_always::<f32>(Icon::VT_TOP, top);
+  }
+  #[inline]
+  pub fn add_rotate(&mut self, rotate: u8) {
+    self.fbb_.push_slot::<u8>(Icon::VT_ROTATE, rotate, 0);
+  }
+  #[inline]
+  pub fn add_h_flip(&mut self, h_flip: bool) {
+    self.fbb_.push_slot::<bool>(Icon::VT_H_FLIP, h_flip, false);
+  }
+  #[inline]
+  pub fn add_v_flip(&mut self, v_flip: bool) {
+    self.fbb_.push_slot::<bool>(Icon::VT_V_FLIP, v_flip, false);
+  }
+  #[inline]
+  pub fn add_hidden(&mut self, hidden: bool) {
+    self.fbb_.push_slot::<bool>(Icon::VT_HIDDEN, hidden, false);
   }
   #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IconBuilder<'a, 'b, A> {
@@ -636,427 +855,1939 @@ impl core::fmt::Debug for Icon<'_> {
       ds.field("body", &self.body());
       ds.field("width", &self.width());
       ds.field("height", &self.height());
+      ds.field("left", &self.left());
+      ds.field("top", &self.top());
+      ds.field("rotate", &self.rotate());
+      ds.field("h_flip", &self.h_flip());
+      ds.field("v_flip", &self.v_flip());
+      ds.field("hidden", &self.hidden());
       ds.finish()
   }
 }
-pub enum IconSetOffset {}
+pub enum AliasOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct IconSet<'a> {
+pub struct Alias<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for IconSet<'a> {
-  type Inner = IconSet<'a>;
+impl<'a> flatbuffers::Follow<'a> for Alias<'a> {
+  type Inner = Alias<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> IconSet<'a> {
-  pub const VT_PREFIX: flatbuffers::VOffsetT = 4;
-  pub const VT_INFO: flatbuffers::VOffsetT = 6;
-  pub const VT_ICONS: flatbuffers::VOffsetT = 8;
+impl<'a> Alias<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_PARENT: flatbuffers::VOffsetT = 6;
+  pub const VT_ROTATE: flatbuffers::VOffsetT = 8;
+  pub const VT_H_FLIP: flatbuffers::VOffsetT = 10;
+  pub const VT_V_FLIP: flatbuffers::VOffsetT = 12;
+  pub const VT_WIDTH: flatbuffers::VOffsetT = 14;
+  pub const VT_HEIGHT: flatbuffers::VOffsetT = 16;
+  pub const VT_LEFT: flatbuffers::VOffsetT = 18;
+  pub const VT_TOP: flatbuffers::VOffsetT = 20;
+  pub const VT_HIDDEN: flatbuffers::VOffsetT = 22;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    IconSet { _tab: table }
+    Alias { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args IconSetArgs<'args>
-  ) -> flatbuffers::WIPOffset<IconSet<'bldr>> {
-    let mut builder = IconSetBuilder::new(_fbb);
-    if let Some(x) = args.icons { builder.add_icons(x); }
-    if let Some(x) = args.info { builder.add_info(x); }
-    if let Some(x) = args.prefix { builder.add_prefix(x); }
+    args: &'args AliasArgs<'args>
+  ) -> flatbuffers::WIPOffset<Alias<'bldr>> {
+    let mut builder = AliasBuilder::new(_fbb);
+    if let Some(x) = args.top { builder.add_top(x); }
+    if let Some(x) = args.left { builder.add_left(x); }
+    if let Some(x) = args.height { builder.add_height(x); }
+    if let Some(x) = args.width { builder.add_width(x); }
+    if let Some(x) = args.parent { builder.add_parent(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
+    if let Some(x) = args.hidden { builder.add_hidden(x); }
+    builder.add_v_flip(args.v_flip);
+    builder.add_h_flip(args.h_flip);
+    builder.add_rotate(args.rotate);
     builder.finish()
   }
 
 
   #[inline]
-  pub fn prefix(&self) -> Option<&'a str> {
+  pub fn id(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(IconSet::VT_PREFIX, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Alias::VT_ID, None).unwrap()}
   }
   #[inline]
-  pub fn info(&self) -> Option<IconInfo<'a>> {
+  pub fn key_compare_less_than(&self, o: &Alias) -> bool {
+    self.id() < o.id()
+  }
+
+  #[inline]
+  pub fn key_compare_with_value(&self, val: & str) -> ::core::cmp::Ordering {
+    let key = self.id();
+    key.cmp(val)
+  }
+  #[inline]
+  pub fn parent(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<IconInfo>>(IconSet::VT_INFO, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Alias::VT_PARENT, None)}
   }
   #[inline]
-  pub fn icons(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Icon<'a>>>> {
+  pub fn rotate(&self) -> u8 {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Icon>>>>(IconSet::VT_ICONS, None)}
+    unsafe { self._tab.get::<u8>(Alias::VT_ROTATE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn h_flip(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Alias::VT_H_FLIP, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn v_flip(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Alias::VT_V_FLIP, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn width(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Alias::VT_WIDTH, None)}
+  }
+  #[inline]
+  pub fn height(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Alias::VT_HEIGHT, None)}
+  }
+  #[inline]
+  pub fn left(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Alias::VT_LEFT, None)}
+  }
+  #[inline]
+  pub fn top(&self) -> Option<f32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(Alias::VT_TOP, None)}
+  }
+  #[inline]
+  pub fn hidden(&self) -> Option<bool> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Alias::VT_HIDDEN, None)}
   }
 }
 
-impl flatbuffers::Verifiable for IconSet<'_> {
+impl flatbuffers::Verifiable for Alias<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("prefix", Self::VT_PREFIX, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<IconInfo>>("info", Self::VT_INFO, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Icon>>>>("icons", Self::VT_ICONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("parent", Self::VT_PARENT, false)?
+     .visit_field::<u8>("rotate", Self::VT_ROTATE, false)?
+     .visit_field::<bool>("h_flip", Self::VT_H_FLIP, false)?
+     .visit_field::<bool>("v_flip", Self::VT_V_FLIP, false)?
+     .visit_field::<f32>("width", Self::VT_WIDTH, false)?
+     .visit_field::<f32>("height", Self::VT_HEIGHT, false)?
+     .visit_field::<f32>("left", Self::VT_LEFT, false)?
+     .visit_field::<f32>("top", Self::VT_TOP, false)?
+     .visit_field::<bool>("hidden", Self::VT_HIDDEN, false)?
      .finish();
     Ok(())
   }
 }
-pub struct IconSetArgs<'a> {
-    pub prefix: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub info: Option<flatbuffers::WIPOffset<IconInfo<'a>>>,
-    pub icons: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Icon<'a>>>>>,
-}
-impl<'a> Default for IconSetArgs<'a> {
+pub struct AliasArgs<'a> {
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub parent: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub rotate: u8,
+    pub h_flip: bool,
+    pub v_flip: bool,
+    pub width: Option<f32>,
+    pub height: Option<f32>,
+    pub left: Option<f32>,
+    pub top: Option<f32>,
+    pub hidden: Option<bool>,
+}
+impl<'a> Default for AliasArgs<'a> {
   #[inline]
   fn default() -> Self {
-    IconSetArgs {
-      prefix: None,
-      info: None,
-      icons: None,
+    AliasArgs {
+      id: None, // required field
+      parent: None,
+      rotate: 0,
+      h_flip: false,
+      v_flip: false,
+      width: None,
+      height: None,
+      left: None,
+      top: None,
+      hidden: None,
     }
   }
 }
 
-pub struct IconSetBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct AliasBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IconSetBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AliasBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_prefix(&mut self, prefix: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IconSet::VT_PREFIX, prefix);
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Alias::VT_ID, id);
   }
   #[inline]
-  pub fn add_info(&mut self, info: flatbuffers::WIPOffset<IconInfo<'b >>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<IconInfo>>(IconSet::VT_INFO, info);
+  pub fn add_parent(&mut self, parent: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Alias::VT_PARENT, parent);
   }
   #[inline]
-  pub fn add_icons(&mut self, icons: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Icon<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IconSet::VT_ICONS, icons);
+  pub fn add_rotate(&mut self, rotate: u8) {
+    self.fbb_.push_slot::<u8>(Alias::VT_ROTATE, rotate, 0);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IconSetBuilder<'a, 'b, A> {
+  pub fn add_h_flip(&mut self, h_flip: bool) {
+    self.fbb_.push_slot::<bool>(Alias::VT_H_FLIP, h_flip, false);
+  }
+  #[inline]
+  pub fn add_v_flip(&mut self, v_flip: bool) {
+    self.fbb_.push_slot::<bool>(Alias::VT_V_FLIP, v_flip, false);
+  }
+  #[inline]
+  pub fn add_width(&mut self, width: f32) {
+    self.fbb_.push_slot_always::<f32>(Alias::VT_WIDTH, width);
+  }
+  #[inline]
+  pub fn add_height(&mut self, height: f32) {
+    self.fbb_.push_slot_always::<f32>(Alias::VT_HEIGHT, height);
+  }
+  #[inline]
+  pub fn add_left(&mut self, left: f32) {
+    self.fbb_.push_slot_always::<f32>(Alias::VT_LEFT, left);
+  }
+  #[inline]
+  pub fn add_top(&mut self, top: f32) {
+    self.fbb_.push_slot_always::<f32>(Alias::VT_TOP, top);
+  }
+  #[inline]
+  pub fn add_hidden(&mut self, hidden: bool) {
+    self.fbb_.push_slot_always::<bool>(Alias::VT_HIDDEN, hidden);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AliasBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    IconSetBuilder {
+    AliasBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<IconSet<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<Alias<'a>> {
     let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Alias::VT_ID,"id");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for IconSet<'_> {
+impl core::fmt::Debug for Alias<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("IconSet");
-      ds.field("prefix", &self.prefix());
-      ds.field("info", &self.info());
-      ds.field("icons", &self.icons());
+    let mut ds = f.debug_struct("Alias");
+      ds.field("id", &self.id());
+      ds.field("parent", &self.parent());
+      ds.field("rotate", &self.rotate());
+      ds.field("h_flip", &self.h_flip());
+      ds.field("v_flip", &self.v_flip());
+      ds.field("width", &self.width());
+      ds.field("height", &self.height());
+      ds.field("left", &self.left());
+      ds.field("top", &self.top());
+      ds.field("hidden", &self.hidden());
       ds.finish()
   }
 }
-pub enum SvglIconOffset {}
+pub enum CategoryOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SvglIcon<'a> {
+pub struct Category<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SvglIcon<'a> {
-  type Inner = SvglIcon<'a>;
+impl<'a> flatbuffers::Follow<'a> for Category<'a> {
+  type Inner = Category<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> SvglIcon<'a> {
-  pub const VT_ID: flatbuffers::VOffsetT = 4;
-  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
-  pub const VT_SVG_CONTENT: flatbuffers::VOffsetT = 8;
-  pub const VT_VIEWBOX: flatbuffers::VOffsetT = 10;
-  pub const VT_WIDTH: flatbuffers::VOffsetT = 12;
-  pub const VT_HEIGHT: flatbuffers::VOffsetT = 14;
+impl<'a> Category<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_ICONS: flatbuffers::VOffsetT = 6;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    SvglIcon { _tab: table }
+    Category { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SvglIconArgs<'args>
-  ) -> flatbuffers::WIPOffset<SvglIcon<'bldr>> {
-    let mut builder = SvglIconBuilder::new(_fbb);
-    builder.add_height(args.height);
-    builder.add_width(args.width);
-    if let Some(x) = args.viewbox { builder.add_viewbox(x); }
-    if let Some(x) = args.svg_content { builder.add_svg_content(x); }
-    if let Some(x) = args.filename { builder.add_filename(x); }
-    if let Some(x) = args.id { builder.add_id(x); }
+    args: &'args CategoryArgs<'args>
+  ) -> flatbuffers::WIPOffset<Category<'bldr>> {
+    let mut builder = CategoryBuilder::new(_fbb);
+    if let Some(x) = args.icons { builder.add_icons(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn id(&self) -> &'a str {
+  pub fn name(&self) -> &'a str {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglIcon::VT_ID, None).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Category::VT_NAME, None).unwrap()}
   }
   #[inline]
-  pub fn key_compare_less_than(&self, o: &SvglIcon) -> bool {
-    self.id() < o.id()
+  pub fn key_compare_less_than(&self, o: &Category) -> bool {
+    self.name() < o.name()
   }
 
   #[inline]
   pub fn key_compare_with_value(&self, val: & str) -> ::core::cmp::Ordering {
-    let key = self.id();
+    let key = self.name();
     key.cmp(val)
   }
   #[inline]
-  pub fn filename(&self) -> Option<&'a str> {
-    // Safety:
-    // Created from valid Table for this object
-    // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglIcon::VT_FILENAME, None)}
-  }
-  #[inline]
-  pub fn svg_content(&self) -> Option<&'a str> {
+  pub fn icons(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglIcon::VT_SVG_CONTENT, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(Category::VT_ICONS, None)}
   }
+}
+
+impl flatbuffers::Verifiable for Category<'_> {
   #[inline]
-  pub fn viewbox(&self) -> Option<&'a str> {
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("icons", Self::VT_ICONS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CategoryArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub icons: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+}
+impl<'a> Default for CategoryArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CategoryArgs {
+      name: None, // required field
+      icons: None,
+    }
+  }
+}
+
+pub struct CategoryBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CategoryBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Category::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_icons(&mut self, icons: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Category::VT_ICONS, icons);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CategoryBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    CategoryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Category<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Category::VT_NAME,"name");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Category<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Category");
+      ds.field("name", &self.name());
+      ds.field("icons", &self.icons());
+      ds.finish()
+  }
+}
+pub enum CharMappingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CharMapping<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CharMapping<'a> {
+  type Inner = CharMapping<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CharMapping<'a> {
+  pub const VT_CHARS: flatbuffers::VOffsetT = 4;
+  pub const VT_ICON: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CharMapping { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args CharMappingArgs<'args>
+  ) -> flatbuffers::WIPOffset<CharMapping<'bldr>> {
+    let mut builder = CharMappingBuilder::new(_fbb);
+    if let Some(x) = args.icon { builder.add_icon(x); }
+    if let Some(x) = args.chars { builder.add_chars(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn chars(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CharMapping::VT_CHARS, None).unwrap()}
+  }
+  #[inline]
+  pub fn key_compare_less_than(&self, o: &CharMapping) -> bool {
+    self.chars() < o.chars()
+  }
+
+  #[inline]
+  pub fn key_compare_with_value(&self, val: & str) -> ::core::cmp::Ordering {
+    let key = self.chars();
+    key.cmp(val)
+  }
+  #[inline]
+  pub fn icon(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(CharMapping::VT_ICON, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CharMapping<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("chars", Self::VT_CHARS, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("icon", Self::VT_ICON, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CharMappingArgs<'a> {
+    pub chars: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub icon: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for CharMappingArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CharMappingArgs {
+      chars: None, // required field
+      icon: None,
+    }
+  }
+}
+
+pub struct CharMappingBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CharMappingBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_chars(&mut self, chars: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CharMapping::VT_CHARS, chars);
+  }
+  #[inline]
+  pub fn add_icon(&mut self, icon: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CharMapping::VT_ICON, icon);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CharMappingBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    CharMappingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CharMapping<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, CharMapping::VT_CHARS,"chars");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CharMapping<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CharMapping");
+      ds.field("chars", &self.chars());
+      ds.field("icon", &self.icon());
+      ds.finish()
+  }
+}
+pub enum IconSetOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct IconSet<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for IconSet<'a> {
+  type Inner = IconSet<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> IconSet<'a> {
+  pub const VT_PREFIX: flatbuffers::VOffsetT = 4;
+  pub const VT_INFO: flatbuffers::VOffsetT = 6;
+  pub const VT_ICONS: flatbuffers::VOffsetT = 8;
+  pub const VT_ALIASES: flatbuffers::VOffsetT = 10;
+  pub const VT_WIDTH: flatbuffers::VOffsetT = 12;
+  pub const VT_HEIGHT: flatbuffers::VOffsetT = 14;
+  pub const VT_LEFT: flatbuffers::VOffsetT = 16;
+  pub const VT_TOP: flatbuffers::VOffsetT = 18;
+  pub const VT_CATEGORIES: flatbuffers::VOffsetT = 20;
+  pub const VT_CHARS: flatbuffers::VOffsetT = 22;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    IconSet { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args IconSetArgs<'args>
+  ) -> flatbuffers::WIPOffset<IconSet<'bldr>> {
+    let mut builder = IconSetBuilder::new(_fbb);
+    if let Some(x) = args.chars { builder.add_chars(x); }
+    if let Some(x) = args.categories { builder.add_categories(x); }
+    builder.add_top(args.top);
+    builder.add_left(args.left);
+    builder.add_height(args.height);
+    builder.add_width(args.width);
+    if let Some(x) = args.aliases { builder.add_aliases(x); }
+    if let Some(x) = args.icons { builder.add_icons(x); }
+    if let Some(x) = args.info { builder.add_info(x); }
+    if let Some(x) = args.prefix { builder.add_prefix(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn prefix(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(IconSet::VT_PREFIX, None)}
+  }
+  #[inline]
+  pub fn info(&self) -> Option<IconInfo<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<IconInfo>>(IconSet::VT_INFO, None)}
+  }
+  #[inline]
+  pub fn icons(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Icon<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Icon>>>>(IconSet::VT_ICONS, None)}
+  }
+  #[inline]
+  pub fn aliases(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Alias<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Alias>>>>(IconSet::VT_ALIASES, None)}
+  }
+  #[inline]
+  pub fn width(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(IconSet::VT_WIDTH, Some(16.0)).unwrap()}
+  }
+  #[inline]
+  pub fn height(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(IconSet::VT_HEIGHT, Some(16.0)).unwrap()}
+  }
+  #[inline]
+  pub fn left(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(IconSet::VT_LEFT, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn top(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(IconSet::VT_TOP, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn categories(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Category<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Category>>>>(IconSet::VT_CATEGORIES, None)}
+  }
+  #[inline]
+  pub fn chars(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CharMapping<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CharMapping>>>>(IconSet::VT_CHARS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for IconSet<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("prefix", Self::VT_PREFIX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<IconInfo>>("info", Self::VT_INFO, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Icon>>>>("icons", Self::VT_ICONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Alias>>>>("aliases", Self::VT_ALIASES, false)?
+     .visit_field::<f32>("width", Self::VT_WIDTH, false)?
+     .visit_field::<f32>("height", Self::VT_HEIGHT, false)?
+     .visit_field::<f32>("left", Self::VT_LEFT, false)?
+     .visit_field::<f32>("top", Self::VT_TOP, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Category>>>>("categories", Self::VT_CATEGORIES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<CharMapping>>>>("chars", Self::VT_CHARS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct IconSetArgs<'a> {
+    pub prefix: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub info: Option<flatbuffers::WIPOffset<IconInfo<'a>>>,
+    pub icons: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Icon<'a>>>>>,
+    pub aliases: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Alias<'a>>>>>,
+    pub width: f32,
+    pub height: f32,
+    pub left: f32,
+    pub top: f32,
+    pub categories: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Category<'a>>>>>,
+    pub chars: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CharMapping<'a>>>>>,
+}
+impl<'a> Default for IconSetArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    IconSetArgs {
+      prefix: None,
+      info: None,
+      icons: None,
+      aliases: None,
+      width: 16.0,
+      height: 16.0,
+      left: 0.0,
+      top: 0.0,
+      categories: None,
+      chars: None,
+    }
+  }
+}
+
+pub struct IconSetBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IconSetBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_prefix(&mut self, prefix: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IconSet::VT_PREFIX, prefix);
+  }
+  #[inline]
+  pub fn add_info(&mut self, info: flatbuffers::WIPOffset<IconInfo<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<IconInfo>>(IconSet::VT_INFO, info);
+  }
+  #[inline]
+  pub fn add_icons(&mut self, icons: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Icon<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IconSet::VT_ICONS, icons);
+  }
+  #[inline]
+  pub fn add_aliases(&mut self, aliases: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Alias<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IconSet::VT_ALIASES, aliases);
+  }
+  #[inline]
+  pub fn add_width(&mut self, width: f32) {
+    self.fbb_.push_slot::<f32>(IconSet::VT_WIDTH, width, 16.0);
+  }
+  #[inline]
+  pub fn add_height(&mut self, height: f32) {
+    self.fbb_.push_slot::<f32>(IconSet::VT_HEIGHT, height, 16.0);
+  }
+  #[inline]
+  pub fn add_left(&mut self, left: f32) {
+    self.fbb_.push_slot::<f32>(IconSet::VT_LEFT, left, 0.0);
+  }
+  #[inline]
+  pub fn add_top(&mut self, top: f32) {
+    self.fbb_.push_slot::<f32>(IconSet::VT_TOP, top, 0.0);
+  }
+  #[inline]
+  pub fn add_categories(&mut self, categories: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Category<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IconSet::VT_CATEGORIES, categories);
+  }
+  #[inline]
+  pub fn add_chars(&mut self, chars: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<CharMapping<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IconSet::VT_CHARS, chars);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IconSetBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    IconSetBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<IconSet<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for IconSet<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("IconSet");
+      ds.field("prefix", &self.prefix());
+      ds.field("info", &self.info());
+      ds.field("icons", &self.icons());
+      ds.field("aliases", &self.aliases());
+      ds.field("width", &self.width());
+      ds.field("height", &self.height());
+      ds.field("left", &self.left());
+      ds.field("top", &self.top());
+      ds.field("categories", &self.categories());
+      ds.field("chars", &self.chars());
+      ds.finish()
+  }
+}
+pub enum SvglIconOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SvglIcon<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SvglIcon<'a> {
+  type Inner = SvglIcon<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SvglIcon<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_FILENAME: flatbuffers::VOffsetT = 6;
+  pub const VT_SVG_CONTENT: flatbuffers::VOffsetT = 8;
+  pub const VT_VIEWBOX: flatbuffers::VOffsetT = 10;
+  pub const VT_WIDTH: flatbuffers::VOffsetT = 12;
+  pub const VT_HEIGHT: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SvglIcon { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SvglIconArgs<'args>
+  ) -> flatbuffers::WIPOffset<SvglIcon<'bldr>> {
+    let mut builder = SvglIconBuilder::new(_fbb);
+    builder.add_height(args.height);
+    builder.add_width(args.width);
+    if let Some(x) = args.viewbox { builder.add_viewbox(x); }
+    if let Some(x) = args.svg_content { builder.add_svg_content(x); }
+    if let Some(x) = args.filename { builder.add_filename(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglIcon::VT_ID, None).unwrap()}
+  }
+  #[inline]
+  pub fn key_compare_less_than(&self, o: &SvglIcon) -> bool {
+    self.id() < o.id()
+  }
+
+  #[inline]
+  pub fn key_compare_with_value(&self, val: & str) -> ::core::cmp::Ordering {
+    let key = self.id();
+    key.cmp(val)
+  }
+  #[inline]
+  pub fn filename(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglIcon::VT_FILENAME, None)}
+  }
+  #[inline]
+  pub fn svg_content(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglIcon::VT_SVG_CONTENT, None)}
+  }
+  #[inline]
+  pub fn viewbox(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglIcon::VT_VIEWBOX, None)}
+  }
+  #[inline]
+  pub fn width(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(SvglIcon::VT_WIDTH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn height(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(SvglIcon::VT_HEIGHT, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SvglIcon<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("svg_content", Self::VT_SVG_CONTENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("viewbox", Self::VT_VIEWBOX, false)?
+     .visit_field::<u32>("width", Self::VT_WIDTH, false)?
+     .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SvglIconArgs<'a> {
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub svg_content: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub viewbox: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub width: u32,
+    pub height: u32,
+}
+impl<'a> Default for SvglIconArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SvglIconArgs {
+      id: None, // required field
+      filename: None,
+      svg_content: None,
+      viewbox: None,
+      width: 0,
+      height: 0,
+    }
+  }
+}
+
+pub struct SvglIconBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SvglIconBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglIcon::VT_ID, id);
+  }
+  #[inline]
+  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglIcon::VT_FILENAME, filename);
+  }
+  #[inline]
+  pub fn add_svg_content(&mut self, svg_content: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglIcon::VT_SVG_CONTENT, svg_content);
+  }
+  #[inline]
+  pub fn add_viewbox(&mut self, viewbox: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglIcon::VT_VIEWBOX, viewbox);
+  }
+  #[inline]
+  pub fn add_width(&mut self, width: u32) {
+    self.fbb_.push_slot::<u32>(SvglIcon::VT_WIDTH, width, 0);
+  }
+  #[inline]
+  pub fn add_height(&mut self, height: u32) {
+    self.fbb_.push_slot::<u32>(SvglIcon::VT_HEIGHT, height, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SvglIconBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SvglIconBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SvglIcon<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, SvglIcon::VT_ID,"id");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SvglIcon<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SvglIcon");
+      ds.field("id", &self.id());
+      ds.field("filename", &self.filename());
+      ds.field("svg_content", &self.svg_content());
+      ds.field("viewbox", &self.viewbox());
+      ds.field("width", &self.width());
+      ds.field("height", &self.height());
+      ds.finish()
+  }
+}
+pub enum SvglRouteOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SvglRoute<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SvglRoute<'a> {
+  type Inner = SvglRoute<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SvglRoute<'a> {
+  pub const VT_ICON: flatbuffers::VOffsetT = 4;
+  pub const VT_LIGHT: flatbuffers::VOffsetT = 6;
+  pub const VT_DARK: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SvglRoute { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SvglRouteArgs<'args>
+  ) -> flatbuffers::WIPOffset<SvglRoute<'bldr>> {
+    let mut builder = SvglRouteBuilder::new(_fbb);
+    if let Some(x) = args.dark { builder.add_dark(x); }
+    if let Some(x) = args.light { builder.add_light(x); }
+    if let Some(x) = args.icon { builder.add_icon(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn icon(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglRoute::VT_ICON, None)}
+  }
+  #[inline]
+  pub fn light(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglRoute::VT_LIGHT, None)}
+  }
+  #[inline]
+  pub fn dark(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglRoute::VT_DARK, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SvglRoute<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("icon", Self::VT_ICON, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("light", Self::VT_LIGHT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("dark", Self::VT_DARK, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SvglRouteArgs<'a> {
+    pub icon: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub light: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub dark: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SvglRouteArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SvglRouteArgs {
+      icon: None,
+      light: None,
+      dark: None,
+    }
+  }
+}
+
+pub struct SvglRouteBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SvglRouteBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_icon(&mut self, icon: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglRoute::VT_ICON, icon);
+  }
+  #[inline]
+  pub fn add_light(&mut self, light: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglRoute::VT_LIGHT, light);
+  }
+  #[inline]
+  pub fn add_dark(&mut self, dark: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglRoute::VT_DARK, dark);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SvglRouteBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SvglRouteBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SvglRoute<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SvglRoute<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SvglRoute");
+      ds.field("icon", &self.icon());
+      ds.field("light", &self.light());
+      ds.field("dark", &self.dark());
+      ds.finish()
+  }
+}
+pub enum SvglEntryOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SvglEntry<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SvglEntry<'a> {
+  type Inner = SvglEntry<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SvglEntry<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_TITLE: flatbuffers::VOffsetT = 6;
+  pub const VT_CATEGORIES: flatbuffers::VOffsetT = 8;
+  pub const VT_ROUTE: flatbuffers::VOffsetT = 10;
+  pub const VT_WORDMARK: flatbuffers::VOffsetT = 12;
+  pub const VT_URL: flatbuffers::VOffsetT = 14;
+  pub const VT_BRAND_URL: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SvglEntry { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SvglEntryArgs<'args>
+  ) -> flatbuffers::WIPOffset<SvglEntry<'bldr>> {
+    let mut builder = SvglEntryBuilder::new(_fbb);
+    if let Some(x) = args.brand_url { builder.add_brand_url(x); }
+    if let Some(x) = args.url { builder.add_url(x); }
+    if let Some(x) = args.wordmark { builder.add_wordmark(x); }
+    if let Some(x) = args.route { builder.add_route(x); }
+    if let Some(x) = args.categories { builder.add_categories(x); }
+    if let Some(x) = args.title { builder.add_title(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglEntry::VT_ID, None).unwrap()}
+  }
+  #[inline]
+  pub fn key_compare_less_than(&self, o: &SvglEntry) -> bool {
+    self.id() < o.id()
+  }
+
+  #[inline]
+  pub fn key_compare_with_value(&self, val: & str) -> ::core::cmp::Ordering {
+    let key = self.id();
+    key.cmp(val)
+  }
+  #[inline]
+  pub fn title(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglIcon::VT_VIEWBOX, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglEntry::VT_TITLE, None)}
+  }
+  #[inline]
+  pub fn categories(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(SvglEntry::VT_CATEGORIES, None)}
+  }
+  #[inline]
+  pub fn route(&self) -> Option<SvglRoute<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<SvglRoute>>(SvglEntry::VT_ROUTE, None)}
+  }
+  #[inline]
+  pub fn wordmark(&self) -> Option<SvglRoute<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<SvglRoute>>(SvglEntry::VT_WORDMARK, None)}
+  }
+  #[inline]
+  pub fn url(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglEntry::VT_URL, None)}
+  }
+  #[inline]
+  pub fn brand_url(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SvglEntry::VT_BRAND_URL, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SvglEntry<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("title", Self::VT_TITLE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("categories", Self::VT_CATEGORIES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<SvglRoute>>("route", Self::VT_ROUTE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<SvglRoute>>("wordmark", Self::VT_WORDMARK, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("url", Self::VT_URL, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("brand_url", Self::VT_BRAND_URL, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SvglEntryArgs<'a> {
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub title: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub categories: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub route: Option<flatbuffers::WIPOffset<SvglRoute<'a>>>,
+    pub wordmark: Option<flatbuffers::WIPOffset<SvglRoute<'a>>>,
+    pub url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub brand_url: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SvglEntryArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SvglEntryArgs {
+      id: None, // required field
+      title: None,
+      categories: None,
+      route: None,
+      wordmark: None,
+      url: None,
+      brand_url: None,
+    }
+  }
+}
+
+pub struct SvglEntryBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SvglEntryBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglEntry::VT_ID, id);
+  }
+  #[inline]
+  pub fn add_title(&mut self, title: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglEntry::VT_TITLE, title);
+  }
+  #[inline]
+  pub fn add_categories(&mut self, categories: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglEntry::VT_CATEGORIES, categories);
+  }
+  #[inline]
+  pub fn add_route(&mut self, route: flatbuffers::WIPOffset<SvglRoute<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<SvglRoute>>(SvglEntry::VT_ROUTE, route);
+  }
+  #[inline]
+  pub fn add_wordmark(&mut self, wordmark: flatbuffers::WIPOffset<SvglRoute<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<SvglRoute>>(SvglEntry::VT_WORDMARK, wordmark);
+  }
+  #[inline]
+  pub fn add_url(&mut self, url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglEntry::VT_URL, url);
+  }
+  #[inline]
+  pub fn add_brand_url(&mut self, brand_url: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglEntry::VT_BRAND_URL, brand_url);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SvglEntryBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SvglEntryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SvglEntry<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, SvglEntry::VT_ID,"id");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SvglEntry<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SvglEntry");
+      ds.field("id", &self.id());
+      ds.field("title", &self.title());
+      ds.field("categories", &self.categories());
+      ds.field("route", &self.route());
+      ds.field("wordmark", &self.wordmark());
+      ds.field("url", &self.url());
+      ds.field("brand_url", &self.brand_url());
+      ds.finish()
+  }
+}
+pub enum SvglCollectionOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SvglCollection<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SvglCollection<'a> {
+  type Inner = SvglCollection<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SvglCollection<'a> {
+  pub const VT_ICONS: flatbuffers::VOffsetT = 4;
+  pub const VT_ENTRIES: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SvglCollection { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SvglCollectionArgs<'args>
+  ) -> flatbuffers::WIPOffset<SvglCollection<'bldr>> {
+    let mut builder = SvglCollectionBuilder::new(_fbb);
+    if let Some(x) = args.entries { builder.add_entries(x); }
+    if let Some(x) = args.icons { builder.add_icons(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn icons(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SvglIcon<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SvglIcon>>>>(SvglCollection::VT_ICONS, None)}
+  }
+  #[inline]
+  pub fn entries(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SvglEntry<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SvglEntry>>>>(SvglCollection::VT_ENTRIES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SvglCollection<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SvglIcon>>>>("icons", Self::VT_ICONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SvglEntry>>>>("entries", Self::VT_ENTRIES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SvglCollectionArgs<'a> {
+    pub icons: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SvglIcon<'a>>>>>,
+    pub entries: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SvglEntry<'a>>>>>,
+}
+impl<'a> Default for SvglCollectionArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SvglCollectionArgs {
+      icons: None,
+      entries: None,
+    }
+  }
+}
+
+pub struct SvglCollectionBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SvglCollectionBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_icons(&mut self, icons: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SvglIcon<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglCollection::VT_ICONS, icons);
+  }
+  #[inline]
+  pub fn add_entries(&mut self, entries: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SvglEntry<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglCollection::VT_ENTRIES, entries);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SvglCollectionBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SvglCollectionBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SvglCollection<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SvglCollection<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SvglCollection");
+      ds.field("icons", &self.icons());
+      ds.field("entries", &self.entries());
+      ds.finish()
+  }
+}
+pub enum SearchIconOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SearchIcon<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SearchIcon<'a> {
+  type Inner = SearchIcon<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SearchIcon<'a> {
+  pub const VT_SET: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SearchIcon { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SearchIconArgs<'args>
+  ) -> flatbuffers::WIPOffset<SearchIcon<'bldr>> {
+    let mut builder = SearchIconBuilder::new(_fbb);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_set(args.set);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn set(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(SearchIcon::VT_SET, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SearchIcon::VT_NAME, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SearchIcon<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u16>("set", Self::VT_SET, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .finish();
+    Ok(())
   }
+}
+pub struct SearchIconArgs<'a> {
+    pub set: u16,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SearchIconArgs<'a> {
   #[inline]
-  pub fn width(&self) -> u32 {
+  fn default() -> Self {
+    SearchIconArgs {
+      set: 0,
+      name: None,
+    }
+  }
+}
+
+pub struct SearchIconBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SearchIconBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_set(&mut self, set: u16) {
+    self.fbb_.push_slot::<u16>(SearchIcon::VT_SET, set, 0);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SearchIcon::VT_NAME, name);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SearchIconBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SearchIconBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SearchIcon<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SearchIcon<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SearchIcon");
+      ds.field("set", &self.set());
+      ds.field("name", &self.name());
+      ds.finish()
+  }
+}
+pub enum SearchSetOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SearchSet<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SearchSet<'a> {
+  type Inner = SearchSet<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SearchSet<'a> {
+  pub const VT_PREFIX: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SearchSet { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SearchSetArgs<'args>
+  ) -> flatbuffers::WIPOffset<SearchSet<'bldr>> {
+    let mut builder = SearchSetBuilder::new(_fbb);
+    if let Some(x) = args.name { builder.add_name(x); }
+    if let Some(x) = args.prefix { builder.add_prefix(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn prefix(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(SvglIcon::VT_WIDTH, Some(0)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SearchSet::VT_PREFIX, None)}
   }
   #[inline]
-  pub fn height(&self) -> u32 {
+  pub fn name(&self) -> Option<&'a str> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<u32>(SvglIcon::VT_HEIGHT, Some(0)).unwrap()}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SearchSet::VT_NAME, None)}
   }
 }
 
-impl flatbuffers::Verifiable for SvglIcon<'_> {
+impl flatbuffers::Verifiable for SearchSet<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, true)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("filename", Self::VT_FILENAME, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("svg_content", Self::VT_SVG_CONTENT, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("viewbox", Self::VT_VIEWBOX, false)?
-     .visit_field::<u32>("width", Self::VT_WIDTH, false)?
-     .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("prefix", Self::VT_PREFIX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
      .finish();
     Ok(())
   }
 }
-pub struct SvglIconArgs<'a> {
-    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub filename: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub svg_content: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub viewbox: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub width: u32,
-    pub height: u32,
+pub struct SearchSetArgs<'a> {
+    pub prefix: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for SvglIconArgs<'a> {
+impl<'a> Default for SearchSetArgs<'a> {
   #[inline]
   fn default() -> Self {
-    SvglIconArgs {
-      id: None, // required field
-      filename: None,
-      svg_content: None,
-      viewbox: None,
-      width: 0,
-      height: 0,
+    SearchSetArgs {
+      prefix: None,
+      name: None,
     }
   }
 }
 
-pub struct SvglIconBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SearchSetBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SvglIconBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SearchSetBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglIcon::VT_ID, id);
+  pub fn add_prefix(&mut self, prefix: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SearchSet::VT_PREFIX, prefix);
   }
   #[inline]
-  pub fn add_filename(&mut self, filename: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglIcon::VT_FILENAME, filename);
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SearchSet::VT_NAME, name);
   }
   #[inline]
-  pub fn add_svg_content(&mut self, svg_content: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglIcon::VT_SVG_CONTENT, svg_content);
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SearchSetBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SearchSetBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
   }
   #[inline]
-  pub fn add_viewbox(&mut self, viewbox: flatbuffers::WIPOffset<&'b  str>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglIcon::VT_VIEWBOX, viewbox);
+  pub fn finish(self) -> flatbuffers::WIPOffset<SearchSet<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SearchSet<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SearchSet");
+      ds.field("prefix", &self.prefix());
+      ds.field("name", &self.name());
+      ds.finish()
   }
+}
+pub enum SearchTermOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SearchTerm<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SearchTerm<'a> {
+  type Inner = SearchTerm<'a>;
   #[inline]
-  pub fn add_width(&mut self, width: u32) {
-    self.fbb_.push_slot::<u32>(SvglIcon::VT_WIDTH, width, 0);
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
+}
+
+impl<'a> SearchTerm<'a> {
+  pub const VT_TERM: flatbuffers::VOffsetT = 4;
+  pub const VT_ICONS: flatbuffers::VOffsetT = 6;
+  pub const VT_FIELDS: flatbuffers::VOffsetT = 8;
+  pub const VT_SETS: flatbuffers::VOffsetT = 10;
+
   #[inline]
-  pub fn add_height(&mut self, height: u32) {
-    self.fbb_.push_slot::<u32>(SvglIcon::VT_HEIGHT, height, 0);
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SearchTerm { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SearchTermArgs<'args>
+  ) -> flatbuffers::WIPOffset<SearchTerm<'bldr>> {
+    let mut builder = SearchTermBuilder::new(_fbb);
+    if let Some(x) = args.sets { builder.add_sets(x); }
+    if let Some(x) = args.fields { builder.add_fields(x); }
+    if let Some(x) = args.icons { builder.add_icons(x); }
+    if let Some(x) = args.term { builder.add_term(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn term(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SearchTerm::VT_TERM, None).unwrap()}
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SvglIconBuilder<'a, 'b, A> {
+  pub fn key_compare_less_than(&self, o: &SearchTerm) -> bool {
+    self.term() < o.term()
+  }
+
+  #[inline]
+  pub fn key_compare_with_value(&self, val: & str) -> ::core::cmp::Ordering {
+    let key = self.term();
+    key.cmp(val)
+  }
+  #[inline]
+  pub fn icons(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(SearchTerm::VT_ICONS, None)}
+  }
+  #[inline]
+  pub fn fields(&self) -> Option<flatbuffers::Vector<'a, SearchField>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, SearchField>>>(SearchTerm::VT_FIELDS, None)}
+  }
+  #[inline]
+  pub fn sets(&self) -> Option<flatbuffers::Vector<'a, u16>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(SearchTerm::VT_SETS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for SearchTerm<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("term", Self::VT_TERM, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("icons", Self::VT_ICONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, SearchField>>>("fields", Self::VT_FIELDS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("sets", Self::VT_SETS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SearchTermArgs<'a> {
+    pub term: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub icons: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub fields: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, SearchField>>>,
+    pub sets: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+}
+impl<'a> Default for SearchTermArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SearchTermArgs {
+      term: None, // required field
+      icons: None,
+      fields: None,
+      sets: None,
+    }
+  }
+}
+
+pub struct SearchTermBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SearchTermBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_term(&mut self, term: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SearchTerm::VT_TERM, term);
+  }
+  #[inline]
+  pub fn add_icons(&mut self, icons: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SearchTerm::VT_ICONS, icons);
+  }
+  #[inline]
+  pub fn add_fields(&mut self, fields: flatbuffers::WIPOffset<flatbuffers::Vector<'b , SearchField>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SearchTerm::VT_FIELDS, fields);
+  }
+  #[inline]
+  pub fn add_sets(&mut self, sets: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SearchTerm::VT_SETS, sets);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SearchTermBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    SvglIconBuilder {
+    SearchTermBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<SvglIcon<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SearchTerm<'a>> {
     let o = self.fbb_.end_table(self.start_);
-    self.fbb_.required(o, SvglIcon::VT_ID,"id");
+    self.fbb_.required(o, SearchTerm::VT_TERM,"term");
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for SvglIcon<'_> {
+impl core::fmt::Debug for SearchTerm<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("SvglIcon");
-      ds.field("id", &self.id());
-      ds.field("filename", &self.filename());
-      ds.field("svg_content", &self.svg_content());
-      ds.field("viewbox", &self.viewbox());
-      ds.field("width", &self.width());
-      ds.field("height", &self.height());
+    let mut ds = f.debug_struct("SearchTerm");
+      ds.field("term", &self.term());
+      ds.field("icons", &self.icons());
+      ds.field("fields", &self.fields());
+      ds.field("sets", &self.sets());
       ds.finish()
   }
 }
-pub enum SvglCollectionOffset {}
+pub enum SearchIndexOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SvglCollection<'a> {
+pub struct SearchIndex<'a> {
   pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SvglCollection<'a> {
-  type Inner = SvglCollection<'a>;
+impl<'a> flatbuffers::Follow<'a> for SearchIndex<'a> {
+  type Inner = SearchIndex<'a>;
   #[inline]
   unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
     Self { _tab: flatbuffers::Table::new(buf, loc) }
   }
 }
 
-impl<'a> SvglCollection<'a> {
-  pub const VT_ICONS: flatbuffers::VOffsetT = 4;
+impl<'a> SearchIndex<'a> {
+  pub const VT_SETS: flatbuffers::VOffsetT = 4;
+  pub const VT_ICONS: flatbuffers::VOffsetT = 6;
+  pub const VT_TERMS: flatbuffers::VOffsetT = 8;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-    SvglCollection { _tab: table }
+    SearchIndex { _tab: table }
   }
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args SvglCollectionArgs<'args>
-  ) -> flatbuffers::WIPOffset<SvglCollection<'bldr>> {
-    let mut builder = SvglCollectionBuilder::new(_fbb);
+    args: &'args SearchIndexArgs<'args>
+  ) -> flatbuffers::WIPOffset<SearchIndex<'bldr>> {
+    let mut builder = SearchIndexBuilder::new(_fbb);
+    if let Some(x) = args.terms { builder.add_terms(x); }
     if let Some(x) = args.icons { builder.add_icons(x); }
+    if let Some(x) = args.sets { builder.add_sets(x); }
     builder.finish()
   }
 
 
   #[inline]
-  pub fn icons(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SvglIcon<'a>>>> {
+  pub fn sets(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SearchSet<'a>>>> {
     // Safety:
     // Created from valid Table for this object
     // which contains a valid value in this slot
-    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SvglIcon>>>>(SvglCollection::VT_ICONS, None)}
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SearchSet>>>>(SearchIndex::VT_SETS, None)}
+  }
+  #[inline]
+  pub fn icons(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SearchIcon<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SearchIcon>>>>(SearchIndex::VT_ICONS, None)}
+  }
+  #[inline]
+  pub fn terms(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SearchTerm<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SearchTerm>>>>(SearchIndex::VT_TERMS, None)}
   }
 }
 
-impl flatbuffers::Verifiable for SvglCollection<'_> {
+impl flatbuffers::Verifiable for SearchIndex<'_> {
   #[inline]
   fn run_verifier(
     v: &mut flatbuffers::Verifier, pos: usize
   ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
     use self::flatbuffers::Verifiable;
     v.visit_table(pos)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SvglIcon>>>>("icons", Self::VT_ICONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SearchSet>>>>("sets", Self::VT_SETS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SearchIcon>>>>("icons", Self::VT_ICONS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SearchTerm>>>>("terms", Self::VT_TERMS, false)?
      .finish();
     Ok(())
   }
 }
-pub struct SvglCollectionArgs<'a> {
-    pub icons: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SvglIcon<'a>>>>>,
+pub struct SearchIndexArgs<'a> {
+    pub sets: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SearchSet<'a>>>>>,
+    pub icons: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SearchIcon<'a>>>>>,
+    pub terms: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SearchTerm<'a>>>>>,
 }
-impl<'a> Default for SvglCollectionArgs<'a> {
+impl<'a> Default for SearchIndexArgs<'a> {
   #[inline]
   fn default() -> Self {
-    SvglCollectionArgs {
+    SearchIndexArgs {
+      sets: None,
       icons: None,
+      terms: None,
     }
   }
 }
 
-pub struct SvglCollectionBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SearchIndexBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
   fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
   start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SvglCollectionBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SearchIndexBuilder<'a, 'b, A> {
   #[inline]
-  pub fn add_icons(&mut self, icons: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SvglIcon<'b >>>>) {
-    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SvglCollection::VT_ICONS, icons);
+  pub fn add_sets(&mut self, sets: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SearchSet<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SearchIndex::VT_SETS, sets);
   }
   #[inline]
-  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SvglCollectionBuilder<'a, 'b, A> {
+  pub fn add_icons(&mut self, icons: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SearchIcon<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SearchIndex::VT_ICONS, icons);
+  }
+  #[inline]
+  pub fn add_terms(&mut self, terms: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<SearchTerm<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SearchIndex::VT_TERMS, terms);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SearchIndexBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
-    SvglCollectionBuilder {
+    SearchIndexBuilder {
       fbb_: _fbb,
       start_: start,
     }
   }
   #[inline]
-  pub fn finish(self) -> flatbuffers::WIPOffset<SvglCollection<'a>> {
+  pub fn finish(self) -> flatbuffers::WIPOffset<SearchIndex<'a>> {
     let o = self.fbb_.end_table(self.start_);
     flatbuffers::WIPOffset::new(o.value())
   }
 }
 
-impl core::fmt::Debug for SvglCollection<'_> {
+impl core::fmt::Debug for SearchIndex<'_> {
   fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-    let mut ds = f.debug_struct("SvglCollection");
+    let mut ds = f.debug_struct("SearchIndex");
+      ds.field("sets", &self.sets());
       ds.field("icons", &self.icons());
+      ds.field("terms", &self.terms());
       ds.finish()
   }
 }
@@ -1,5 +1,11 @@
 pub mod converters;
+pub mod export;
+pub mod font;
+pub mod raster;
 pub mod readers;
+pub mod registry;
+pub mod search;
+pub mod xcassets;
 
 // Include generated FlatBuffers code
 #[allow(dead_code, unused_imports)]
@@ -7,7 +13,13 @@ pub mod readers;
 pub mod icon_generated;
 
 // Re-export commonly used types
-pub use readers::{IconSetsReader, SvglReader};
+pub use converters::svgl::Theme;
+pub use export::{ExportOptions, Framework, IconSelection};
+pub use font::IconFont;
+pub use readers::{IconRef, IconSetView, IconSetsReader, SvglCollectionView, SvglLogo, SvglReader, SvglVariants};
+pub use registry::{IconRegistry, ResolvedIcon};
+pub use dx_icon_types::StaticIcon;
+pub use search::{IconSearch, SearchHit, SearchOptions};
 
 /// Initialize and get access to icon-sets library
 pub fn icon_sets() -> IconSetsReader {
@@ -18,3 +30,13 @@ pub fn icon_sets() -> IconSetsReader {
 pub fn svgl() -> SvglReader {
     SvglReader::new()
 }
+
+/// Unified `prefix:name` resolver across icon-sets and svgl
+pub fn registry() -> IconRegistry {
+    IconRegistry::new()
+}
+
+/// Search icon names, aliases, categories, set names and tags
+pub fn search() -> IconSearch<'static> {
+    IconSearch::embedded()
+}
@@ -1,41 +1,748 @@
-use dx_icon::{converters::iconsets::IconSetJson, SvglReader};
+use clap::{Args, Parser, Subcommand};
+use dx_icon::converters::bundle::{build_bundle, BundleSources};
+use dx_icon::converters::css::{CssIconBuilder, CssOptions};
+use dx_icon::converters::optimize::{OptimizeOptions, OptimizeStats, Pass};
+use dx_icon::converters::sprite::SpriteBuilder;
+use dx_icon::export::{export_icons, export_set};
+use dx_icon::font::char_codepoints;
+use dx_icon::raster::{export_pngs, write_favicons, PngOptions};
+use dx_icon::registry::SVGL_PREFIX;
+use dx_icon::xcassets::{export_xcassets, XcassetsOptions};
+use dx_icon::{ExportOptions, Framework, IconFont, IconRegistry, IconSelection, ResolvedIcon, SearchOptions, Theme};
+use serde_json::{json, Value};
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Write};
 use std::path::PathBuf;
+use std::process::ExitCode;
 
-fn main() {
-    println!("=== dx-icon: FlatBuffers Icon Library ===\n");
-    
-    // Example 1: SVGL Icons
-    println!("1. SVGL Icons (SVG files)");
-    println!("   Run: cargo run --example svgl_usage\n");
-    
-    let svgl_path = PathBuf::from("inspirations/svgl/static/library");
-    match SvglReader::from_directory(svgl_path) {
-        Ok(reader) => {
-            println!("   ✓ {} SVGL icons available", reader.count());
+/// Exit code when an icon, set or search result could not be found
+const EXIT_NOT_FOUND: u8 = 3;
+
+#[derive(Parser)]
+#[command(
+    name = "dx-icon",
+    version,
+    about = "Search, inspect and export icons from the embedded icon library",
+    after_help = "Exit codes: 0 success, 1 error, 2 invalid usage, 3 nothing found"
+)]
+struct Cli {
+    /// Print machine-readable JSON instead of text
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Search icons by name, alias, category or set
+    Search {
+        query: String,
+        /// Only search these prefixes (repeatable)
+        #[arg(short, long = "prefix")]
+        prefixes: Vec<String>,
+        #[arg(short, long, default_value_t = 20)]
+        limit: usize,
+        /// Disable typo-tolerant matching
+        #[arg(long)]
+        exact: bool,
+    },
+    /// Print an icon as SVG or as a framework component
+    Show {
+        /// Icon id such as `mdi:home` or `svgl:github`
+        id: String,
+        /// svg, react, vue, svelte, react-native, qwik, solid, astro, leptos, dioxus, yew, vector-drawable, compose,
+        /// flutter or flutter-painter
+        #[arg(short, long, default_value = "svg")]
+        framework: Framework,
+        /// Color scheme for svgl logos with light and dark variants
+        #[arg(short, long)]
+        theme: Option<Theme>,
+        /// Generate JavaScript instead of TypeScript components
+        #[arg(long)]
+        js: bool,
+    },
+    /// Write icons to files for a framework, with an index barrel and license headers
+    Export {
+        /// Icon ids such as `mdi:home`, or icon names when `--set` is given
+        #[arg(required_unless_present = "set")]
+        ids: Vec<String>,
+        /// Export from this icon set; every icon unless names or `--glob` are given
+        #[arg(short, long)]
+        set: Option<String>,
+        /// Only export icons of `--set` matching these patterns, e.g. `arrow-*`
+        #[arg(short, long, requires = "set")]
+        glob: Vec<String>,
+        /// svg, react, vue, svelte, react-native, qwik, solid, astro, leptos, dioxus, yew, vector-drawable, compose,
+        /// flutter or flutter-painter
+        #[arg(short, long, default_value = "svg")]
+        framework: Framework,
+        #[arg(short, long, default_value = "icons")]
+        out: PathBuf,
+        #[arg(short, long)]
+        theme: Option<Theme>,
+        #[arg(long)]
+        js: bool,
+        /// Skip writing the index barrel
+        #[arg(long)]
+        no_barrel: bool,
+        /// Skip the license comment at the top of each file
+        #[arg(long)]
+        no_license: bool,
+        #[command(flatten)]
+        optimize: OptimizeArgs,
+    },
+    /// Combine icons into one SVG sprite of `<symbol>` elements
+    Sprite {
+        /// Icon ids such as `mdi:home`; symbols are named `mdi-home`
+        #[arg(required = true)]
+        ids: Vec<String>,
+        /// Write the sprite here instead of stdout
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+        #[arg(short, long)]
+        theme: Option<Theme>,
+    },
+    /// Generate CSS classes that draw icons from data URIs, masked with `currentColor` unless the icon has its own colors
+    Css {
+        /// Icon ids such as `mdi:home`, or icon names when `--set` is given
+        #[arg(required_unless_present = "set")]
+        ids: Vec<String>,
+        /// Take icons from this icon set; every icon unless names or `--glob` are given
+        #[arg(short, long)]
+        set: Option<String>,
+        /// Only include icons of `--set` matching these patterns, e.g. `arrow-*`
+        #[arg(short, long, requires = "set")]
+        glob: Vec<String>,
+        /// Class selector, with `{prefix}` and `{name}` filled in
+        #[arg(long, default_value = ".i-{prefix}-{name}")]
+        selector: String,
+        /// Icon height; widths follow each icon's aspect ratio
+        #[arg(long, default_value = "1em")]
+        size: String,
+        /// Custom property that overrides the size, e.g. `--icon-size`
+        #[arg(long, allow_hyphen_values = true)]
+        size_var: Option<String>,
+        /// Write the stylesheet here instead of stdout
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+        #[arg(short, long)]
+        theme: Option<Theme>,
+        #[command(flatten)]
+        optimize: OptimizeArgs,
+    },
+    /// Build an icon font (TTF and WOFF2) with a CSS class per icon
+    Font {
+        /// Icon ids such as `mdi:home`, or icon names when `--set` is given
+        #[arg(required_unless_present = "set")]
+        ids: Vec<String>,
+        /// Take icons from this icon set; every icon unless names or `--glob` are given
+        #[arg(short, long)]
+        set: Option<String>,
+        /// Only include icons of `--set` matching these patterns, e.g. `arrow-*`
+        #[arg(short, long, requires = "set")]
+        glob: Vec<String>,
+        #[arg(short, long, default_value = "fonts")]
+        out: PathBuf,
+        /// Font family, also used for the file names
+        #[arg(short, long, default_value = "dx-icons")]
+        name: String,
+        /// CSS classes are named `{class_prefix}-{icon}`
+        #[arg(long, default_value = "icon")]
+        class_prefix: String,
+        #[arg(short, long)]
+        theme: Option<Theme>,
+    },
+    /// Rasterize icons to PNG, with `@2x`/`@3x` variants for each extra scale
+    Png {
+        /// Icon ids such as `mdi:home`, or icon names when `--set` is given
+        #[arg(required_unless_present = "set")]
+        ids: Vec<String>,
+        /// Take icons from this icon set; every icon unless names or `--glob` are given
+        #[arg(short, long)]
+        set: Option<String>,
+        /// Only include icons of `--set` matching these patterns, e.g. `arrow-*`
+        #[arg(short, long, requires = "set")]
+        glob: Vec<String>,
+        /// Height in pixels at 1x
+        #[arg(long, default_value_t = 24)]
+        size: u32,
+        /// Fill for `currentColor`, e.g. `#0f172a`; black when omitted
+        #[arg(short, long)]
+        color: Option<String>,
+        /// Pixel densities to write, e.g. `1,2,3`
+        #[arg(long, value_delimiter = ',', default_value = "1")]
+        scales: Vec<u32>,
+        #[arg(short, long, default_value = "png")]
+        out: PathBuf,
+        #[arg(short, long)]
+        theme: Option<Theme>,
+    },
+    /// Write icons as vector image sets of an Xcode asset catalog, optionally with SwiftUI shapes
+    Xcassets {
+        /// Icon ids such as `mdi:home`, or icon names when `--set` is given
+        #[arg(required_unless_present = "set")]
+        ids: Vec<String>,
+        /// Take icons from this icon set; every icon unless names or `--glob` are given
+        #[arg(short, long)]
+        set: Option<String>,
+        /// Only include icons of `--set` matching these patterns, e.g. `arrow-*`
+        #[arg(short, long, requires = "set")]
+        glob: Vec<String>,
+        /// Asset catalog directory
+        #[arg(short, long, default_value = "Icons.xcassets")]
+        out: PathBuf,
+        /// Also write a Swift file declaring a SwiftUI `Shape` per icon
+        #[arg(long, value_name = "FILE")]
+        swiftui: Option<PathBuf>,
+        #[arg(short, long)]
+        theme: Option<Theme>,
+        #[command(flatten)]
+        optimize: OptimizeArgs,
+    },
+    /// Write favicon.ico, favicon.svg and touch icons for one icon
+    Favicon {
+        /// Icon id such as `mdi:home` or `svgl:github`
+        id: String,
+        /// Fill for `currentColor`, e.g. `#0f172a`; black when omitted
+        #[arg(short, long)]
+        color: Option<String>,
+        #[arg(short, long, default_value = "favicon")]
+        out: PathBuf,
+        #[arg(short, long)]
+        theme: Option<Theme>,
+    },
+    /// List every available icon set
+    ListSets,
+    /// Show metadata for an icon set
+    Info { prefix: String },
+    /// Compile icon sources into FlatBuffers binaries
+    Build {
+        #[arg(long, default_value = "inspirations/icon-sets/json")]
+        icon_sets: PathBuf,
+        #[arg(long, default_value = "inspirations/svgl/static/library")]
+        svgl: PathBuf,
+        #[arg(long, default_value = "inspirations/svgl/src/data/svgs.ts")]
+        svgl_data: PathBuf,
+        #[arg(short, long, default_value = "icons")]
+        out: PathBuf,
+        #[command(flatten)]
+        optimize: OptimizeArgs,
+    },
+}
+
+#[derive(Args)]
+struct OptimizeArgs {
+    /// Keep SVG markup exactly as stored
+    #[arg(long)]
+    no_optimize: bool,
+    /// Skip an optimizer pass: comments, metadata, groups, merge-paths, minify or precision (repeatable)
+    #[arg(long = "skip-pass", value_name = "PASS")]
+    skip: Vec<Pass>,
+    /// Decimal places kept in path data and coordinates
+    #[arg(long, value_name = "DIGITS")]
+    precision: Option<u8>,
+}
+
+impl OptimizeArgs {
+    fn options(&self) -> Option<OptimizeOptions> {
+        if self.no_optimize {
+            return None;
         }
-        Err(e) => {
-            println!("   ✗ Error: {}", e);
+        let mut options = OptimizeOptions::default();
+        if self.precision.is_some() {
+            options.precision = self.precision;
+        }
+        for pass in &self.skip {
+            options.skip(*pass);
         }
+        Some(options)
     }
-    
-    // Example 2: Icon-Sets
-    println!("\n2. Icon-Sets (JSON collections)");
-    println!("   Run: cargo run --example iconsets_usage\n");
-    
-    let example_path = PathBuf::from("inspirations/icon-sets/json/ant-design.json");
-    match IconSetJson::from_file(&example_path) {
-        Ok(iconset) => {
-            println!("   ✓ Example: {} with {} icons", iconset.info.name, iconset.icons.len());
+}
+
+/// `prefix-name` stem that names `show` components and font glyphs from several sets unambiguously
+fn file_stem(icon: &ResolvedIcon) -> String {
+    format!("{}-{}", icon.prefix, icon.name.replace('_', "-"))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(code) => code,
+        // Output piped into `head` and similar is not an error
+        Err(e) if e.downcast_ref::<io::Error>().is_some_and(|e| e.kind() == io::ErrorKind::BrokenPipe) => {
+            ExitCode::SUCCESS
         }
         Err(e) => {
-            println!("   ✗ Error loading example: {}", e);
+            if cli.json {
+                println!("{}", json!({ "error": e.to_string() }));
+            } else {
+                eprintln!("error: {}", e);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: &Cli) -> Result<ExitCode, Box<dyn Error>> {
+    let registry = IconRegistry::new();
+
+    match &cli.command {
+        Command::Search { query, prefixes, limit, exact } => {
+            let options = SearchOptions { prefixes: prefixes.clone(), limit: *limit, fuzzy: !exact };
+            let hits = dx_icon::search().search(query, &options);
+            if cli.json {
+                let hits: Vec<Value> = hits
+                    .iter()
+                    .map(|hit| json!({ "id": hit.id(), "prefix": hit.prefix, "name": hit.name, "score": hit.score }))
+                    .collect();
+                emit(Value::Array(hits))?;
+            } else {
+                for hit in &hits {
+                    emit(hit.id())?;
+                }
+            }
+            Ok(found(!hits.is_empty()))
+        }
+        Command::Show { id, framework, theme, js } => {
+            let Some(icon) = resolve(&registry, id, *theme) else {
+                return not_found(cli, format!("icon '{}' not found", id));
+            };
+            let output = framework.render(&icon, &file_stem(&icon), !js);
+            if cli.json {
+                let mut value = icon_json(&icon);
+                value["output"] = Value::String(output);
+                emit(value)?;
+            } else {
+                emit(output)?;
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Export { ids, set, glob, framework, out, theme, js, no_barrel, no_license, optimize } => {
+            let options = ExportOptions {
+                framework: *framework,
+                typescript: !js,
+                theme: *theme,
+                barrel: !no_barrel,
+                license_header: !no_license,
+                optimize: optimize.options(),
+            };
+            let report = match set {
+                Some(prefix) => {
+                    if registry.icon_sets().get(prefix).is_none() && (prefix != SVGL_PREFIX || registry.svgl().is_none()) {
+                        return not_found(cli, format!("icon set '{}' not found", prefix));
+                    }
+                    let selection = if !glob.is_empty() {
+                        IconSelection::Globs(glob.clone())
+                    } else if !ids.is_empty() {
+                        IconSelection::Names(ids.clone())
+                    } else {
+                        IconSelection::All
+                    };
+                    export_set(&registry, prefix, &selection, &options, out)?
+                }
+                None => export_icons(&registry, ids, &options, out)?,
+            };
+
+            if cli.json {
+                emit(json!({
+                    "written": report.files,
+                    "barrel": report.barrel,
+                    "missing": report.missing,
+                    "warnings": report.warnings,
+                    "optimized": options.optimize.map(|_| stats_json(&report.stats)),
+                }))?;
+            } else {
+                for path in report.files.iter().chain(&report.barrel) {
+                    emit(path.display())?;
+                }
+                for id in &report.missing {
+                    eprintln!("warning: icon '{}' not found", id);
+                }
+                for warning in &report.warnings {
+                    eprintln!("warning: {}", warning);
+                }
+                if options.optimize.is_some() && report.stats.files > 0 {
+                    eprintln!("optimized {} icons: {}", report.stats.files, report.stats);
+                }
+            }
+            Ok(found(report.missing.is_empty() && !report.files.is_empty()))
+        }
+        Command::Sprite { ids, out, theme } => {
+            let mut sprite = SpriteBuilder::new();
+            let mut symbols = Vec::new();
+            let mut missing = Vec::new();
+            for id in ids {
+                match resolve(&registry, id, *theme) {
+                    Some(icon) => symbols.extend(sprite.add(&icon.id(), &icon.body, icon.view_box)),
+                    None => missing.push(id.as_str()),
+                }
+            }
+
+            let svg = sprite.to_svg();
+            match out {
+                Some(path) => fs::write(path, &svg)?,
+                None if !cli.json => emit(&svg)?,
+                None => {}
+            }
+            if cli.json {
+                let svg = if out.is_none() { Some(svg) } else { None };
+                emit(json!({ "symbols": symbols, "missing": missing, "svg": svg }))?;
+            } else {
+                for id in &missing {
+                    eprintln!("warning: icon '{}' not found", id);
+                }
+            }
+            Ok(found(missing.is_empty()))
+        }
+        Command::Css { ids, set, glob, selector, size, size_var, out, theme, optimize } => {
+            let Some(ids) = select_ids(&registry, ids, set.as_deref(), glob) else {
+                return not_found(cli, format!("icon set '{}' not found", set.as_deref().unwrap_or_default()));
+            };
+            let options = CssOptions { selector: selector.clone(), size: size.clone(), size_var: size_var.clone() };
+            let optimize = optimize.options();
+            let mut css = CssIconBuilder::new(options);
+            let mut selectors = Vec::new();
+            let mut missing = Vec::new();
+            for id in &ids {
+                match resolve(&registry, id, *theme) {
+                    Some(mut icon) => {
+                        if let Some(optimize) = &optimize {
+                            icon.optimize(optimize);
+                        }
+                        selectors.extend(css.add(icon.prefix, icon.name, &icon.to_svg(), icon.view_box, icon.palette));
+                    }
+                    None => missing.push(id.as_str()),
+                }
+            }
+
+            let stylesheet = css.to_css();
+            match out {
+                Some(path) => fs::write(path, &stylesheet)?,
+                None if !cli.json => emit(stylesheet.trim_end())?,
+                None => {}
+            }
+            if cli.json {
+                let stylesheet = if out.is_none() { Some(stylesheet) } else { None };
+                emit(json!({ "selectors": selectors, "missing": missing, "css": stylesheet }))?;
+            } else {
+                for id in &missing {
+                    eprintln!("warning: icon '{}' not found", id);
+                }
+            }
+            Ok(found(missing.is_empty() && !css.is_empty()))
+        }
+        Command::Font { ids, set, glob, out, name, class_prefix, theme } => {
+            let Some(ids) = select_ids(&registry, ids, set.as_deref(), glob) else {
+                return not_found(cli, format!("icon set '{}' not found", set.as_deref().unwrap_or_default()));
+            };
+
+            let mut icons = Vec::new();
+            let mut missing = Vec::new();
+            for id in &ids {
+                match resolve(&registry, id, *theme) {
+                    Some(icon) => icons.push(icon),
+                    None => missing.push(id.as_str()),
+                }
+            }
+
+            let prefixed = icons.windows(2).any(|pair| pair[0].prefix != pair[1].prefix);
+            let mut font = IconFont::new(name);
+            let mut skipped = Vec::new();
+            for icon in &icons {
+                let glyph = if prefixed { file_stem(icon) } else { icon.name.replace('_', "-") };
+                let codepoint = registry
+                    .icon_sets()
+                    .get(icon.prefix)
+                    .and_then(|set| char_codepoints(set.view().chars()).get(icon.name).copied());
+                if let Err(e) = font.add(&glyph, &icon.body, icon.view_box, codepoint) {
+                    skipped.push(format!("{}: {}", icon.id(), e));
+                }
+            }
+            if font.glyphs().is_empty() {
+                return not_found(cli, "no icons could be added to the font".to_string());
+            }
+
+            fs::create_dir_all(out)?;
+            let files = [
+                (out.join(format!("{}.ttf", name)), font.to_ttf()),
+                (out.join(format!("{}.woff2", name)), font.to_woff2()),
+                (out.join(format!("{}.css", name)), font.to_css(&format!("./{}", name), class_prefix).into_bytes()),
+            ];
+            for (path, content) in &files {
+                fs::write(path, content)?;
+            }
+
+            if cli.json {
+                let glyphs: Vec<Value> = font
+                    .glyphs()
+                    .iter()
+                    .map(|glyph| json!({ "name": glyph.name, "codepoint": format!("{:x}", glyph.codepoint) }))
+                    .collect();
+                let written: Vec<&PathBuf> = files.iter().map(|(path, _)| path).collect();
+                emit(json!({ "written": written, "glyphs": glyphs, "missing": missing, "skipped": skipped }))?;
+            } else {
+                for (path, _) in &files {
+                    emit(path.display())?;
+                }
+                for id in &missing {
+                    eprintln!("warning: icon '{}' not found", id);
+                }
+                for reason in &skipped {
+                    eprintln!("warning: skipped {}", reason);
+                }
+            }
+            Ok(found(missing.is_empty()))
         }
+        Command::Png { ids, set, glob, size, color, scales, out, theme } => {
+            let Some(ids) = select_ids(&registry, ids, set.as_deref(), glob) else {
+                return not_found(cli, format!("icon set '{}' not found", set.as_deref().unwrap_or_default()));
+            };
+            if *size == 0 || scales.contains(&0) {
+                return Err("--size and --scales must be at least 1".into());
+            }
+            let options = PngOptions { size: *size, color: color.clone(), theme: *theme, scales: scales.clone() };
+            let report = export_pngs(&registry, &ids, &options, out)?;
+
+            if cli.json {
+                emit(json!({ "written": report.files, "missing": report.missing, "warnings": report.warnings }))?;
+            } else {
+                for path in &report.files {
+                    emit(path.display())?;
+                }
+                for id in &report.missing {
+                    eprintln!("warning: icon '{}' not found", id);
+                }
+                for warning in &report.warnings {
+                    eprintln!("warning: {}", warning);
+                }
+            }
+            Ok(found(report.missing.is_empty() && !report.files.is_empty()))
+        }
+        Command::Xcassets { ids, set, glob, out, swiftui, theme, optimize } => {
+            let Some(ids) = select_ids(&registry, ids, set.as_deref(), glob) else {
+                return not_found(cli, format!("icon set '{}' not found", set.as_deref().unwrap_or_default()));
+            };
+            let options = XcassetsOptions { theme: *theme, swiftui: swiftui.clone(), optimize: optimize.options() };
+            let report = export_xcassets(&registry, &ids, &options, out)?;
+
+            if cli.json {
+                emit(json!({
+                    "written": report.files,
+                    "missing": report.missing,
+                    "warnings": report.warnings,
+                    "optimized": options.optimize.map(|_| stats_json(&report.stats)),
+                }))?;
+            } else {
+                for path in &report.files {
+                    emit(path.display())?;
+                }
+                for id in &report.missing {
+                    eprintln!("warning: icon '{}' not found", id);
+                }
+                for warning in &report.warnings {
+                    eprintln!("warning: {}", warning);
+                }
+            }
+            Ok(found(report.missing.is_empty() && report.files.len() > 1))
+        }
+        Command::Favicon { id, color, out, theme } => {
+            let Some(icon) = resolve(&registry, id, *theme) else {
+                return not_found(cli, format!("icon '{}' not found", id));
+            };
+            let files = write_favicons(&icon, color.as_deref(), out)?;
+            if cli.json {
+                emit(json!({ "written": files }))?;
+            } else {
+                for path in &files {
+                    emit(path.display())?;
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::ListSets => {
+            let sets = registry.icon_sets();
+            let mut rows: Vec<(String, String, usize)> = sets
+                .list_sets()
+                .into_iter()
+                .filter_map(|prefix| sets.get(prefix))
+                .map(|set| (set.prefix.clone(), set.name.clone(), set.total as usize))
+                .collect();
+            if let Some(svgl) = registry.svgl() {
+                rows.push((SVGL_PREFIX.to_string(), "SVGL".to_string(), svgl.len()));
+            }
+            rows.sort();
+
+            if cli.json {
+                let rows: Vec<Value> = rows
+                    .iter()
+                    .map(|(prefix, name, total)| json!({ "prefix": prefix, "name": name, "total": total }))
+                    .collect();
+                emit(Value::Array(rows))?;
+            } else {
+                for (prefix, name, total) in &rows {
+                    emit(format_args!("{:<24} {:>6}  {}", prefix, total, name))?;
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Info { prefix } => {
+            let Some(info) = set_info(&registry, prefix) else {
+                return not_found(cli, format!("icon set '{}' not found", prefix));
+            };
+            if cli.json {
+                emit(info)?;
+            } else if let Value::Object(fields) = &info {
+                for (key, value) in fields {
+                    match value {
+                        Value::Null => {}
+                        Value::String(text) => emit(format_args!("{:<12} {}", key, text))?,
+                        Value::Array(items) => {
+                            let items: Vec<String> = items
+                                .iter()
+                                .map(|item| item.as_str().map_or_else(|| item.to_string(), str::to_string))
+                                .collect();
+                            emit(format_args!("{:<12} {}", key, items.join(", ")))?;
+                        }
+                        other => emit(format_args!("{:<12} {}", key, other))?,
+                    }
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Build { icon_sets, svgl, svgl_data, out, optimize } => {
+            let sources = BundleSources { icon_sets, svgl, svgl_data, optimize: optimize.options() };
+            let bundle = build_bundle(&sources, out)?;
+            for warning in &bundle.warnings {
+                eprintln!("warning: {}", warning);
+            }
+
+            if cli.json {
+                let sets: Vec<Value> = bundle
+                    .sets
+                    .iter()
+                    .map(|(prefix, path)| json!({ "prefix": prefix, "path": path }))
+                    .collect();
+                emit(json!({
+                    "sets": sets,
+                    "svgl": bundle.svgl,
+                    "search": bundle.search,
+                    "warnings": bundle.warnings,
+                    "optimized": sources.optimize.map(|_| stats_json(&bundle.svgl_stats)),
+                }))?;
+            } else {
+                emit(format_args!(
+                    "Compiled {} icon sets{} into {}",
+                    bundle.sets.len(),
+                    if bundle.svgl.is_some() { " and svgl" } else { "" },
+                    out.display()
+                ))?;
+                if sources.optimize.is_some() && bundle.svgl_stats.files > 0 {
+                    emit(format_args!("Optimized {} svgl logos: {}", bundle.svgl_stats.files, bundle.svgl_stats))?;
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+/// Write a line to stdout, surfacing broken pipes instead of panicking like `println!`
+fn emit(line: impl Display) -> io::Result<()> {
+    writeln!(io::stdout().lock(), "{}", line)
+}
+
+fn resolve(registry: &IconRegistry, id: &str, theme: Option<Theme>) -> Option<ResolvedIcon<'static>> {
+    match theme {
+        Some(theme) => registry.resolve_themed(id, theme),
+        None => registry.resolve(id),
     }
-    
-    println!("\n--- Next Steps ---");
-    println!("The converters are ready. Next, we'll:");
-    println!("• Implement FlatBuffers binary conversion");
-    println!("• Generate binary files at build time");
-    println!("• Add fast binary lookups");
-    println!("\nFor now, run the examples to see icon parsing in action!");
+}
+
+/// Ids given on the command line, or the names and globs picked from `set`; `None` when the set is unknown
+fn select_ids(registry: &IconRegistry, ids: &[String], set: Option<&str>, glob: &[String]) -> Option<Vec<String>> {
+    let Some(prefix) = set else {
+        return Some(ids.to_vec());
+    };
+    let selection = if !glob.is_empty() {
+        IconSelection::Globs(glob.to_vec())
+    } else if !ids.is_empty() {
+        IconSelection::Names(ids.to_vec())
+    } else {
+        IconSelection::All
+    };
+    selection.ids(registry, prefix)
+}
+
+fn found(any: bool) -> ExitCode {
+    if any {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_NOT_FOUND)
+    }
+}
+
+fn not_found(cli: &Cli, message: String) -> Result<ExitCode, Box<dyn Error>> {
+    if cli.json {
+        emit(json!({ "error": message }))?;
+    } else {
+        eprintln!("error: {}", message);
+    }
+    Ok(ExitCode::from(EXIT_NOT_FOUND))
+}
+
+fn icon_json(icon: &ResolvedIcon) -> Value {
+    json!({
+        "id": icon.id(),
+        "prefix": icon.prefix,
+        "name": icon.name,
+        "width": icon.width(),
+        "height": icon.height(),
+        "viewBox": icon.view_box.view_box(),
+        "license": icon.license.as_ref().map(|license| json!({
+            "title": license.title,
+            "spdx": license.spdx,
+            "url": license.url,
+        })),
+    })
+}
+
+fn stats_json(stats: &OptimizeStats) -> Value {
+    json!({
+        "files": stats.files,
+        "originalBytes": stats.original_bytes,
+        "optimizedBytes": stats.optimized_bytes,
+        "savedPercent": (stats.saved_percent() * 10.0).round() / 10.0,
+    })
+}
+
+fn set_info(registry: &IconRegistry, prefix: &str) -> Option<Value> {
+    if prefix == SVGL_PREFIX {
+        let svgl = registry.svgl()?;
+        return Some(json!({
+            "prefix": SVGL_PREFIX,
+            "name": "SVGL",
+            "total": svgl.len(),
+            "logos": svgl.entries().count(),
+        }));
+    }
+
+    let set = registry.icon_sets().get(prefix)?;
+    let view = set.view();
+    let info = set.icon_set().info();
+    let defaults = view.defaults();
+    Some(json!({
+        "prefix": set.prefix,
+        "name": set.name,
+        "total": set.total,
+        "version": info.and_then(|info| info.version()),
+        "author": info.and_then(|info| info.author()).and_then(|author| author.name()),
+        "license": info.and_then(|info| info.license()).and_then(|license| license.spdx()),
+        "size": format!("{}x{}", defaults.width, defaults.height),
+        "categories": view.categories().collect::<Vec<_>>(),
+        "samples": view.sample_icons().map(|icon| icon.name()).collect::<Vec<_>>(),
+        "tags": view.tags().collect::<Vec<_>>(),
+    }))
 }
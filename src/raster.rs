@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use resvg::tiny_skia::{Pixmap, Transform};
+use crate::converters::svgl::Theme;
+use crate::converters::utils::svg_with_xmlns;
+use crate::export::{unique_stem, ExportReport, Framework};
+use crate::registry::{IconRegistry, ResolvedIcon};
+
+/// PNG sizes written by `write_favicons`, besides `favicon.ico`
+pub const FAVICON_PNGS: [(&str, u32); 5] = [
+    ("favicon-16x16.png", 16),
+    ("favicon-32x32.png", 32),
+    ("apple-touch-icon.png", 180),
+    ("android-chrome-192x192.png", 192),
+    ("android-chrome-512x512.png", 512),
+];
+
+/// Sizes packed into `favicon.ico`
+pub const FAVICON_ICO_SIZES: [u32; 3] = [16, 32, 48];
+
+/// Render an icon to PNG, `size` CSS pixels tall at `scale` device pixels per CSS pixel.
+/// `color` replaces `currentColor`, which is otherwise drawn black.
+pub fn render_png(icon: &ResolvedIcon, size: u32, color: Option<&str>, scale: f32) -> Result<Vec<u8>, Box<dyn Error>> {
+    if size == 0 || !scale.is_finite() || scale <= 0.0 {
+        return Err(format!("invalid PNG size {} at scale {}", size, scale).into());
+    }
+
+    let tree = usvg::Tree::from_str(&svg_document(icon, color), &usvg::Options::default())?;
+    let view = tree.size();
+    let height = (size as f32 * scale).round().max(1.0);
+    let width = (height * view.width() / view.height()).round().max(1.0);
+
+    let mut pixmap = Pixmap::new(width as u32, height as u32)
+        .ok_or_else(|| format!("cannot allocate a {}x{} image", width, height))?;
+    let transform = Transform::from_scale(width / view.width(), height / view.height());
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    Ok(pixmap.encode_png()?)
+}
+
+/// Standalone SVG with `currentColor` resolved, as browsers would draw it in an `<img>`
+fn svg_document(icon: &ResolvedIcon, color: Option<&str>) -> String {
+    svg_with_xmlns(&icon.to_svg()).replace("currentColor", color.unwrap_or("#000"))
+}
+
+/// Options for `export_pngs`
+#[derive(Debug, Clone)]
+pub struct PngOptions {
+    /// Height in CSS pixels at 1x
+    pub size: u32,
+    pub color: Option<String>,
+    pub theme: Option<Theme>,
+    /// Pixel densities to write, as `name.png`, `name@2x.png`, `name@3x.png`, ...
+    pub scales: Vec<u32>,
+}
+
+impl Default for PngOptions {
+    fn default() -> Self {
+        PngOptions {
+            size: 24,
+            color: None,
+            theme: None,
+            scales: vec![1, 2, 3],
+        }
+    }
+}
+
+/// Render icons by `prefix:name` id to PNGs; file names include the prefix when ids span several sets.
+/// An image that fails to render is recorded in the report's warnings and the rest are still written.
+pub fn export_pngs(
+    registry: &IconRegistry,
+    ids: &[String],
+    options: &PngOptions,
+    out_dir: &Path,
+) -> Result<ExportReport, Box<dyn Error>> {
+    let mut report = ExportReport::default();
+    let mut icons = Vec::new();
+    for id in ids {
+        let icon = match options.theme {
+            Some(theme) => registry.resolve_themed(id, theme),
+            None => registry.resolve(id),
+        };
+        match icon {
+            Some(icon) => icons.push(icon),
+            None => report.missing.push(id.clone()),
+        }
+    }
+
+    let prefixed = icons.windows(2).any(|pair| pair[0].prefix != pair[1].prefix);
+    fs::create_dir_all(out_dir)?;
+    let mut used = HashSet::new();
+    for icon in &icons {
+        let stem = unique_stem(&mut used, icon, prefixed, Framework::Svg);
+        for &scale in &options.scales {
+            let file_name = match scale {
+                1 => format!("{}.png", stem),
+                _ => format!("{}@{}x.png", stem, scale),
+            };
+            let png = match render_png(icon, options.size, options.color.as_deref(), scale as f32) {
+                Ok(png) => png,
+                Err(error) => {
+                    report.warnings.push(format!("{}:{}: {}: {}", icon.prefix, icon.name, file_name, error));
+                    continue;
+                }
+            };
+            let path = out_dir.join(file_name);
+            fs::write(&path, png)?;
+            report.files.push(path);
+        }
+    }
+    Ok(report)
+}
+
+/// Write `favicon.ico`, `favicon.svg` and the PNG sizes browsers and home screens ask for
+pub fn write_favicons(icon: &ResolvedIcon, color: Option<&str>, out_dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    fs::create_dir_all(out_dir)?;
+    let mut files = Vec::new();
+
+    let svg = out_dir.join("favicon.svg");
+    fs::write(&svg, svg_document(icon, color))?;
+    files.push(svg);
+
+    let images = FAVICON_ICO_SIZES
+        .iter()
+        .map(|&size| Ok((size, render_png(icon, size, color, 1.0)?)))
+        .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+    let ico = out_dir.join("favicon.ico");
+    fs::write(&ico, ico_file(&images))?;
+    files.push(ico);
+
+    for (name, size) in FAVICON_PNGS {
+        let path = out_dir.join(name);
+        fs::write(&path, render_png(icon, size, color, 1.0)?)?;
+        files.push(path);
+    }
+    Ok(files)
+}
+
+/// Pack square PNGs into an ICO container, which has stored PNG data directly since Windows Vista
+pub fn ico_file(images: &[(u32, Vec<u8>)]) -> Vec<u8> {
+    let mut ico = Vec::new();
+    ico.extend_from_slice(&[0, 0, 1, 0]);
+    ico.extend_from_slice(&(images.len() as u16).to_le_bytes());
+
+    let mut offset = 6 + 16 * images.len();
+    for (size, png) in images {
+        // Sizes of 256 and up are written as 0
+        let dimension = if *size >= 256 { 0 } else { *size as u8 };
+        ico.extend_from_slice(&[dimension, dimension, 0, 0]);
+        ico.extend_from_slice(&1u16.to_le_bytes());
+        ico.extend_from_slice(&32u16.to_le_bytes());
+        ico.extend_from_slice(&(png.len() as u32).to_le_bytes());
+        ico.extend_from_slice(&(offset as u32).to_le_bytes());
+        offset += png.len();
+    }
+    for (_, png) in images {
+        ico.extend_from_slice(png);
+    }
+    ico
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_png() {
+        let registry = IconRegistry::new();
+        let home = registry.resolve("mdi:home").unwrap();
+
+        let png = render_png(&home, 24, Some("#ff0000"), 2.0).unwrap();
+        let pixmap = Pixmap::decode_png(&png).unwrap();
+        assert_eq!((pixmap.width(), pixmap.height()), (48, 48));
+        // The corner is empty and the wall left of the door is filled with the requested color
+        assert_eq!(pixmap.pixel(0, 0).unwrap().alpha(), 0);
+        let wall = pixmap.pixel(14, 36).unwrap();
+        assert_eq!((wall.red(), wall.green(), wall.blue(), wall.alpha()), (255, 0, 0, 255));
+        assert!(render_png(&home, 0, None, 1.0).is_err());
+
+        let ico = ico_file(&[(16, vec![1, 2]), (256, vec![3])]);
+        assert_eq!(&ico[..6], &[0, 0, 1, 0, 2, 0]);
+        assert_eq!(ico[22], 0);
+        assert_eq!(u32::from_le_bytes(ico[18..22].try_into().unwrap()), 38);
+        assert_eq!(&ico[38..], &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_export_pngs_continues_after_failure() {
+        let registry = IconRegistry::new();
+        let out = std::env::temp_dir().join(format!("dx-icon-png-test-{}", std::process::id()));
+        fs::remove_dir_all(&out).ok();
+
+        // The huge density can't be allocated; the other images are still written
+        let options = PngOptions { scales: vec![100_000_000, 1], ..Default::default() };
+        let ids = ["mdi:home".to_string(), "mdi:account".to_string()];
+        let report = export_pngs(&registry, &ids, &options, &out).unwrap();
+        assert_eq!(report.files, [out.join("home.png"), out.join("account.png")]);
+        assert_eq!(report.warnings.len(), 2);
+        assert!(report.warnings[0].starts_with("mdi:home: home@100000000x.png: cannot allocate"));
+        assert!(report.warnings[1].starts_with("mdi:account: account@100000000x.png: "));
+
+        fs::remove_dir_all(out).ok();
+    }
+}
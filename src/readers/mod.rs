@@ -1,6 +1,16 @@
 use walkdir::WalkDir;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use crate::converters::svgl::Theme;
+use crate::icon_generated::dx_icon::IconSet;
+
+mod view;
+
+pub use view::{IconRef, IconSetView, SvglCollectionView, SvglLogo, SvglVariants};
+
+pub(crate) mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/icon_sets.rs"));
+}
 
 /// Reader for icon-sets based icons (JSON sources)
 pub struct IconSetsReader {
@@ -12,15 +22,60 @@ pub struct IconSetData {
     pub name: String,
     pub total: u32,
     pub version: String,
+    set: IconSet<'static>,
+}
+
+impl IconSetData {
+    /// Parse an embedded FlatBuffers binary produced by `build.rs`
+    pub fn from_bytes(data: &'static [u8]) -> Result<Self, flatbuffers::InvalidFlatbuffer> {
+        let set = flatbuffers::root::<IconSet>(data)?;
+        let info = set.info();
+
+        Ok(IconSetData {
+            prefix: set.prefix().unwrap_or_default().to_string(),
+            name: info.and_then(|i| i.name()).unwrap_or_default().to_string(),
+            total: info.map(|i| i.total()).unwrap_or(0),
+            version: info.and_then(|i| i.version()).unwrap_or_default().to_string(),
+            set,
+        })
+    }
+
+    /// Access the underlying FlatBuffers table
+    pub fn icon_set(&self) -> IconSet<'static> {
+        self.set
+    }
+
+    /// Zero-copy view for allocation-free icon lookups
+    pub fn view(&self) -> IconSetView<'static> {
+        IconSetView::from_table(self.set)
+    }
+
+    pub fn icon(&self, name: &str) -> Option<IconRef<'static>> {
+        self.view().icon(name)
+    }
+
+    /// SVG body of an icon, without the surrounding `<svg>` element
+    pub fn icon_body(&self, name: &str) -> Option<&'static str> {
+        self.icon(name).map(|icon| icon.body())
+    }
 }
 
 impl IconSetsReader {
     pub fn new() -> Self {
-        // For now, return empty reader
-        // Later we'll load from embedded binaries
-        IconSetsReader {
-            icons: HashMap::new(),
+        let mut icons = HashMap::new();
+
+        for (prefix, data) in embedded::ICON_SETS {
+            match IconSetData::from_bytes(data) {
+                Ok(set) => {
+                    icons.insert(prefix.to_string(), set);
+                }
+                Err(e) => {
+                    eprintln!("Warning: Failed to load icon set {}: {}", prefix, e);
+                }
+            }
         }
+
+        IconSetsReader { icons }
     }
 
     pub fn get(&self, id: &str) -> Option<&IconSetData> {
@@ -30,6 +85,10 @@ impl IconSetsReader {
     pub fn list_sets(&self) -> Vec<&str> {
         self.icons.keys().map(|s| s.as_str()).collect()
     }
+
+    pub fn count(&self) -> usize {
+        self.icons.len()
+    }
 }
 
 impl Default for IconSetsReader {
@@ -38,6 +97,20 @@ impl Default for IconSetsReader {
     }
 }
 
+/// Zero-copy view over the embedded SVGL collection, if one was compiled
+pub fn svgl_collection() -> Option<SvglCollectionView<'static>> {
+    if embedded::SVGL.is_empty() {
+        return None;
+    }
+    match SvglCollectionView::from_bytes(embedded::SVGL) {
+        Ok(collection) => Some(collection),
+        Err(e) => {
+            eprintln!("Warning: Failed to load svgl collection: {}", e);
+            None
+        }
+    }
+}
+
 /// Reader for SVGL icons (SVG file sources)
 pub struct SvglReader {
     icons: HashMap<String, SvgIconData>,
@@ -51,11 +124,23 @@ pub struct SvgIconData {
 
 impl SvglReader {
     pub fn new() -> Self {
-        // For now, return empty reader
-        // We'll implement actual loading from embedded binaries
-        SvglReader {
-            icons: HashMap::new(),
+        let mut icons = HashMap::new();
+
+        if let Some(collection) = svgl_collection() {
+            for icon in collection.iter() {
+                let id = icon.id().to_string();
+                icons.insert(
+                    id.clone(),
+                    SvgIconData {
+                        id,
+                        filename: icon.filename().unwrap_or_default().to_string(),
+                        svg_content: icon.svg_content().unwrap_or_default().to_string(),
+                    },
+                );
+            }
         }
+
+        SvglReader { icons }
     }
 
     /// Load icons from the svgl directory for testing/building
@@ -94,6 +179,12 @@ impl SvglReader {
         self.icons.get(id)
     }
 
+    /// Themed variant of a logo, falling back to the neutral file and then the opposite theme
+    pub fn get_themed(&self, id: &str, theme: Theme) -> Option<&SvgIconData> {
+        let (base, _) = Theme::split_suffix(id);
+        theme.candidates(base).iter().find_map(|candidate| self.icons.get(candidate))
+    }
+
     pub fn list_icons(&self) -> Vec<&str> {
         self.icons.keys().map(|s| s.as_str()).collect()
     }
@@ -108,3 +199,20 @@ impl Default for SvglReader {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_icon_sets() {
+        let reader = IconSetsReader::new();
+        let mdi = reader.get("mdi").expect("mdi should be embedded");
+        assert_eq!(mdi.prefix, "mdi");
+        assert!(mdi.total > 0);
+        assert!(mdi.icon_body("home").is_some_and(|body| body.contains("<path")));
+        assert!(mdi.icon_body("definitely-not-an-icon").is_none());
+        assert_eq!(mdi.icon_body("123"), mdi.icon_body("numeric"));
+        assert!(mdi.icon("home").unwrap().to_svg().starts_with("<svg viewBox=\"0 0 24 24\""));
+    }
+}
@@ -0,0 +1,546 @@
+use flatbuffers::{ForwardsUOffset, Vector};
+use std::cmp::Ordering;
+use crate::converters::iconsets::{IconTransform, MAX_ALIAS_DEPTH};
+use crate::converters::render::{IconBox, RenderedIcon};
+use crate::converters::svgl::Theme;
+use crate::icon_generated::dx_icon::{Alias, Category, CharMapping, Icon, IconSet, SvglCollection, SvglEntry, SvglIcon, SvglRoute};
+
+/// Binary search a FlatBuffers vector sorted by its `(key)` field
+fn search_by_key<'a, T, F>(items: Vector<'a, ForwardsUOffset<T>>, compare: F) -> Option<T::Inner>
+where
+    T: flatbuffers::Follow<'a> + 'a,
+    F: Fn(&T::Inner) -> Ordering,
+{
+    let (mut low, mut high) = (0, items.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let item = items.get(mid);
+        match compare(&item) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Some(item),
+        }
+    }
+    None
+}
+
+/// Borrowed, allocation-free view over a serialized `IconSet`
+#[derive(Clone, Copy)]
+pub struct IconSetView<'a> {
+    set: IconSet<'a>,
+}
+
+/// Borrowed icon entry inside an `IconSetView`
+#[derive(Clone, Copy)]
+pub struct IconRef<'a> {
+    name: &'a str,
+    icon: Icon<'a>,
+    transform: IconTransform,
+    defaults: IconBox,
+}
+
+impl<'a> IconSetView<'a> {
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, flatbuffers::InvalidFlatbuffer> {
+        Ok(IconSetView { set: flatbuffers::root::<IconSet>(data)? })
+    }
+
+    pub fn from_table(set: IconSet<'a>) -> Self {
+        IconSetView { set }
+    }
+
+    pub fn table(&self) -> IconSet<'a> {
+        self.set
+    }
+
+    pub fn prefix(&self) -> &'a str {
+        self.set.prefix().unwrap_or_default()
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.set.info().and_then(|i| i.name()).unwrap_or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.set.icons().map_or(0, |icons| icons.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Set-level dimensions applied to icons that don't define their own
+    pub fn defaults(&self) -> IconBox {
+        IconBox::new(self.set.left(), self.set.top(), self.set.width(), self.set.height())
+    }
+
+    /// Find an icon or alias by name in O(log n) without allocating
+    pub fn icon(&self, name: &str) -> Option<IconRef<'a>> {
+        if let Some(icon) = self.find_icon(name) {
+            return Some(self.icon_ref(icon.id(), icon, icon_transform(&icon)));
+        }
+
+        let alias = self.find_alias(name)?;
+        let mut transform = IconTransform::default();
+        let mut current = alias;
+        for _ in 0..MAX_ALIAS_DEPTH {
+            // Aliases nearer the requested name override those further up the chain
+            transform = alias_transform(&current).merge(transform);
+            let parent = current.parent()?;
+            if let Some(icon) = self.find_icon(parent) {
+                return Some(self.icon_ref(alias.id(), icon, icon_transform(&icon).merge(transform)));
+            }
+            current = self.find_alias(parent)?;
+        }
+        None
+    }
+
+    /// Iterate over source icons, excluding aliases
+    pub fn iter(&self) -> impl Iterator<Item = IconRef<'a>> + 'a {
+        let view = *self;
+        self.set
+            .icons()
+            .into_iter()
+            .flatten()
+            .map(move |icon| view.icon_ref(icon.id(), icon, icon_transform(&icon)))
+    }
+
+    /// Iterate over alias names and the names they point to
+    pub fn aliases(&self) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+        self.set
+            .aliases()
+            .into_iter()
+            .flatten()
+            .map(|alias| (alias.id(), alias.parent().unwrap_or_default()))
+    }
+
+    /// Names of all categories defined by the set, in sorted order
+    pub fn categories(&self) -> impl Iterator<Item = &'a str> + 'a {
+        self.set.categories().into_iter().flatten().map(|category| category.name())
+    }
+
+    /// Icons listed under a category; empty when the category doesn't exist
+    pub fn icons_in_category(&self, category: &str) -> impl Iterator<Item = IconRef<'a>> + 'a {
+        let view = *self;
+        self.find_category(category)
+            .and_then(|category| category.icons())
+            .into_iter()
+            .flatten()
+            .filter_map(move |name| view.icon(name))
+    }
+
+    /// Icon mapped to a single icon-font codepoint
+    pub fn icon_by_char(&self, code: u32) -> Option<IconRef<'a>> {
+        self.icon_by_chars(&format!("{:x}", code))
+    }
+
+    /// Icon mapped to a codepoint sequence such as `1f9d1-200d-1f3a8`
+    pub fn icon_by_chars(&self, chars: &str) -> Option<IconRef<'a>> {
+        let mapping = self.find_char(chars)?;
+        self.icon(mapping.icon()?)
+    }
+
+    /// Iterate over codepoint sequences and the icon names they map to
+    pub fn chars(&self) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+        self.set
+            .chars()
+            .into_iter()
+            .flatten()
+            .map(|mapping| (mapping.chars(), mapping.icon().unwrap_or_default()))
+    }
+
+    /// Icons the set author picked as a preview of the set
+    pub fn sample_icons(&self) -> impl Iterator<Item = IconRef<'a>> + 'a {
+        let view = *self;
+        self.set
+            .info()
+            .and_then(|info| info.samples())
+            .into_iter()
+            .flatten()
+            .filter_map(move |name| view.icon(name))
+    }
+
+    pub fn tags(&self) -> impl Iterator<Item = &'a str> + 'a {
+        self.set.info().and_then(|info| info.tags()).into_iter().flatten()
+    }
+
+    fn icon_ref(&self, name: &'a str, icon: Icon<'a>, transform: IconTransform) -> IconRef<'a> {
+        IconRef { name, icon, transform, defaults: self.defaults() }
+    }
+
+    fn find_icon(&self, name: &str) -> Option<Icon<'a>> {
+        search_by_key(self.set.icons()?, |icon| icon.key_compare_with_value(name))
+    }
+
+    fn find_alias(&self, name: &str) -> Option<Alias<'a>> {
+        search_by_key(self.set.aliases()?, |alias| alias.key_compare_with_value(name))
+    }
+
+    fn find_category(&self, name: &str) -> Option<Category<'a>> {
+        search_by_key(self.set.categories()?, |category| category.key_compare_with_value(name))
+    }
+
+    fn find_char(&self, chars: &str) -> Option<CharMapping<'a>> {
+        search_by_key(self.set.chars()?, |mapping| mapping.key_compare_with_value(chars))
+    }
+}
+
+fn icon_transform(icon: &Icon) -> IconTransform {
+    IconTransform {
+        rotate: icon.rotate() % 4,
+        h_flip: icon.h_flip(),
+        v_flip: icon.v_flip(),
+        width: icon.width(),
+        height: icon.height(),
+        left: icon.left(),
+        top: icon.top(),
+        hidden: icon.hidden().then_some(true),
+    }
+}
+
+fn alias_transform(alias: &Alias) -> IconTransform {
+    IconTransform {
+        rotate: alias.rotate() % 4,
+        h_flip: alias.h_flip(),
+        v_flip: alias.v_flip(),
+        width: alias.width(),
+        height: alias.height(),
+        left: alias.left(),
+        top: alias.top(),
+        hidden: alias.hidden(),
+    }
+}
+
+impl<'a> IconRef<'a> {
+    /// Name this icon was looked up by, which may be an alias
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Name of the source icon holding the body
+    pub fn source_name(&self) -> &'a str {
+        self.icon.id()
+    }
+
+    pub fn is_alias(&self) -> bool {
+        self.name != self.icon.id()
+    }
+
+    /// Rotation, flips and dimensions of the icon merged with those along the alias chain
+    pub fn transform(&self) -> IconTransform {
+        self.transform
+    }
+
+    pub fn body(&self) -> &'a str {
+        self.icon.body().unwrap_or_default()
+    }
+
+    /// Explicit width from the icon or an alias, or `None` when the set default applies
+    pub fn width(&self) -> Option<f32> {
+        self.transform.width
+    }
+
+    /// Explicit height from the icon or an alias, or `None` when the set default applies
+    pub fn height(&self) -> Option<f32> {
+        self.transform.height
+    }
+
+    pub fn left(&self) -> Option<f32> {
+        self.transform.left
+    }
+
+    pub fn top(&self) -> Option<f32> {
+        self.transform.top
+    }
+
+    pub fn hidden(&self) -> bool {
+        self.transform.hidden.unwrap_or(false)
+    }
+
+    /// Icon dimensions, falling back to the set-level defaults
+    pub fn icon_box(&self) -> IconBox {
+        self.transform.icon_box(self.defaults)
+    }
+
+    /// Body and viewBox with all transforms applied
+    pub fn render(&self) -> RenderedIcon {
+        RenderedIcon::new(self.body(), self.icon_box(), self.transform)
+    }
+
+    pub fn to_svg(&self) -> String {
+        self.render().to_svg()
+    }
+
+    pub fn table(&self) -> Icon<'a> {
+        self.icon
+    }
+}
+
+/// Borrowed, allocation-free view over a serialized `SvglCollection`
+#[derive(Clone, Copy)]
+pub struct SvglCollectionView<'a> {
+    collection: SvglCollection<'a>,
+}
+
+impl<'a> SvglCollectionView<'a> {
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, flatbuffers::InvalidFlatbuffer> {
+        Ok(SvglCollectionView { collection: flatbuffers::root::<SvglCollection>(data)? })
+    }
+
+    pub fn len(&self) -> usize {
+        self.collection.icons().map_or(0, |icons| icons.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn icon(&self, id: &str) -> Option<SvglIcon<'a>> {
+        let icons = self.collection.icons()?;
+        search_by_key(icons, |icon| icon.key_compare_with_value(id))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = SvglIcon<'a>> + 'a {
+        self.collection.icons().into_iter().flatten()
+    }
+
+    /// Logo metadata by id, e.g. `nvidia-icon`
+    pub fn entry(&self, id: &str) -> Option<SvglEntry<'a>> {
+        let entries = self.collection.entries()?;
+        search_by_key(entries, |entry| entry.key_compare_with_value(id))
+    }
+
+    /// Logo metadata by its case-insensitive display title, e.g. `NVIDIA`
+    pub fn entry_by_title(&self, title: &str) -> Option<SvglEntry<'a>> {
+        self.entries()
+            .find(|entry| entry.title().is_some_and(|t| t.eq_ignore_ascii_case(title)))
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = SvglEntry<'a>> + 'a {
+        self.collection.entries().into_iter().flatten()
+    }
+
+    /// Icon files behind a route from svgs.ts
+    pub fn route_variants(&self, route: SvglRoute<'a>) -> SvglVariants<'a> {
+        SvglVariants {
+            default: route.icon().and_then(|id| self.icon(id)),
+            light: route.light().and_then(|id| self.icon(id)),
+            dark: route.dark().and_then(|id| self.icon(id)),
+        }
+    }
+
+    /// Logical logo for an entry id or file stem, grouping its light and dark files
+    pub fn logo(&self, name: &str) -> Option<SvglLogo<'a>> {
+        let (base, _) = Theme::split_suffix(name);
+        if let Some(entry) = self.entry(base) {
+            return Some(SvglLogo {
+                id: base.to_string(),
+                entry: Some(entry),
+                icon: entry.route().map(|route| self.route_variants(route)).unwrap_or_default(),
+                wordmark: entry.wordmark().map(|route| self.route_variants(route)),
+            });
+        }
+
+        // Logos without metadata are paired purely by their file names
+        let themed = |theme: Theme| {
+            ['-', '_']
+                .iter()
+                .find_map(|separator| self.icon(&format!("{}{}{}", base, separator, theme.as_str())))
+        };
+        let icon = SvglVariants {
+            default: self.icon(base),
+            light: themed(Theme::Light),
+            dark: themed(Theme::Dark),
+        };
+        if icon.is_empty() {
+            return None;
+        }
+        Some(SvglLogo { id: base.to_string(), entry: None, icon, wordmark: None })
+    }
+}
+
+/// Light, dark and theme-neutral files for one logo or wordmark
+#[derive(Clone, Copy, Default)]
+pub struct SvglVariants<'a> {
+    pub default: Option<SvglIcon<'a>>,
+    pub light: Option<SvglIcon<'a>>,
+    pub dark: Option<SvglIcon<'a>>,
+}
+
+impl<'a> SvglVariants<'a> {
+    /// File for a theme, falling back to the neutral file and then the opposite theme
+    pub fn get(&self, theme: Theme) -> Option<SvglIcon<'a>> {
+        self.exact(theme).or(self.default).or_else(|| self.exact(theme.opposite()))
+    }
+
+    pub fn exact(&self, theme: Theme) -> Option<SvglIcon<'a>> {
+        match theme {
+            Theme::Light => self.light,
+            Theme::Dark => self.dark,
+        }
+    }
+
+    /// Whether both a light and a dark file exist
+    pub fn is_themed(&self) -> bool {
+        self.light.is_some() && self.dark.is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.light.is_none() && self.dark.is_none()
+    }
+}
+
+/// One svgl logo with its themed icon and wordmark files
+#[derive(Clone)]
+pub struct SvglLogo<'a> {
+    id: String,
+    entry: Option<SvglEntry<'a>>,
+    icon: SvglVariants<'a>,
+    wordmark: Option<SvglVariants<'a>>,
+}
+
+impl<'a> SvglLogo<'a> {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Display title from svgs.ts, or the id when the logo has no metadata
+    pub fn title(&self) -> &str {
+        self.entry.and_then(|entry| entry.title()).unwrap_or(&self.id)
+    }
+
+    pub fn entry(&self) -> Option<SvglEntry<'a>> {
+        self.entry
+    }
+
+    pub fn icon(&self, theme: Theme) -> Option<SvglIcon<'a>> {
+        self.icon.get(theme)
+    }
+
+    pub fn wordmark(&self, theme: Theme) -> Option<SvglIcon<'a>> {
+        self.wordmark.as_ref()?.get(theme)
+    }
+
+    pub fn icon_variants(&self) -> SvglVariants<'a> {
+        self.icon
+    }
+
+    pub fn wordmark_variants(&self) -> Option<SvglVariants<'a>> {
+        self.wordmark
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converters::iconsets::IconSetJson;
+
+    #[test]
+    fn test_icon_lookup_by_key() {
+        let json = r#"{
+            "prefix": "test",
+            "info": { "name": "Test Icons", "total": 3 },
+            "icons": {
+                "b": { "body": "<path d='B'/>" },
+                "a": { "body": "<path d='A'/>", "width": 24 },
+                "c": { "body": "<path d='C'/>" }
+            }
+        }"#;
+        let iconset: IconSetJson = serde_json::from_str(json).unwrap();
+        let data = iconset.to_flatbuffer();
+        let view = IconSetView::from_bytes(&data).unwrap();
+
+        assert_eq!(view.prefix(), "test");
+        assert_eq!(view.len(), 3);
+        for name in ["a", "b", "c"] {
+            assert_eq!(view.icon(name).unwrap().name(), name);
+        }
+        assert_eq!(view.icon("a").unwrap().width(), Some(24.0));
+        assert_eq!(view.icon("b").unwrap().width(), None);
+        assert!(view.icon("0").is_none());
+        assert!(view.icon("d").is_none());
+    }
+
+    #[test]
+    fn test_alias_resolution() {
+        let json = r#"{
+            "prefix": "test",
+            "info": { "name": "Test Icons", "total": 1 },
+            "icons": { "arrow": { "body": "<path d='M0 0'/>" } },
+            "aliases": {
+                "arrow-down": { "parent": "arrow-right", "rotate": 1 },
+                "arrow-right": { "parent": "arrow", "vFlip": true },
+                "arrow-wide": { "parent": "arrow-right", "width": 32, "left": -4, "hidden": true },
+                "broken": { "parent": "missing" }
+            }
+        }"#;
+        let iconset: IconSetJson = serde_json::from_str(json).unwrap();
+        let data = iconset.to_flatbuffer();
+        let view = IconSetView::from_bytes(&data).unwrap();
+
+        let icon = view.icon("arrow-down").unwrap();
+        assert!(icon.is_alias());
+        assert_eq!(icon.name(), "arrow-down");
+        assert_eq!(icon.source_name(), "arrow");
+        assert_eq!(icon.body(), "<path d='M0 0'/>");
+        assert_eq!(icon.transform(), IconTransform { rotate: 1, v_flip: true, ..Default::default() });
+        assert_eq!(icon.width(), None);
+
+        // Alias dimensions replace the parent's
+        let wide = view.icon("arrow-wide").unwrap();
+        assert_eq!((wide.width(), wide.height(), wide.left()), (Some(32.0), None, Some(-4.0)));
+        assert!(wide.hidden());
+        assert_eq!(wide.to_svg(), iconset.icon_svg("arrow-wide").unwrap());
+        assert!(wide.to_svg().starts_with("<svg viewBox=\"0 0 32 16\" width=\"32\" height=\"16\""));
+
+        assert!(view.icon("broken").is_none());
+        assert_eq!(view.aliases().count(), 4);
+    }
+
+    #[test]
+    fn test_browse_metadata() {
+        let json = r#"{
+            "prefix": "test",
+            "info": { "name": "Test Icons", "total": 2, "samples": ["cat"], "tags": ["Animals"] },
+            "icons": {
+                "cat": { "body": "<path d='C'/>" },
+                "dog": { "body": "<path d='D'/>" }
+            },
+            "categories": { "Animal": ["cat", "dog"], "Pets": ["dog"] },
+            "chars": { "f001": "cat", "1f415-200d-1f9ba": "dog" }
+        }"#;
+        let iconset: IconSetJson = serde_json::from_str(json).unwrap();
+        let data = iconset.to_flatbuffer();
+        let view = IconSetView::from_bytes(&data).unwrap();
+
+        assert_eq!(view.categories().collect::<Vec<_>>(), ["Animal", "Pets"]);
+        let animals: Vec<_> = view.icons_in_category("Animal").map(|icon| icon.name()).collect();
+        assert_eq!(animals, ["cat", "dog"]);
+        assert_eq!(view.icons_in_category("Plants").count(), 0);
+        assert_eq!(view.icon_by_char(0xF001).unwrap().name(), "cat");
+        assert_eq!(view.icon_by_chars("1f415-200d-1f9ba").unwrap().name(), "dog");
+        assert!(view.icon_by_char(0xF002).is_none());
+        assert_eq!(view.sample_icons().map(|icon| icon.name()).collect::<Vec<_>>(), ["cat"]);
+        assert_eq!(view.tags().collect::<Vec<_>>(), ["Animals"]);
+    }
+
+    #[test]
+    fn test_svgl_entries() {
+        let svgl = crate::readers::svgl_collection().unwrap();
+        let nvidia = svgl.entry_by_title("nvidia").unwrap();
+        assert_eq!(nvidia.id(), "nvidia-icon");
+        assert!(svgl.entry("nvidia-icon").is_some());
+        assert!(nvidia.brand_url().is_some());
+
+        let logo = svgl.logo("nvidia-icon").unwrap();
+        assert_eq!(logo.title(), "NVIDIA");
+        assert_eq!(logo.wordmark(Theme::Dark).unwrap().id(), "nvidia-wordmark-dark");
+        assert_eq!(logo.wordmark(Theme::Light).unwrap().id(), "nvidia-wordmark-light");
+        assert!(logo.icon_variants().is_themed());
+
+        // Logos are grouped by file name too, falling back to the other theme when one is missing
+        let github = svgl.logo("github_dark").unwrap();
+        assert_eq!(github.icon(Theme::Light).unwrap().id(), "github_light");
+        let single = SvglVariants { dark: svgl.icon("github_dark"), ..Default::default() };
+        assert_eq!(single.get(Theme::Light).unwrap().id(), "github_dark");
+    }
+}
@@ -0,0 +1,221 @@
+use std::borrow::Cow;
+use crate::converters::optimize::{optimize_svg, OptimizeOptions, OptimizeStats};
+use crate::converters::render::{IconBox, RenderedIcon};
+use crate::converters::svgl::Theme;
+use crate::converters::utils::svg_inner_content;
+use crate::icon_generated::dx_icon::SvglIcon;
+use crate::readers::{svgl_collection, IconSetsReader, SvglCollectionView};
+
+/// Prefix used to address SVGL logos, as in `svgl:github-dark`
+pub const SVGL_PREFIX: &str = "svgl";
+
+/// Which library a resolved icon came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconSource {
+    IconSet,
+    Svgl,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IconLicense<'a> {
+    pub title: &'a str,
+    pub spdx: &'a str,
+    pub url: Option<&'a str>,
+}
+
+/// Icon data in a common shape regardless of the library it came from
+#[derive(Debug, Clone)]
+pub struct ResolvedIcon<'a> {
+    pub source: IconSource,
+    pub prefix: &'a str,
+    pub name: &'a str,
+    /// Markup inside the root `<svg>` element
+    pub body: Cow<'a, str>,
+    pub view_box: IconBox,
+    pub license: Option<IconLicense<'a>>,
+    /// Whether the icon has its own colors instead of drawing with `currentColor`
+    pub palette: bool,
+    document: Option<Cow<'a, str>>,
+}
+
+impl<'a> ResolvedIcon<'a> {
+    /// Full `prefix:name` identifier
+    pub fn id(&self) -> String {
+        format!("{}:{}", self.prefix, self.name)
+    }
+
+    pub fn width(&self) -> f32 {
+        self.view_box.width
+    }
+
+    pub fn height(&self) -> f32 {
+        self.view_box.height
+    }
+
+    /// Complete SVG document for this icon
+    pub fn to_svg(&self) -> Cow<'a, str> {
+        match &self.document {
+            Some(document) => document.clone(),
+            None => Cow::Owned(
+                RenderedIcon { body: self.body.to_string(), view_box: self.view_box }.to_svg(),
+            ),
+        }
+    }
+
+    /// Run the SVG optimizer over this icon's markup, returning the size of its document before and after
+    pub fn optimize(&mut self, options: &OptimizeOptions) -> OptimizeStats {
+        let original = self.to_svg().len();
+        match &self.document {
+            Some(document) => {
+                let (optimized, _) = optimize_svg(document, options);
+                self.body = Cow::Owned(svg_inner_content(&optimized).to_string());
+                self.document = Some(Cow::Owned(optimized));
+            }
+            None => self.body = Cow::Owned(optimize_svg(&self.body, options).0),
+        }
+        OptimizeStats { files: 1, original_bytes: original, optimized_bytes: self.to_svg().len() }
+    }
+}
+
+/// Single entry point for resolving `prefix:name` icons across all libraries
+pub struct IconRegistry {
+    sets: IconSetsReader,
+    svgl: Option<SvglCollectionView<'static>>,
+}
+
+impl IconRegistry {
+    pub fn new() -> Self {
+        IconRegistry {
+            sets: IconSetsReader::new(),
+            svgl: svgl_collection(),
+        }
+    }
+
+    /// Resolve an icon from a `prefix:name` identifier
+    pub fn resolve(&self, id: &str) -> Option<ResolvedIcon<'static>> {
+        let (prefix, name) = id.split_once(':')?;
+        self.resolve_parts(prefix, name)
+    }
+
+    pub fn resolve_parts(&self, prefix: &str, name: &str) -> Option<ResolvedIcon<'static>> {
+        if prefix == SVGL_PREFIX {
+            let svgl = self.svgl?;
+            // SVGL file names mix `-` and `_` before the theme, e.g. `1password-dark` and `github_dark`;
+            // the base name itself is kept as written
+            let (base, theme) = Theme::split_suffix(name);
+            let stems = theme.map(|theme| [format!("{}-{}", base, theme.as_str()), format!("{}_{}", base, theme.as_str())]);
+            return svgl
+                .icon(name)
+                .or_else(|| stems.iter().flatten().find_map(|stem| svgl.icon(stem)))
+                .or_else(|| svgl.logo(name)?.icon(Theme::Light))
+                .map(resolve_svgl);
+        }
+
+        let set = self.sets.get(prefix)?;
+        let view = set.view();
+        let icon = view.icon(name)?;
+        let rendered = icon.render();
+        let info = view.table().info();
+        let license = info.and_then(|info| info.license()).map(|license| IconLicense {
+            title: license.title().unwrap_or_default(),
+            spdx: license.spdx().unwrap_or_default(),
+            url: license.url(),
+        });
+
+        Some(ResolvedIcon {
+            source: IconSource::IconSet,
+            prefix: view.prefix(),
+            name: icon.name(),
+            body: Cow::Owned(rendered.body),
+            view_box: rendered.view_box,
+            license,
+            palette: info.is_some_and(|info| info.palette()),
+            document: None,
+        })
+    }
+
+    /// Resolve an icon for a color scheme; SVGL logos pick their light or dark variant
+    pub fn resolve_themed(&self, id: &str, theme: Theme) -> Option<ResolvedIcon<'static>> {
+        let (prefix, name) = id.split_once(':')?;
+        if prefix == SVGL_PREFIX {
+            return self.svgl?.logo(name)?.icon(theme).map(resolve_svgl);
+        }
+        self.resolve_parts(prefix, name)
+    }
+
+    /// All known prefixes, including `svgl` when the logo collection is available
+    pub fn prefixes(&self) -> Vec<&str> {
+        let mut prefixes = self.sets.list_sets();
+        if self.svgl.is_some() {
+            prefixes.push(SVGL_PREFIX);
+        }
+        prefixes.sort();
+        prefixes
+    }
+
+    pub fn icon_sets(&self) -> &IconSetsReader {
+        &self.sets
+    }
+
+    pub fn svgl(&self) -> Option<SvglCollectionView<'static>> {
+        self.svgl
+    }
+}
+
+impl Default for IconRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn resolve_svgl(icon: SvglIcon<'static>) -> ResolvedIcon<'static> {
+    let content = icon.svg_content().unwrap_or_default();
+    let view_box = icon
+        .viewbox()
+        .and_then(IconBox::parse_view_box)
+        .unwrap_or_else(|| IconBox::new(0.0, 0.0, icon.width() as f32, icon.height() as f32));
+
+    ResolvedIcon {
+        source: IconSource::Svgl,
+        prefix: SVGL_PREFIX,
+        name: icon.id(),
+        body: Cow::Borrowed(svg_inner_content(content)),
+        view_box,
+        license: None,
+        palette: true,
+        document: Some(Cow::Borrowed(content)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_across_sources() {
+        let registry = IconRegistry::new();
+
+        let home = registry.resolve("mdi:home").unwrap();
+        assert_eq!(home.source, IconSource::IconSet);
+        assert_eq!(home.id(), "mdi:home");
+        assert_eq!(home.view_box, IconBox::new(0.0, 0.0, 24.0, 24.0));
+        assert_eq!(home.license.as_ref().unwrap().spdx, "Apache-2.0");
+        assert!(!home.palette);
+        assert!(home.to_svg().starts_with("<svg viewBox=\"0 0 24 24\""));
+
+        let github = registry.resolve("svgl:github-dark").unwrap();
+        assert_eq!(github.source, IconSource::Svgl);
+        assert_eq!(github.name, "github_dark");
+        assert!(github.palette);
+        assert!(!github.body.starts_with("<svg"));
+        assert!(github.to_svg().contains("<svg"));
+        assert_eq!(registry.resolve("svgl:github").unwrap().name, "github_light");
+        assert_eq!(registry.resolve("svgl:apache-kafka_dark").unwrap().name, "apache-kafka-dark");
+        assert!(registry.resolve("svgl:apache_kafka-dark").is_none());
+        assert_eq!(registry.resolve_themed("svgl:github", Theme::Dark).unwrap().name, "github_dark");
+
+        assert!(registry.resolve("mdi:not-an-icon").is_none());
+        assert!(registry.resolve("nope:home").is_none());
+        assert!(registry.resolve("home").is_none());
+    }
+}
@@ -0,0 +1,381 @@
+use std::collections::{HashMap, HashSet};
+use crate::converters::search::tokenize;
+use crate::icon_generated::dx_icon::{SearchField, SearchIndex, SearchTerm};
+use crate::readers::embedded;
+
+/// Score for a query token that equals an indexed term
+const EXACT_SCORE: f32 = 1.0;
+/// Score for a query token that is a prefix of an indexed term
+const PREFIX_SCORE: f32 = 0.75;
+/// Score for an unknown query token one and two edits away from an indexed term
+const FUZZY_SCORES: [f32; 2] = [0.5, 0.3];
+/// Weight applied when a token only matches the set (name, prefix, category, tags)
+const SET_WEIGHT: f32 = 0.4;
+/// Bonus when the whole query equals, or starts, the icon name
+const NAME_EXACT_BONUS: f32 = 1.0;
+const NAME_PREFIX_BONUS: f32 = 0.25;
+
+fn field_weight(field: SearchField) -> f32 {
+    match field {
+        SearchField::Name => 1.0,
+        SearchField::Alias => 0.8,
+        _ => 0.5,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    /// Only return icons from these prefixes; empty searches everything
+    pub prefixes: Vec<String>,
+    pub limit: usize,
+    /// Allow matches one or two edits away from the query
+    pub fuzzy: bool,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        SearchOptions {
+            prefixes: Vec::new(),
+            limit: 50,
+            fuzzy: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit<'a> {
+    pub prefix: &'a str,
+    pub name: &'a str,
+    pub score: f32,
+}
+
+impl SearchHit<'_> {
+    /// Full `prefix:name` identifier, resolvable through `IconRegistry`
+    pub fn id(&self) -> String {
+        format!("{}:{}", self.prefix, self.name)
+    }
+}
+
+/// Best score per icon and per set for a single query token, keyed by their
+/// positions in `SearchIndex.icons` and `SearchIndex.sets`
+#[derive(Default)]
+struct TokenMatches {
+    icons: HashMap<u32, f32>,
+    sets: HashMap<u16, f32>,
+}
+
+impl TokenMatches {
+    fn add(&mut self, term: SearchTerm, score: f32) {
+        if let (Some(icons), Some(fields)) = (term.icons(), term.fields()) {
+            for (icon, field) in icons.iter().zip(fields.iter()) {
+                let best = self.icons.entry(icon).or_insert(0.0);
+                *best = best.max(score * field_weight(field));
+            }
+        }
+        for set in term.sets().into_iter().flatten() {
+            let best = self.sets.entry(set).or_insert(0.0);
+            *best = best.max(score * SET_WEIGHT);
+        }
+    }
+
+    /// Score for an icon, through its own terms or through its set
+    fn score(&self, icon: u32, set: u16) -> Option<f32> {
+        let score = self.icons.get(&icon).copied().unwrap_or(0.0).max(self.sets.get(&set).copied().unwrap_or(0.0));
+        (score > 0.0).then_some(score)
+    }
+}
+
+/// Ranked fuzzy search over the index precomputed by `build.rs`
+pub struct IconSearch<'a> {
+    index: Option<SearchIndex<'a>>,
+}
+
+impl<'a> IconSearch<'a> {
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, flatbuffers::InvalidFlatbuffer> {
+        Ok(IconSearch { index: Some(flatbuffers::root::<SearchIndex>(data)?) })
+    }
+
+    pub fn search(&self, query: &str, options: &SearchOptions) -> Vec<SearchHit<'a>> {
+        let Some(index) = self.index else {
+            return Vec::new();
+        };
+        let (Some(sets), Some(icons)) = (index.sets(), index.icons()) else {
+            return Vec::new();
+        };
+
+        let tokens: Vec<String> = tokenize(query).collect();
+        if tokens.is_empty() {
+            return Vec::new();
+        }
+        let matches: Vec<TokenMatches> = tokens
+            .iter()
+            .map(|t| self.match_token(t, options.fuzzy))
+            .collect();
+
+        let allowed_sets: Option<HashSet<u16>> = (!options.prefixes.is_empty()).then(|| {
+            sets.iter()
+                .enumerate()
+                .filter(|(_, set)| options.prefixes.iter().any(|p| Some(p.as_str()) == set.prefix()))
+                .map(|(i, _)| i as u16)
+                .collect()
+        });
+
+        // Tokens that can also match a whole set can't narrow the candidates,
+        // so start from the smallest icon-only token
+        let candidates: Vec<u32> = match matches.iter().filter(|m| m.sets.is_empty()).min_by_key(|m| m.icons.len()) {
+            Some(narrowest) => narrowest.icons.keys().copied().collect(),
+            None => {
+                // Every token names a set, as in `mdi` or `material design`: browse the sets they all match
+                let browsed: HashSet<u16> = matches[0]
+                    .sets
+                    .keys()
+                    .copied()
+                    .filter(|set| matches.iter().all(|m| m.sets.contains_key(set)))
+                    .collect();
+                let mut all: Vec<u32> = matches.iter().flat_map(|m| m.icons.keys().copied()).collect();
+                if !browsed.is_empty() {
+                    all.extend((0..icons.len() as u32).filter(|&i| browsed.contains(&icons.get(i as usize).set())));
+                }
+                all.sort_unstable();
+                all.dedup();
+                all
+            }
+        };
+
+        let mut hits = Vec::new();
+        for icon_index in candidates {
+            let icon = icons.get(icon_index as usize);
+            let set = icon.set();
+            if allowed_sets.as_ref().is_some_and(|allowed| !allowed.contains(&set)) {
+                continue;
+            }
+
+            let mut score = 0.0;
+            let mut matched_all = true;
+            for token in &matches {
+                match token.score(icon_index, set) {
+                    Some(token_score) => score += token_score,
+                    None => {
+                        matched_all = false;
+                        break;
+                    }
+                }
+            }
+            if !matched_all {
+                continue;
+            }
+
+            let name = icon.name().unwrap_or_default();
+            score += name_bonus(name, &tokens);
+
+            hits.push(SearchHit {
+                prefix: sets.get(set as usize).prefix().unwrap_or_default(),
+                name,
+                score,
+            });
+        }
+
+        let rank = |a: &SearchHit, b: &SearchHit| {
+            b.score
+                .total_cmp(&a.score)
+                .then(a.name.len().cmp(&b.name.len()))
+                .then(a.name.cmp(b.name))
+                .then(a.prefix.cmp(b.prefix))
+        };
+        // Only the top results need a full sort
+        if hits.len() > options.limit && options.limit > 0 {
+            hits.select_nth_unstable_by(options.limit - 1, rank);
+        }
+        hits.truncate(options.limit);
+        hits.sort_by(rank);
+        hits
+    }
+
+    fn match_token(&self, token: &str, fuzzy: bool) -> TokenMatches {
+        let mut matches = TokenMatches::default();
+        let Some(terms) = self.index.and_then(|index| index.terms()) else {
+            return matches;
+        };
+
+        // Terms are sorted, so the exact match and all prefix matches are contiguous
+        let start = lower_bound(&terms, token);
+        let allow_prefix = token.chars().count() >= 2;
+        let mut exact = false;
+        for i in start..terms.len() {
+            let term = terms.get(i);
+            if term.term() == token {
+                matches.add(term, EXACT_SCORE);
+                exact = true;
+            } else if allow_prefix && term.term().starts_with(token) {
+                matches.add(term, PREFIX_SCORE);
+            } else {
+                break;
+            }
+        }
+
+        // Typo tolerance only kicks in for tokens that aren't a known term themselves
+        let token_len = token.chars().count();
+        if fuzzy && !exact && token_len >= 4 {
+            let max_distance = if token_len >= 8 { 2 } else { 1 };
+            let mut matcher = FuzzyMatcher::new(token, max_distance);
+            for term in terms.iter() {
+                let text = term.term();
+                // Cheap length check before the edit distance; byte length equals
+                // character count for the ASCII names that make up nearly all terms
+                if text.starts_with(token) || (text.len().abs_diff(token.len()) > max_distance && text.is_ascii()) {
+                    continue;
+                }
+                if let Some(distance) = matcher.distance(text) {
+                    matches.add(term, FUZZY_SCORES[distance - 1]);
+                }
+            }
+        }
+
+        matches
+    }
+}
+
+impl IconSearch<'static> {
+    /// Search index embedded at build time
+    pub fn embedded() -> Self {
+        IconSearch::from_bytes(embedded::SEARCH_INDEX).unwrap_or_else(|e| {
+            eprintln!("Warning: Failed to load search index: {}", e);
+            IconSearch { index: None }
+        })
+    }
+}
+
+/// Bonus for names that equal or start with the whole query, compared token by token
+fn name_bonus(name: &str, tokens: &[String]) -> f32 {
+    let mut parts = name.split(|c: char| !c.is_alphanumeric()).filter(|part| !part.is_empty());
+    for (i, token) in tokens.iter().enumerate() {
+        let Some(part) = parts.next() else {
+            return 0.0;
+        };
+        if part.eq_ignore_ascii_case(token) {
+            continue;
+        }
+        let is_last = i + 1 == tokens.len();
+        let starts_with = part.len() > token.len()
+            && part.is_char_boundary(token.len())
+            && part[..token.len()].eq_ignore_ascii_case(token);
+        return if is_last && starts_with { NAME_PREFIX_BONUS } else { 0.0 };
+    }
+    if parts.next().is_none() {
+        NAME_EXACT_BONUS
+    } else {
+        NAME_PREFIX_BONUS
+    }
+}
+
+fn lower_bound(terms: &flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SearchTerm<'_>>>, token: &str) -> usize {
+    let (mut low, mut high) = (0, terms.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if terms.get(mid).term() < token {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Bounded edit distance against a fixed query token, reusing its scratch rows across terms
+struct FuzzyMatcher {
+    token: Vec<char>,
+    max: usize,
+    term: Vec<char>,
+    rows: [Vec<usize>; 3],
+}
+
+impl FuzzyMatcher {
+    fn new(token: &str, max: usize) -> Self {
+        FuzzyMatcher {
+            token: token.chars().collect(),
+            max,
+            term: Vec::new(),
+            rows: [Vec::new(), Vec::new(), Vec::new()],
+        }
+    }
+
+    /// Edit distance to `term` counting adjacent transpositions as one edit,
+    /// or `None` if it is 0 or exceeds the bound
+    fn distance(&mut self, term: &str) -> Option<usize> {
+        let (a, max) = (&self.token, self.max);
+        self.term.clear();
+        self.term.extend(term.chars());
+        let b = &self.term;
+        if a.len().abs_diff(b.len()) > max {
+            return None;
+        }
+
+        let [before, previous, current] = &mut self.rows;
+        before.clear();
+        before.resize(b.len() + 1, 0);
+        previous.clear();
+        previous.extend(0..=b.len());
+        current.clear();
+        current.resize(b.len() + 1, 0);
+
+        for i in 0..a.len() {
+            current[0] = i + 1;
+            let mut row_min = current[0];
+            for j in 0..b.len() {
+                let cost = usize::from(a[i] != b[j]);
+                let mut distance = (previous[j] + cost).min(previous[j + 1] + 1).min(current[j] + 1);
+                if i > 0 && j > 0 && a[i] == b[j - 1] && a[i - 1] == b[j] {
+                    distance = distance.min(before[j - 1] + 1);
+                }
+                current[j + 1] = distance;
+                row_min = row_min.min(distance);
+            }
+            if row_min > max {
+                return None;
+            }
+            std::mem::swap(before, previous);
+            std::mem::swap(previous, current);
+        }
+
+        let distance = previous[b.len()];
+        (distance > 0 && distance <= max).then_some(distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_embedded_index() {
+        let search = IconSearch::embedded();
+
+        let hits = search.search("home", &SearchOptions::default());
+        assert!(!hits.is_empty());
+        assert_eq!(hits[0].name, "home");
+
+        let options = SearchOptions { prefixes: vec!["mdi".to_string()], ..Default::default() };
+        let hits = search.search("hoem", &options);
+        assert!(hits.iter().all(|hit| hit.prefix == "mdi"));
+        assert!(hits.iter().any(|hit| hit.id() == "mdi:home"));
+
+        let hits = search.search("mdi arrow left", &SearchOptions::default());
+        assert!(hits.iter().any(|hit| hit.id() == "mdi:arrow-left"));
+
+        // A query that only names a set browses it
+        let hits = search.search("mdi", &SearchOptions { limit: 20, ..Default::default() });
+        assert_eq!(hits.len(), 20);
+        assert!(hits.iter().all(|hit| hit.prefix == "mdi"));
+
+        assert!(search.search("", &SearchOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_distance() {
+        let mut home = FuzzyMatcher::new("home", 1);
+        assert_eq!(home.distance("hoem"), Some(1));
+        assert_eq!(home.distance("hone"), Some(1));
+        assert_eq!(home.distance("home"), None);
+        assert_eq!(home.distance("house"), None);
+    }
+}
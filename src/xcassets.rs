@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::converters::optimize::OptimizeOptions;
+use crate::converters::svgl::Theme;
+use crate::converters::swift::{imageset_contents, is_swift_type, svg_to_swiftui_shape, CATALOG_CONTENTS};
+use crate::converters::utils::svg_with_xmlns;
+use crate::export::{unique_name, unique_stem, ExportReport, Framework};
+use crate::registry::IconRegistry;
+
+/// Options for `export_xcassets`
+#[derive(Debug, Clone)]
+pub struct XcassetsOptions {
+    pub theme: Option<Theme>,
+    /// Also write a Swift file with a SwiftUI `Shape` per icon
+    pub swiftui: Option<PathBuf>,
+    /// Optimizer passes applied to each icon first; `None` writes markup as stored
+    pub optimize: Option<OptimizeOptions>,
+}
+
+impl Default for XcassetsOptions {
+    fn default() -> Self {
+        XcassetsOptions {
+            theme: None,
+            swiftui: None,
+            optimize: Some(OptimizeOptions::default()),
+        }
+    }
+}
+
+/// Write icons by `prefix:name` id as image sets of an asset catalog at `catalog`, e.g. `Icons.xcassets`.
+/// Each set holds one SVG that Xcode keeps as a vector; monotone icons render as templates.
+pub fn export_xcassets(
+    registry: &IconRegistry,
+    ids: &[String],
+    options: &XcassetsOptions,
+    catalog: &Path,
+) -> Result<ExportReport, Box<dyn Error>> {
+    let mut report = ExportReport::default();
+    let mut icons = Vec::new();
+    for id in ids {
+        let icon = match options.theme {
+            Some(theme) => registry.resolve_themed(id, theme),
+            None => registry.resolve(id),
+        };
+        match icon {
+            Some(mut icon) => {
+                if let Some(optimize) = &options.optimize {
+                    report.stats += icon.optimize(optimize);
+                }
+                icons.push(icon);
+            }
+            None => report.missing.push(id.clone()),
+        }
+    }
+
+    let prefixed = icons.windows(2).any(|pair| pair[0].prefix != pair[1].prefix);
+    fs::create_dir_all(catalog)?;
+    let contents = catalog.join("Contents.json");
+    fs::write(&contents, CATALOG_CONTENTS)?;
+    report.files.push(contents);
+
+    let (mut used, mut used_shapes) = (HashSet::new(), HashSet::new());
+    let mut swift = String::from("import SwiftUI\n");
+    for icon in &icons {
+        let stem = unique_stem(&mut used, icon, prefixed, Framework::Svg);
+        let imageset = catalog.join(format!("{}.imageset", stem));
+        fs::create_dir_all(&imageset)?;
+        let file_name = format!("{}.svg", stem);
+        // Asset catalogs don't resolve `currentColor`; template rendering recolors the black anyway
+        let svg = svg_with_xmlns(&icon.to_svg()).replace("currentColor", "#000");
+        fs::write(imageset.join(&file_name), svg)?;
+        fs::write(imageset.join("Contents.json"), imageset_contents(&file_name, !icon.palette))?;
+        report.files.push(imageset);
+
+        if options.swiftui.is_some() {
+            let name = unique_name(&mut used_shapes, icon, prefixed, Framework::React, is_swift_type);
+            let shape = svg_to_swiftui_shape(&icon.to_svg(), &name);
+            swift.push('\n');
+            for warning in shape.warnings {
+                swift.push_str(&format!("// SwiftUI: {}\n", warning));
+                report.warnings.push(format!("{}: {}", icon.id(), warning));
+            }
+            swift.push_str(&format!("// {}\n", icon.id()));
+            swift.push_str(&shape.code);
+        }
+    }
+
+    if let Some(path) = &options.swiftui {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, swift)?;
+        report.files.push(path.clone());
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_xcassets() {
+        let registry = IconRegistry::new();
+        let out = std::env::temp_dir().join(format!("dx-icon-xcassets-test-{}", std::process::id()));
+        fs::remove_dir_all(&out).ok();
+
+        let catalog = out.join("Icons.xcassets");
+        let options = XcassetsOptions { swiftui: Some(out.join("Icons.swift")), ..Default::default() };
+        let ids = ["mdi:home".to_string(), "svgl:github".to_string(), "mdi:missing".to_string()];
+        let report = export_xcassets(&registry, &ids, &options, &catalog).unwrap();
+        assert_eq!(report.missing, ["mdi:missing"]);
+        assert_eq!(fs::read_to_string(catalog.join("Contents.json")).unwrap(), CATALOG_CONTENTS);
+
+        let home = catalog.join("mdi-home.imageset");
+        assert!(fs::read_to_string(home.join("mdi-home.svg")).unwrap().starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        let contents = fs::read_to_string(home.join("Contents.json")).unwrap();
+        assert!(contents.contains("\"filename\" : \"mdi-home.svg\""));
+        assert!(contents.contains("\"template-rendering-intent\" : \"template\""));
+        let logo = fs::read_to_string(catalog.join("svgl-github-light.imageset/Contents.json")).unwrap();
+        assert!(logo.contains("\"template-rendering-intent\" : \"original\""));
+
+        let swift = fs::read_to_string(out.join("Icons.swift")).unwrap();
+        assert!(swift.starts_with("import SwiftUI\n\n// mdi:home\n/// Drawn in a 24×24 viewBox"));
+        assert!(swift.contains("struct MdiHome: Shape {\n"));
+        assert!(swift.contains("struct SvglGithubLight: Shape {\n"));
+        assert_eq!(swift.matches("import SwiftUI").count(), 1);
+
+        // Unprefixed names must not shadow the SwiftUI types the shape uses
+        let report = export_xcassets(&registry, &["mdi:shape".to_string()], &options, &catalog).unwrap();
+        assert!(report.missing.is_empty());
+        assert!(fs::read_to_string(out.join("Icons.swift")).unwrap().contains("struct ShapeIcon: Shape {\n"));
+
+        fs::remove_dir_all(out).ok();
+    }
+}
@@ -0,0 +1,82 @@
+use serde_json::Value;
+use std::fs;
+use std::process::{Command, Output};
+
+fn dx_icon(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_dx-icon")).args(args).output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_search() {
+    let output = dx_icon(&["search", "home", "-p", "mdi", "-l", "3"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output).lines().next(), Some("mdi:home"));
+    assert_eq!(stdout(&output).lines().count(), 3);
+
+    let output = dx_icon(&["--json", "search", "home", "-p", "mdi", "-l", "3"]);
+    assert_eq!(output.status.code(), Some(0));
+    let hits: Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(hits[0]["id"], "mdi:home");
+    assert_eq!(hits.as_array().unwrap().len(), 3);
+
+    let output = dx_icon(&["search", "zzqqxx", "--exact"]);
+    assert_eq!(output.status.code(), Some(3));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn test_show() {
+    let output = dx_icon(&["show", "mdi:home"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).starts_with("<svg viewBox=\"0 0 24 24\""));
+
+    let output = dx_icon(&["--json", "show", "mdi:home", "-f", "react"]);
+    assert_eq!(output.status.code(), Some(0));
+    let icon: Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(icon["id"], "mdi:home");
+    assert!(icon["output"].as_str().unwrap().contains("MdiHome"));
+
+    let output = dx_icon(&["show", "mdi:nope"]);
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "error: icon 'mdi:nope' not found\n");
+
+    let output = dx_icon(&["--json", "show", "mdi:nope"]);
+    assert_eq!(output.status.code(), Some(3));
+    let error: Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(error["error"], "icon 'mdi:nope' not found");
+
+    // Usage errors come from clap
+    assert_eq!(dx_icon(&["show", "--bogus"]).status.code(), Some(2));
+    assert_eq!(dx_icon(&["show", "mdi:home", "-f", "cobol"]).status.code(), Some(2));
+}
+
+#[test]
+fn test_export() {
+    let out = std::env::temp_dir().join(format!("dx-icon-cli-test-{}", std::process::id()));
+    fs::remove_dir_all(&out).ok();
+    let dir = out.to_str().unwrap();
+
+    let output = dx_icon(&["--json", "export", "mdi:home", "-o", dir]);
+    assert_eq!(output.status.code(), Some(0));
+    let report: Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(report["written"][0], out.join("home.svg").to_str().unwrap());
+    assert!(fs::read_to_string(out.join("home.svg")).unwrap().contains("<svg viewBox=\"0 0 24 24\""));
+
+    // Icons that resolve are still written when others are missing
+    let output = dx_icon(&["export", "mdi:account", "mdi:nope", "-o", dir]);
+    assert_eq!(output.status.code(), Some(3));
+    assert!(out.join("account.svg").exists());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("warning: icon 'mdi:nope' not found"));
+
+    // An output directory that can't be created is an error
+    let file = out.join("home.svg");
+    let output = dx_icon(&["--json", "export", "mdi:home", "-o", file.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(serde_json::from_str::<Value>(&stdout(&output)).unwrap()["error"].is_string());
+
+    fs::remove_dir_all(out).ok();
+}